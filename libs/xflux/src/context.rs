@@ -36,6 +36,12 @@ impl Context {
         self
     }
 
+    /// Mutable access to a variable, so steps can patch nested data with
+    /// [`Value::set`] instead of rebuilding it.
+    pub fn var_mut(&mut self, name: &str) -> Option<&mut Value> {
+        self.data.get_mut(name)
+    }
+
     pub fn register(&mut self, action: ActionRef, executor: impl Execute + 'static) -> &mut Self {
         self.actions.insert(action, Arc::new(executor));
         self
@@ -0,0 +1,305 @@
+use crate::{Ident, ParseError, Path};
+
+/// Interop with the path syntaxes other JSON tooling speaks. Both produce
+/// the same [`Path`] as [`Path::parse`], so any of them can address a value.
+impl Path {
+    /// Parse a JSON Pointer (RFC 6901) such as `/users/0/name`. `~1` and `~0`
+    /// unescape to `/` and `~`, and segments that are valid array indices
+    /// (digits without a leading zero) become [`Ident::Index`].
+    pub fn from_json_pointer(src: &str) -> Result<Self, ParseError> {
+        if src.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let Some(rest) = src.strip_prefix('/') else {
+            return Err(format!("json pointer {:?} must start with '/'", src).into());
+        };
+
+        rest.split('/')
+            .map(|segment| {
+                let mut key = String::with_capacity(segment.len());
+                let mut chars = segment.chars();
+
+                while let Some(c) = chars.next() {
+                    match c {
+                        '~' => match chars.next() {
+                            Some('0') => key.push('~'),
+                            Some('1') => key.push('/'),
+                            _ => {
+                                return Err(ParseError::from(format!(
+                                    "invalid escape in json pointer {:?}",
+                                    src
+                                )));
+                            }
+                        },
+                        c => key.push(c),
+                    }
+                }
+
+                match key.parse::<usize>() {
+                    Ok(i)
+                        if key.bytes().all(|b| b.is_ascii_digit())
+                            && (key == "0" || !key.starts_with('0')) =>
+                    {
+                        Ok(Ident::Index(i))
+                    }
+                    _ => Ok(Ident::Key(key.into())),
+                }
+            })
+            .collect()
+    }
+
+    /// Write the path as a JSON Pointer, escaping `~` and `/` in keys.
+    pub fn to_json_pointer(&self) -> String {
+        let mut out = String::new();
+
+        for ident in self.iter() {
+            out.push('/');
+
+            match ident {
+                Ident::Key(key) => out.push_str(&key.replace('~', "~0").replace('/', "~1")),
+                Ident::Index(i) => out.push_str(&i.to_string()),
+            }
+        }
+
+        out
+    }
+
+    /// Parse JSONPath-style dotted and bracket notation for a single node:
+    /// `$.users[0].name`, `$['weird key']` or `$["a.b"]`. The leading `$`
+    /// is optional, `[n]` is an index and every other segment is a key.
+    pub fn from_json_path(src: &str) -> Result<Self, ParseError> {
+        let err = |expected: &str, at: usize| {
+            ParseError::from(format!(
+                "invalid json path {:?}: expected {} at {}",
+                src, expected, at
+            ))
+        };
+
+        let bytes = src.as_bytes();
+        let mut path = Self::default();
+        let mut i = match bytes.first() {
+            Some(b'$') => 1,
+            Some(b'.' | b'[') | None => 0,
+            Some(_) => {
+                // a bare first name, as in `users[0]`
+                let end = name_end(src, 0);
+                path.push(&src[..end]);
+                end
+            }
+        };
+
+        while i < bytes.len() {
+            match bytes[i] {
+                b'.' => {
+                    let end = name_end(src, i + 1);
+
+                    if end == i + 1 {
+                        return Err(err("a name", end));
+                    }
+
+                    path.push(&src[i + 1..end]);
+                    i = end;
+                }
+                b'[' => match bytes.get(i + 1) {
+                    Some(&quote @ (b'\'' | b'"')) => {
+                        let (key, end) = quoted(src, i + 2, quote as char)
+                            .ok_or_else(|| err("a closing quote", i + 1))?;
+
+                        if bytes.get(end) != Some(&b']') {
+                            return Err(err("']'", end));
+                        }
+
+                        path.push(key);
+                        i = end + 1;
+                    }
+                    _ => {
+                        let end = src[i + 1..]
+                            .find(']')
+                            .map(|n| i + 1 + n)
+                            .ok_or_else(|| err("']'", src.len()))?;
+                        let index = src[i + 1..end]
+                            .parse::<usize>()
+                            .map_err(|_| err("an index or quoted key", i + 1))?;
+
+                        path.push(index);
+                        i = end + 1;
+                    }
+                },
+                _ => return Err(err("'.' or '['", i)),
+            }
+        }
+
+        Ok(path)
+    }
+
+    /// Write the path in dotted notation, bracketing indices and any key
+    /// that isn't a plain identifier.
+    pub fn to_json_path(&self) -> String {
+        let mut out = String::from("$");
+
+        for ident in self.iter() {
+            match ident {
+                Ident::Index(i) => out.push_str(&format!("[{}]", i)),
+                Ident::Key(key)
+                    if key.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') =>
+                {
+                    out.push('.');
+                    out.push_str(key);
+                }
+                Ident::Key(key) => {
+                    out.push_str("['");
+
+                    for c in key.chars() {
+                        if matches!(c, '\'' | '\\') {
+                            out.push('\\');
+                        }
+
+                        out.push(c);
+                    }
+
+                    out.push_str("']");
+                }
+            }
+        }
+
+        out
+    }
+}
+
+/// The end of a dotted name starting at `start`.
+fn name_end(src: &str, start: usize) -> usize {
+    src[start..]
+        .find(['.', '[', ']'])
+        .map_or(src.len(), |n| start + n)
+}
+
+/// Read a quoted key starting after its opening quote, returning it and the
+/// offset just past the closing quote. Supports JSON's backslash escapes.
+fn quoted(src: &str, start: usize, quote: char) -> Option<(String, usize)> {
+    let mut key = String::new();
+    let mut chars = src[start..].char_indices().map(|(i, c)| (start + i, c));
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            c if c == quote => return Some((key, i + 1)),
+            '\\' => key.push(match chars.next()?.1 {
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                'b' => '\u{8}',
+                'f' => '\u{c}',
+                'u' => {
+                    let high = hex4(&mut chars)?;
+                    let units = match (0xd800..0xdc00).contains(&high) {
+                        true => match (chars.next()?.1, chars.next()?.1) {
+                            ('\\', 'u') => vec![high, hex4(&mut chars)?],
+                            _ => return None,
+                        },
+                        false => vec![high],
+                    };
+
+                    char::decode_utf16(units).next()?.ok()?
+                }
+                c @ ('\'' | '"' | '\\' | '/') => c,
+                _ => return None,
+            }),
+            c => key.push(c),
+        }
+    }
+
+    None
+}
+
+/// The four hex digits of a `\u` escape.
+fn hex4(chars: &mut impl Iterator<Item = (usize, char)>) -> Option<u16> {
+    let digits: String = chars.take(4).map(|(_, c)| c).collect();
+
+    match digits.len() {
+        4 => u16::from_str_radix(&digits, 16).ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_pointer() {
+        let path = Path::from_json_pointer("/users/0/name").unwrap();
+        assert_eq!(path, Path::parse("users/0/name").unwrap());
+        assert_eq!(path.to_json_pointer(), "/users/0/name");
+
+        let path = Path::from_json_pointer("/a~1b/m~0n/05/-/").unwrap();
+        assert_eq!(path, Path::from_keys(["a/b", "m~n", "05", "-", ""]));
+        assert_eq!(path.to_json_pointer(), "/a~1b/m~0n/05/-/");
+
+        assert!(Path::from_json_pointer("").unwrap().is_empty());
+        assert_eq!(Path::default().to_json_pointer(), "");
+    }
+
+    #[test]
+    fn json_pointer_errors() {
+        assert!(Path::from_json_pointer("a/b").is_err());
+        assert!(Path::from_json_pointer("/a~2").is_err());
+        assert!(Path::from_json_pointer("/a~").is_err());
+    }
+
+    #[test]
+    fn json_path() {
+        let expected = Path::parse("users/0/name").unwrap();
+
+        assert_eq!(Path::from_json_path("$.users[0].name").unwrap(), expected);
+        assert_eq!(
+            Path::from_json_path("$['users'][0][\"name\"]").unwrap(),
+            expected
+        );
+        assert_eq!(Path::from_json_path("users[0].name").unwrap(), expected);
+        assert_eq!(
+            Path::from_json_path("$.data").unwrap(),
+            Path::from_keys(["data"])
+        );
+        assert!(Path::from_json_path("$").unwrap().is_empty());
+        assert!(Path::from_json_path("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn json_path_quoted_keys() {
+        let path = Path::from_json_path(r#"$['weird key']["a.b"]['it\'s']['é\n']"#).unwrap();
+        assert_eq!(
+            path,
+            Path::from_keys(["weird key", "a.b", "it's", "\u{e9}\n"])
+        );
+
+        let path = Path::from_json_path(r#"$['\ud83d\ude00']"#).unwrap();
+        assert_eq!(path, Path::from_keys(["\u{1f600}"]));
+    }
+
+    #[test]
+    fn json_path_errors() {
+        for src in [
+            "$.",
+            "$..a",
+            "$[",
+            "$[a]",
+            "$[-1]",
+            "$['a",
+            "$['a'",
+            "$['a\\q']",
+            "$x",
+            "$.a]",
+        ] {
+            assert!(Path::from_json_path(src).is_err(), "{}", src);
+        }
+    }
+
+    #[test]
+    fn to_json_path() {
+        let path = Path::from_keys(["users", "weird key", "it's", "_a1"]).child(3_usize);
+        assert_eq!(path.to_json_path(), r"$.users['weird key']['it\'s']._a1[3]");
+        assert_eq!(Path::from_json_path(&path.to_json_path()).unwrap(), path);
+        assert_eq!(Path::default().to_json_path(), "$");
+    }
+}
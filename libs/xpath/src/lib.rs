@@ -1,7 +1,10 @@
 mod error;
 mod ident;
+mod json;
 mod path;
+mod selector;
 
 pub use error::*;
 pub use ident::*;
 pub use path::*;
+pub use selector::*;
@@ -4,24 +4,53 @@ use crate::{Ident, ParseError};
 pub struct Path(Vec<Ident>);
 
 impl Path {
+    /// Parse `/`-separated segments. All-digit segments become indices;
+    /// a segment wrapped in single quotes, such as `'404'` or `'a/b'`, is
+    /// always a key, with `\'` and `\\` escaping a quote and a backslash.
     pub fn parse(src: &str) -> Result<Self, ParseError> {
         if src.is_empty() {
             return Ok(Self(vec![]));
         }
 
         let mut items = vec![];
+        let mut rest = src;
 
-        for item in src.split("/") {
-            if item.is_empty() {
-                return Err("path segments cannot be empty".into());
-            }
+        loop {
+            let (ident, next) = match rest.strip_prefix('\'') {
+                Some(quoted) => parse_quoted(quoted)?,
+                None => {
+                    let end = rest.find('/').unwrap_or(rest.len());
+
+                    if end == 0 {
+                        return Err("path segments cannot be empty".into());
+                    }
+
+                    (Ident::parse(&rest[..end]), &rest[end..])
+                }
+            };
 
-            items.push(Ident::parse(item));
+            items.push(ident);
+
+            match next.strip_prefix('/') {
+                Some("") => return Err("path segments cannot be empty".into()),
+                Some(next) => rest = next,
+                None if next.is_empty() => break,
+                None => return Err(format!("expected '/' after quoted key in {:?}", src).into()),
+            }
         }
 
         Ok(Self(items))
     }
 
+    /// Build a path of keys taken as-is: digits stay keys and quotes are kept.
+    pub fn from_keys<I, K>(keys: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: AsRef<str>,
+    {
+        Self(keys.into_iter().map(|k| Ident::key(k.as_ref())).collect())
+    }
+
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
@@ -61,6 +90,49 @@ impl Path {
     }
 }
 
+/// Read a quoted key up to its closing quote, returning the rest.
+pub(crate) fn parse_quoted(src: &str) -> Result<(Ident, &str), ParseError> {
+    let mut key = String::new();
+    let mut chars = src.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\'' => return Ok((Ident::Key(key.into()), &src[i + 1..])),
+            '\\' => match chars.next() {
+                Some((_, c @ ('\'' | '\\'))) => key.push(c),
+                Some((_, c)) => {
+                    return Err(format!("invalid escape '\\{}' in quoted key", c).into());
+                }
+                None => break,
+            },
+            c => key.push(c),
+        }
+    }
+
+    Err("unterminated quoted key".into())
+}
+
+/// Whether [`Path::parse`] would split `key`, read it as an index or take
+/// it for a quoted key.
+pub(crate) fn needs_quotes(key: &str) -> bool {
+    key.is_empty() || key.contains('/') || key.starts_with('\'') || key.parse::<usize>().is_ok()
+}
+
+/// Write `key` in single quotes, escaping quotes and backslashes.
+pub(crate) fn write_quoted(f: &mut std::fmt::Formatter<'_>, key: &str) -> std::fmt::Result {
+    write!(f, "'")?;
+
+    for c in key.chars() {
+        if matches!(c, '\'' | '\\') {
+            write!(f, "\\")?;
+        }
+
+        write!(f, "{}", c)?;
+    }
+
+    write!(f, "'")
+}
+
 impl From<&str> for Path {
     fn from(value: &str) -> Self {
         Self::parse(value).unwrap()
@@ -73,6 +145,14 @@ impl From<String> for Path {
     }
 }
 
+impl FromIterator<Ident> for Path {
+    fn from_iter<T: IntoIterator<Item = Ident>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// Keys are quoted where [`Path::parse`] would otherwise misread them, so
+/// the text always parses back.
 impl std::fmt::Display for Path {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for (i, ident) in self.0.iter().enumerate() {
@@ -80,7 +160,10 @@ impl std::fmt::Display for Path {
                 write!(f, "/")?;
             }
 
-            write!(f, "{}", ident)?;
+            match ident {
+                Ident::Key(key) if needs_quotes(key) => write_quoted(f, key)?,
+                ident => write!(f, "{}", ident)?,
+            }
         }
 
         Ok(())
@@ -230,6 +313,36 @@ mod tests {
         assert_eq!(Path::default().to_string(), "");
     }
 
+    #[test]
+    fn parse_quoted() {
+        let path = Path::parse(r"codes/'404'/'a/b'/'it\'s'/''").unwrap();
+        assert_eq!(path.len(), 5);
+        assert_eq!(path[1], Ident::key("404"));
+        assert_eq!(path[2], Ident::key("a/b"));
+        assert_eq!(path[3], Ident::key("it's"));
+        assert_eq!(path[4], Ident::key(""));
+        assert_eq!(Path::parse("it's").unwrap()[0], Ident::key("it's"));
+    }
+
+    #[test]
+    fn parse_quoted_errors() {
+        assert!(Path::parse("'a").is_err());
+        assert!(Path::parse("'a'b").is_err());
+        assert!(Path::parse(r"'a\n'").is_err());
+        assert!(Path::parse("'a'/").is_err());
+    }
+
+    #[test]
+    fn display_quotes_ambiguous_keys() {
+        let path = Path::from_keys(["404", "a/b", "'x", "", "05", "plain"]);
+        assert_eq!(path.to_string(), r"'404'/'a/b'/'\'x'/''/'05'/plain");
+        assert_eq!(Path::parse(&path.to_string()).unwrap(), path);
+
+        let path: Path = [Ident::key(r"a\'b"), Ident::index(3)].into_iter().collect();
+        assert_eq!(path.to_string(), r"a\'b/3");
+        assert_eq!(Path::parse(&path.to_string()).unwrap(), path);
+    }
+
     #[test]
     fn eq() {
         assert_eq!(Path::parse("a/0/b").unwrap(), Path::parse("a/0/b").unwrap());
@@ -283,6 +396,14 @@ mod tests {
             assert!(result.is_err());
         }
 
+        #[test]
+        fn roundtrip_quoted() {
+            let original = Path::from_keys(["status", "404", "a/b"]);
+            let json = serde_json::to_string(&original).unwrap();
+            assert_eq!(json, r#""status/'404'/'a/b'""#);
+            assert_eq!(serde_json::from_str::<Path>(&json).unwrap(), original);
+        }
+
         #[test]
         fn roundtrip() {
             let original = Path::parse("a/1/b/2").unwrap();
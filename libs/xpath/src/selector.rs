@@ -0,0 +1,366 @@
+use crate::{Ident, ParseError, Path, path};
+
+/// One step of a [`Selector`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Segment {
+    /// A single key or index, as in a [`Path`].
+    Ident(Ident),
+    /// `*`: every field of a struct or item of an array or tuple.
+    Wildcard,
+    /// `**`: this node and every node below it.
+    Descendants,
+    /// `-n`: the `n`th item counted from the end, so `-1` is the last.
+    FromEnd(usize),
+    /// `start:end:step`, counted like a Python slice. Missing bounds cover
+    /// the whole sequence and negative ones count from the end.
+    Slice {
+        start: Option<isize>,
+        end: Option<isize>,
+        step: isize,
+    },
+    /// `[a,b]`: each member in turn.
+    Union(Vec<Segment>),
+}
+
+impl Segment {
+    /// Read an unquoted segment that isn't a union.
+    fn parse(src: &str) -> Result<Self, ParseError> {
+        if src.is_empty() {
+            return Err("selector segments cannot be empty".into());
+        }
+
+        if src == "*" {
+            return Ok(Self::Wildcard);
+        }
+
+        if src == "**" {
+            return Ok(Self::Descendants);
+        }
+
+        if src.contains(':') {
+            let parts: Vec<_> = src.split(':').collect();
+
+            if parts.len() > 3 {
+                return Err(format!("slice {:?} has more than three parts", src).into());
+            }
+
+            let bound = |part: &str| match part {
+                "" => Ok(None),
+                part => part
+                    .parse::<isize>()
+                    .map(Some)
+                    .map_err(|_| ParseError::from(format!("invalid slice bound {:?}", part))),
+            };
+
+            let step = bound(parts.get(2).copied().unwrap_or(""))?.unwrap_or(1);
+
+            if step == 0 {
+                return Err("slice step cannot be zero".into());
+            }
+
+            return Ok(Self::Slice {
+                start: bound(parts[0])?,
+                end: bound(parts[1])?,
+                step,
+            });
+        }
+
+        if let Some(n) = src.strip_prefix('-')
+            && n.bytes().all(|b| b.is_ascii_digit())
+            && let Ok(n @ 1..) = n.parse::<usize>()
+        {
+            return Ok(Self::FromEnd(n));
+        }
+
+        Ok(Self::Ident(Ident::parse(src)))
+    }
+
+    /// Read the members of a union, starting after its `[`, returning the
+    /// rest after its `]`.
+    fn parse_union(src: &str) -> Result<(Self, &str), ParseError> {
+        let mut members = vec![];
+        let mut rest = src;
+
+        loop {
+            let (member, next) = match rest.strip_prefix('\'') {
+                Some(quoted) => {
+                    path::parse_quoted(quoted).map(|(ident, next)| (Self::Ident(ident), next))?
+                }
+                None => {
+                    let end = rest.find([',', ']']).unwrap_or(rest.len());
+                    (Self::parse(&rest[..end])?, &rest[end..])
+                }
+            };
+
+            if member == Self::Descendants {
+                return Err("'**' cannot be part of a union".into());
+            }
+
+            members.push(member);
+
+            match next.as_bytes().first() {
+                Some(b',') => rest = &next[1..],
+                Some(b']') => return Ok((Self::Union(members), &next[1..])),
+                _ => return Err("unterminated union".into()),
+            }
+        }
+    }
+
+    fn fmt_member(&self, f: &mut std::fmt::Formatter<'_>, in_union: bool) -> std::fmt::Result {
+        match self {
+            Self::Ident(Ident::Key(key))
+                if path::needs_quotes(key)
+                    || key.starts_with('[')
+                    || (in_union && key.contains([',', ']']))
+                    || Self::parse(key).ok() != Some(Self::Ident(Ident::key(key))) =>
+            {
+                path::write_quoted(f, key)
+            }
+            Self::Ident(ident) => write!(f, "{}", ident),
+            Self::Wildcard => write!(f, "*"),
+            Self::Descendants => write!(f, "**"),
+            Self::FromEnd(n) => write!(f, "-{}", n),
+            Self::Slice { start, end, step } => {
+                let bound = |v: &Option<isize>| v.map(|v| v.to_string()).unwrap_or_default();
+                write!(f, "{}:{}", bound(start), bound(end))?;
+
+                match step {
+                    1 => Ok(()),
+                    step => write!(f, ":{}", step),
+                }
+            }
+            Self::Union(members) => {
+                write!(f, "[")?;
+
+                for (i, member) in members.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+
+                    member.fmt_member(f, true)?;
+                }
+
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Segment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_member(f, false)
+    }
+}
+
+/// A [`Path`] pattern that can match many nodes, such as `users/*/email`,
+/// `**/id`, `items/-1`, `items/1:5:2` or `items/[0,2]`. Keys are quoted as
+/// in a [`Path`], so `'*'` is the key `*` rather than a wildcard.
+#[derive(Default, Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Selector(Vec<Segment>);
+
+impl Selector {
+    pub fn parse(src: &str) -> Result<Self, ParseError> {
+        if src.is_empty() {
+            return Ok(Self(vec![]));
+        }
+
+        let mut segments = vec![];
+        let mut rest = src;
+
+        loop {
+            let (segment, next) = if let Some(quoted) = rest.strip_prefix('\'') {
+                path::parse_quoted(quoted).map(|(ident, next)| (Segment::Ident(ident), next))?
+            } else if let Some(union) = rest.strip_prefix('[') {
+                Segment::parse_union(union)?
+            } else {
+                let end = rest.find('/').unwrap_or(rest.len());
+                (Segment::parse(&rest[..end])?, &rest[end..])
+            };
+
+            segments.push(segment);
+
+            match next.strip_prefix('/') {
+                Some("") => return Err("selector segments cannot be empty".into()),
+                Some(next) => rest = next,
+                None if next.is_empty() => break,
+                None => return Err(format!("expected '/' after segment in {:?}", src).into()),
+            }
+        }
+
+        Ok(Self(segments))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        &self.0
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Segment> {
+        self.0.iter()
+    }
+
+    /// The [`Path`] this selector addresses when it can only match one node.
+    pub fn as_path(&self) -> Option<Path> {
+        self.0
+            .iter()
+            .map(|segment| match segment {
+                Segment::Ident(ident) => Some(ident.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl From<Path> for Selector {
+    fn from(value: Path) -> Self {
+        Self(value.iter().cloned().map(Segment::Ident).collect())
+    }
+}
+
+impl From<&str> for Selector {
+    fn from(value: &str) -> Self {
+        Self::parse(value).unwrap()
+    }
+}
+
+impl FromIterator<Segment> for Selector {
+    fn from_iter<T: IntoIterator<Item = Segment>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl std::fmt::Display for Selector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "/")?;
+            }
+
+            write!(f, "{}", segment)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Selector {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        s.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Selector {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(d)?;
+        Self::parse(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(k: &str) -> Segment {
+        Segment::Ident(Ident::key(k))
+    }
+
+    #[test]
+    fn parse_segments() {
+        let selector = Selector::parse("users/*/email").unwrap();
+        assert_eq!(
+            selector.segments(),
+            [key("users"), Segment::Wildcard, key("email")]
+        );
+
+        let selector = Selector::parse("**/items/-1/1:5:2/::-1/[0,2,'a,b']").unwrap();
+        assert_eq!(
+            selector.segments(),
+            [
+                Segment::Descendants,
+                key("items"),
+                Segment::FromEnd(1),
+                Segment::Slice {
+                    start: Some(1),
+                    end: Some(5),
+                    step: 2
+                },
+                Segment::Slice {
+                    start: None,
+                    end: None,
+                    step: -1
+                },
+                Segment::Union(vec![
+                    Segment::Ident(Ident::index(0)),
+                    Segment::Ident(Ident::index(2)),
+                    key("a,b"),
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        for src in [
+            "a//b", "a/", "[]", "[0", "[0]x", "[**]", "1:2:0", "1:2:3:4", "a:b", "'a",
+        ] {
+            assert!(Selector::parse(src).is_err(), "{}", src);
+        }
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for src in [
+            "users/*/email",
+            "**/id",
+            "items/-1/1:5:2/:3/-2:/::-1",
+            "[0,2]/['*',b]",
+            "'*'/'**'/'-1'/'1:2'/'[x'/'404'",
+            "",
+        ] {
+            let selector = Selector::parse(src).unwrap();
+            assert_eq!(selector.to_string(), src);
+            assert_eq!(Selector::parse(&selector.to_string()).unwrap(), selector);
+        }
+
+        let selector: Selector = [Segment::Union(vec![key("a]"), key("b")])]
+            .into_iter()
+            .collect();
+        assert_eq!(selector.to_string(), "['a]',b]");
+        assert_eq!(Selector::parse(&selector.to_string()).unwrap(), selector);
+    }
+
+    #[test]
+    fn from_path() {
+        let path = Path::parse("users/0/'*'").unwrap();
+        let selector = Selector::from(path.clone());
+
+        assert_eq!(selector.to_string(), "users/0/'*'");
+        assert_eq!(selector.as_path(), Some(path));
+        assert_eq!(Selector::parse("users/*").unwrap().as_path(), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        let selector = Selector::parse("users/*/['a,b',-1]").unwrap();
+        let json = serde_json::to_string(&selector).unwrap();
+        assert_eq!(json, r#""users/*/['a,b',-1]""#);
+        assert_eq!(serde_json::from_str::<Selector>(&json).unwrap(), selector);
+        assert!(serde_json::from_str::<Selector>(r#""a//b""#).is_err());
+    }
+}
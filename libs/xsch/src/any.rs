@@ -108,6 +108,15 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn validate_options_across_widths() {
+        let schema = any().options(&[&1i32, &2i32]);
+        assert!(schema.validate(&1i64.to_value().into()).is_ok());
+        assert!(schema.validate(&2u8.to_value().into()).is_ok());
+        assert!(schema.validate(&2.0f64.to_value().into()).is_ok());
+        assert!(schema.validate(&2.5f64.to_value().into()).is_err());
+    }
+
     #[test]
     fn validate_options_mismatch() {
         let schema = any().options(&[&1i32, &2i32]);
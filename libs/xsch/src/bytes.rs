@@ -0,0 +1,110 @@
+use xval::ToValue;
+
+use crate::{
+    Context, Equals, Max, Min, Phase, Required, RuleSet, Schema, ToSchema, ValidError, Validator,
+};
+
+pub fn bytes() -> BytesSchema {
+    BytesSchema::default()
+}
+
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(transparent)
+)]
+pub struct BytesSchema(pub(crate) RuleSet);
+
+impl BytesSchema {
+    pub fn equals(mut self, value: &[u8]) -> Self {
+        self.0 = self
+            .0
+            .add(Equals::from(xval::Bytes::from(value).to_value()).into());
+        self
+    }
+
+    pub fn required(mut self) -> Self {
+        self.0 = self.0.add(Required::new(true).into());
+        self
+    }
+
+    /// Minimum length in bytes.
+    pub fn min(mut self, min: usize) -> Self {
+        self.0 = self.0.add(Min::from(xval::Number::from_usize(min)).into());
+        self
+    }
+
+    /// Maximum length in bytes.
+    pub fn max(mut self, max: usize) -> Self {
+        self.0 = self.0.add(Max::from(xval::Number::from_usize(max)).into());
+        self
+    }
+}
+
+impl ToSchema for BytesSchema {
+    fn to_schema(&self) -> Schema {
+        Schema::Bytes(self.clone())
+    }
+}
+
+impl From<BytesSchema> for Schema {
+    fn from(value: BytesSchema) -> Self {
+        Self::Bytes(value)
+    }
+}
+
+impl Validator for BytesSchema {
+    fn validate(&self, ctx: &Context) -> Result<xval::Value, ValidError> {
+        let value = self.0.validate_phase(ctx, Phase::Presence)?;
+
+        if !value.is_null() && !value.is_bytes() {
+            return Err(ctx.error("expected bytes"));
+        }
+
+        let mut next = ctx.clone();
+        next.value = value;
+        self.0.validate_phase(&next, Phase::Constraint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_bytes() {
+        let schema = bytes();
+        assert!(schema.validate(&xval::valueof!(b"\x00\x01").into()).is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_non_bytes() {
+        let schema = bytes();
+        let err = schema.validate(&"AAE=".to_value().into()).unwrap_err();
+        assert_eq!(err.message.as_deref(), Some("expected bytes"));
+    }
+
+    #[test]
+    fn validate_required_rejects_null() {
+        let schema = bytes().required();
+        let err = schema.validate(&xval::valueof!(null).into()).unwrap_err();
+        assert_eq!(err.errors[0].message.as_deref(), Some("required"));
+    }
+
+    #[test]
+    fn validate_equals() {
+        let schema = bytes().equals(b"abc");
+        assert!(schema.validate(&xval::valueof!(b"abc").into()).is_ok());
+        assert!(schema.validate(&xval::valueof!(b"abd").into()).is_err());
+    }
+
+    #[test]
+    fn validate_min_max() {
+        let schema = bytes().min(2).max(3);
+        assert!(schema.validate(&xval::valueof!(b"a").into()).is_err());
+        assert!(schema.validate(&xval::valueof!(b"ab").into()).is_ok());
+        assert!(schema.validate(&xval::valueof!(b"abc").into()).is_ok());
+        assert!(schema.validate(&xval::valueof!(b"abcd").into()).is_err());
+    }
+}
@@ -1,6 +1,7 @@
 mod any;
 mod array;
 mod bool;
+mod bytes;
 mod context;
 mod error;
 mod float;
@@ -14,6 +15,7 @@ mod string;
 pub use any::*;
 pub use array::*;
 pub use bool::*;
+pub use bytes::*;
 pub use context::*;
 pub use error::*;
 pub use float::*;
@@ -40,6 +42,8 @@ impl ToSchema for xval::Value {
             xval::Value::Bool(_) => bool().to_schema(),
             xval::Value::Number(_) => number().to_schema(),
             xval::Value::String(_) => string().to_schema(),
+            xval::Value::Bytes(_) => bytes().to_schema(),
+            xval::Value::Time(_) => any().to_schema(),
             xval::Value::Object(o) => match o {
                 xval::Object::Struct(v) => {
                     let mut schema = object();
@@ -75,6 +79,7 @@ pub enum Schema {
     Any(AnySchema),
     Bool(BoolSchema),
     String(StringSchema),
+    Bytes(BytesSchema),
     Number(NumberSchema),
     Int(IntSchema),
     Float(FloatSchema),
@@ -95,6 +100,10 @@ impl Schema {
         matches!(self, Self::String(_))
     }
 
+    pub fn is_bytes(&self) -> bool {
+        matches!(self, Self::Bytes(_))
+    }
+
     pub fn is_number(&self) -> bool {
         matches!(self, Self::Number(_))
     }
@@ -136,6 +145,13 @@ impl Schema {
         }
     }
 
+    pub fn as_bytes(&self) -> Option<&BytesSchema> {
+        match self {
+            Self::Bytes(v) => Some(v),
+            _ => None,
+        }
+    }
+
     pub fn as_number(&self) -> Option<&NumberSchema> {
         match self {
             Self::Number(v) => Some(v),
@@ -184,6 +200,7 @@ impl Validator for Schema {
             Self::Any(v) => v.validate(ctx),
             Self::Bool(v) => v.validate(ctx),
             Self::String(v) => v.validate(ctx),
+            Self::Bytes(v) => v.validate(ctx),
             Self::Number(v) => v.validate(ctx),
             Self::Int(v) => v.validate(ctx),
             Self::Float(v) => v.validate(ctx),
@@ -370,6 +387,17 @@ mod tests {
             assert!(schema.validate(&map.to_value().into()).is_err());
         }
 
+        #[test]
+        fn object_numeric_key_path() {
+            let schema = Schema::Object(object().field("404", string()));
+            let err = schema
+                .validate(&xval::valueof!({ "404": 1_i32 }).into())
+                .unwrap_err();
+            let err = &err.errors[0].errors[0];
+            assert_eq!(err.path, xpath::Path::from_keys(["404"]));
+            assert_eq!(err.path.to_string(), "'404'");
+        }
+
         #[test]
         fn object_missing_required_field() {
             use std::collections::HashMap;
@@ -548,6 +576,25 @@ mod tests {
             assert_eq!(v1, v2);
         }
 
+        #[test]
+        fn roundtrip_number_with_decimal_bounds() {
+            let json = r#"{"type":"number","min":"0.01","max":"99999999999999999999.99"}"#;
+            let schema: Schema = serde_json::from_str(json).unwrap();
+            let price = |v: &str| -> Context {
+                xval::ToValue::to_value(&v.parse::<xval::Decimal>().unwrap()).into()
+            };
+
+            assert!(schema.validate(&price("0.01")).is_ok());
+            assert!(schema.validate(&price("0.009")).is_err());
+            assert!(schema.validate(&price("99999999999999999999.991")).is_err());
+            assert!(schema.validate(&xval::valueof!(0.5_f64).into()).is_ok());
+
+            let reserialized = serde_json::to_string(&schema).unwrap();
+            let v1: serde_json::Value = serde_json::from_str(json).unwrap();
+            let v2: serde_json::Value = serde_json::from_str(&reserialized).unwrap();
+            assert_eq!(v1, v2);
+        }
+
         #[test]
         fn serialize_int_empty() {
             let schema = Schema::Int(int());
@@ -186,6 +186,23 @@ mod tests {
         assert!(schema.validate(&10.0f64.to_value().into()).is_err());
     }
 
+    #[test]
+    fn validate_min_and_max_decimal() {
+        let dec = |v: &str| xval::Number::from(v.parse::<xval::Decimal>().unwrap());
+        let schema = number().min(dec("0.1")).max(dec("10.00"));
+
+        assert!(schema.validate(&dec("0.10").to_value().into()).is_ok());
+        assert!(schema.validate(&dec("0.09").to_value().into()).is_err());
+        assert!(schema.validate(&10i32.to_value().into()).is_ok());
+        assert!(
+            schema
+                .validate(&dec("10.000000000000000001").to_value().into())
+                .is_err()
+        );
+        // the nearest f64 to 0.1 is slightly above it
+        assert!(schema.validate(&0.1f64.to_value().into()).is_ok());
+    }
+
     #[test]
     fn convert_to_int_schema() {
         let schema = number().required().int();
@@ -55,7 +55,7 @@ impl Validator for Fields {
             // Check for unexpected fields in the input
             for (ident, _) in input.items() {
                 if !self.0.contains_key(&ident.to_string()) {
-                    let path = ctx.path.child(xpath::Ident::parse(&ident.to_string()));
+                    let path = ctx.path.child(ident.clone());
                     error.errors.push(
                         ValidError::new(path)
                             .message(&format!("unexpected field '{}'", &ident))
@@ -67,7 +67,7 @@ impl Validator for Fields {
             // Validator all schema-defined fields
             for (name, schema) in &self.0 {
                 let mut next = ctx.clone();
-                next.path = ctx.path.child(xpath::Ident::parse(name));
+                next.path = ctx.path.child(xpath::Ident::key(name));
                 next.value = input
                     .field(xpath::Ident::key(name))
                     .map(|v| v.to_value())
@@ -32,7 +32,7 @@ impl From<Max> for Rule {
 
 impl Validator for Max {
     fn validate(&self, ctx: &Context) -> Result<xval::Value, ValidError> {
-        if ctx.value.is_array() || ctx.value.is_string() {
+        if ctx.value.is_array() || ctx.value.is_string() || ctx.value.is_bytes() {
             if ctx.value.len() > self.0.to_usize() {
                 return Err(ctx.error(&format!(
                     "length must be at most {}, got {}",
@@ -4,6 +4,8 @@ mod index_out_of_bounds;
 mod invalid_index;
 mod not_callable;
 mod not_iterable;
+mod overflow;
+mod precision_loss;
 mod type_error;
 mod undefined_field;
 mod undefined_pipe;
@@ -16,6 +18,8 @@ pub use index_out_of_bounds::*;
 pub use invalid_index::*;
 pub use not_callable::*;
 pub use not_iterable::*;
+pub use overflow::*;
+pub use precision_loss::*;
 pub use type_error::*;
 pub use undefined_field::*;
 pub use undefined_pipe::*;
@@ -53,6 +57,10 @@ pub enum EvalError {
     IndexOutOfBounds(IndexOutOfBoundsError),
     TypeError(TypeError),
     DivisionByZero(DivisionByZeroError),
+    Overflow(OverflowError),
+    PrecisionLoss(PrecisionLossError),
+    /// Any other failure reported by `xval`.
+    Value(xval::ValueError),
     NotCallable(NotCallableError),
     NotIterable(NotIterableError),
     InvalidIndex(InvalidIndexError),
@@ -93,6 +101,9 @@ impl std::fmt::Display for EvalError {
             Self::IndexOutOfBounds(e) => write!(f, "{e}"),
             Self::TypeError(e) => write!(f, "{e}"),
             Self::DivisionByZero(e) => write!(f, "{e}"),
+            Self::Overflow(e) => write!(f, "{e}"),
+            Self::PrecisionLoss(e) => write!(f, "{e}"),
+            Self::Value(e) => write!(f, "{e}"),
             Self::NotCallable(e) => write!(f, "{e}"),
             Self::NotIterable(e) => write!(f, "{e}"),
             Self::InvalidIndex(e) => write!(f, "{e}"),
@@ -110,6 +121,8 @@ pub fn is_truthy(val: &xval::Value) -> bool {
         xval::Value::Bool(b) => b.to_bool(),
         xval::Value::Number(n) => n.to_f64() != 0.0,
         xval::Value::String(s) => !s.as_str().is_empty(),
+        xval::Value::Bytes(b) => !b.is_empty(),
+        xval::Value::Time(t) => !t.is_duration() || !t.as_duration().is_zero(),
         xval::Value::Object(o) => !o.is_empty(),
     }
 }
@@ -125,6 +138,28 @@ pub fn expect_number<'a>(val: &'a xval::Value, span: Span) -> Result<&'a xval::N
     }
 }
 
+/// Maps a failed [`xval::Number`] checked operation to an eval error.
+pub fn arithmetic_error(err: xval::ValueError, span: Span) -> EvalError {
+    match err {
+        xval::ValueError::DivisionByZero => EvalError::DivisionByZero(DivisionByZeroError),
+        xval::ValueError::Overflow { value, target } => {
+            EvalError::Overflow(OverflowError { value, target })
+        }
+        xval::ValueError::PrecisionLoss { value, target } => {
+            EvalError::PrecisionLoss(PrecisionLossError { value, target })
+        }
+        xval::ValueError::TypeMismatch { expected, received } => EvalError::TypeError(TypeError {
+            expected,
+            got: received,
+        }),
+        xval::ValueError::OutOfBounds { index, len } => {
+            EvalError::IndexOutOfBounds(IndexOutOfBoundsError { index, len })
+        }
+        other => EvalError::Value(other),
+    }
+    .with_span(span)
+}
+
 pub fn value_to_usize(val: &xval::Value, span: Span) -> Result<usize> {
     let n = expect_number(val, span.clone())?;
     let v = n.to_i64();
@@ -141,6 +176,8 @@ pub fn value_type_name(val: &xval::Value) -> String {
         xval::Value::Bool(_) => "bool".to_string(),
         xval::Value::Number(_) => "number".to_string(),
         xval::Value::String(_) => "string".to_string(),
+        xval::Value::Bytes(_) => "bytes".to_string(),
+        xval::Value::Time(t) => t.type_name().to_string(),
         xval::Value::Object(o) => o.name().to_string(),
     }
 }
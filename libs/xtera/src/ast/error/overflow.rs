@@ -0,0 +1,11 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverflowError {
+    pub value: String,
+    pub target: &'static str,
+}
+
+impl std::fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} is out of range for {}", self.value, self.target)
+    }
+}
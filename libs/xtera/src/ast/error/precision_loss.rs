@@ -0,0 +1,15 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrecisionLossError {
+    pub value: String,
+    pub target: &'static str,
+}
+
+impl std::fmt::Display for PrecisionLossError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} cannot be represented exactly as {}",
+            self.value, self.target
+        )
+    }
+}
@@ -1,7 +1,5 @@
 use crate::Scope;
-use crate::ast::{
-    BinaryOp, DivisionByZeroError, EvalError, Result, Span, expect_number, is_truthy,
-};
+use crate::ast::{BinaryOp, Result, Span, arithmetic_error, expect_number, is_truthy};
 
 use super::Expr;
 
@@ -92,50 +90,18 @@ impl BinaryExpr {
         r: &xval::Number,
         span: Span,
     ) -> Result<xval::Value> {
-        if l.is_float() || r.is_float() {
-            let lf = l.to_f64();
-            let rf = r.to_f64();
-            return match op {
-                BinaryOp::Add => Ok(xval::valueof!((lf + rf))),
-                BinaryOp::Sub => Ok(xval::valueof!((lf - rf))),
-                BinaryOp::Mul => Ok(xval::valueof!((lf * rf))),
-                BinaryOp::Div => {
-                    if rf == 0.0 {
-                        return Err(EvalError::DivisionByZero(DivisionByZeroError).with_span(span));
-                    }
-                    Ok(xval::valueof!((lf / rf)))
-                }
-                BinaryOp::Mod => {
-                    if rf == 0.0 {
-                        return Err(EvalError::DivisionByZero(DivisionByZeroError).with_span(span));
-                    }
-                    Ok(xval::valueof!((lf % rf)))
-                }
-                _ => unreachable!(),
-            };
-        }
-
-        let li = l.to_i64();
-        let ri = r.to_i64();
-
-        match op {
-            BinaryOp::Add => Ok(xval::valueof!((li.wrapping_add(ri)))),
-            BinaryOp::Sub => Ok(xval::valueof!((li.wrapping_sub(ri)))),
-            BinaryOp::Mul => Ok(xval::valueof!((li.wrapping_mul(ri)))),
-            BinaryOp::Div => {
-                if ri == 0 {
-                    return Err(EvalError::DivisionByZero(DivisionByZeroError).with_span(span));
-                }
-                Ok(xval::valueof!((li.wrapping_div(ri))))
-            }
-            BinaryOp::Mod => {
-                if ri == 0 {
-                    return Err(EvalError::DivisionByZero(DivisionByZeroError).with_span(span));
-                }
-                Ok(xval::valueof!((li.wrapping_rem(ri))))
-            }
+        let result = match op {
+            BinaryOp::Add => l.checked_add(*r),
+            BinaryOp::Sub => l.checked_sub(*r),
+            BinaryOp::Mul => l.checked_mul(*r),
+            BinaryOp::Div => l.checked_div(*r),
+            BinaryOp::Mod => l.checked_rem(*r),
             _ => unreachable!(),
-        }
+        };
+
+        result
+            .map(xval::Value::Number)
+            .map_err(|err| arithmetic_error(err, span))
     }
 }
 
@@ -1,5 +1,3 @@
-use std::collections::HashMap;
-
 use crate::Scope;
 use crate::ast::{Result, Span};
 
@@ -13,11 +11,11 @@ pub struct ObjectExpr {
 
 impl ObjectExpr {
     pub fn eval(&self, scope: &Scope) -> Result<xval::Value> {
-        let mut map = HashMap::new();
+        let mut map = xval::OrderedMap::new();
         for (key, val_expr) in &self.entries {
             map.insert(xval::Ident::key(key), val_expr.eval(scope)?);
         }
-        Ok(xval::valueof!((map)))
+        Ok(xval::Value::from_struct(map))
     }
 }
 
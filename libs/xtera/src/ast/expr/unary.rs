@@ -1,5 +1,5 @@
 use crate::Scope;
-use crate::ast::{Result, Span, UnaryOp, expect_number, is_truthy};
+use crate::ast::{Result, Span, UnaryOp, arithmetic_error, expect_number, is_truthy};
 
 use super::Expr;
 
@@ -17,11 +17,9 @@ impl UnaryExpr {
             UnaryOp::Not => Ok(xval::valueof!((!is_truthy(&val)))),
             UnaryOp::Neg => {
                 let n = expect_number(&val, self.span.clone())?;
-                if n.is_float() {
-                    Ok(xval::valueof!((-n.to_f64())))
-                } else {
-                    Ok(xval::valueof!((n.to_i64().wrapping_neg())))
-                }
+                n.checked_neg()
+                    .map(xval::Value::Number)
+                    .map_err(|err| arithmetic_error(err, self.span.clone()))
             }
         }
     }
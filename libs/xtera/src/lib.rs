@@ -221,6 +221,41 @@ mod tests {
         assert!(matches!(err.inner(), ast::EvalError::DivisionByZero(_)));
     }
 
+    #[test]
+    fn int_math_is_exact() {
+        assert_eq!(render("{{ 9007199254740993 + 1 }}"), "9007199254740994");
+        assert_eq!(
+            render("{{ 9223372036854775807 + 1 }}"),
+            "9223372036854775808"
+        );
+        assert_eq!(render("{{ 7 / 2 }}"), "3");
+    }
+
+    #[test]
+    fn overflow() {
+        let err = render_err(
+            "{{ 9223372036854775807 * 9223372036854775807 * 9223372036854775807 }}",
+            &Scope::new(),
+        );
+        assert!(matches!(err.inner(), ast::EvalError::Overflow(_)));
+    }
+
+    #[test]
+    fn decimal_precision_loss() {
+        let mut s = Scope::new();
+        s.set_var(
+            "a",
+            xval::json::from_str_decimal("0.00000000000000000001").unwrap(),
+        );
+
+        let err = render_err("{{ a * a }}", &s);
+        assert!(matches!(err.inner(), ast::EvalError::PrecisionLoss(_)));
+
+        s.set_var("b", xval::json::from_str_decimal("1.0").unwrap());
+        let err = render_err("{{ b / 3 }}", &s);
+        assert!(matches!(err.inner(), ast::EvalError::PrecisionLoss(_)));
+    }
+
     #[test]
     fn comparison() {
         assert_eq!(render("{{ 1 < 2 }}"), "true");
@@ -372,4 +407,13 @@ mod tests {
         s.set_template("main", tpl);
         assert_eq!(s.render("main").unwrap(), "10");
     }
+
+    #[test]
+    fn interpolation_of_object() {
+        let mut s = Scope::new();
+        s.set_var("user", xval::valueof!({ "name": "bob", "tags": ["a"] }));
+        let tpl = Template::parse("{{ user }}").unwrap();
+        s.set_template("main", tpl);
+        assert_eq!(s.render("main").unwrap(), r#"{"name":"bob","tags":["a"]}"#);
+    }
 }
@@ -0,0 +1,309 @@
+use syn::ext::IdentExt;
+
+/// `#[value(rename_all = "...")]` case conventions, as serde spells them.
+#[derive(Clone, Copy)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn parse(lit: &syn::LitStr) -> syn::Result<Self> {
+        Ok(match lit.value().as_str() {
+            "lowercase" => Self::Lower,
+            "UPPERCASE" => Self::Upper,
+            "PascalCase" => Self::Pascal,
+            "camelCase" => Self::Camel,
+            "snake_case" => Self::Snake,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnake,
+            "kebab-case" => Self::Kebab,
+            "SCREAMING-KEBAB-CASE" => Self::ScreamingKebab,
+            other => {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    format!("unknown rename rule `{}`", other),
+                ));
+            }
+        })
+    }
+
+    /// Rename a PascalCase variant name.
+    pub fn apply_to_variant(self, name: &str) -> String {
+        match self {
+            Self::Pascal => name.to_string(),
+            Self::Lower => name.to_ascii_lowercase(),
+            Self::Upper => name.to_ascii_uppercase(),
+            Self::Camel => {
+                let mut chars = name.chars();
+
+                match chars.next() {
+                    Some(first) => first.to_lowercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            }
+            _ => {
+                let mut snake = String::with_capacity(name.len() + 4);
+
+                for (i, c) in name.char_indices() {
+                    if i > 0 && c.is_uppercase() {
+                        snake.push('_');
+                    }
+
+                    snake.push(c.to_ascii_lowercase());
+                }
+
+                self.apply_to_field(&snake)
+            }
+        }
+    }
+
+    /// Rename a snake_case field name.
+    pub fn apply_to_field(self, name: &str) -> String {
+        match self {
+            Self::Lower | Self::Snake => name.to_string(),
+            Self::Upper | Self::ScreamingSnake => name.to_ascii_uppercase(),
+            Self::Kebab => name.replace('_', "-"),
+            Self::ScreamingKebab => name.replace('_', "-").to_ascii_uppercase(),
+            Self::Pascal | Self::Camel => {
+                let mut out = String::with_capacity(name.len());
+                let mut upper = matches!(self, Self::Pascal);
+
+                for c in name.chars() {
+                    if c == '_' {
+                        upper = true;
+                    } else if upper {
+                        out.push(c.to_ascii_uppercase());
+                        upper = false;
+                    } else {
+                        out.push(c);
+                    }
+                }
+
+                out
+            }
+        }
+    }
+}
+
+/// How an enum records which variant a value holds.
+#[derive(Default)]
+pub enum Repr {
+    /// `{ "Variant": payload }`, or `"Variant"` for unit variants.
+    #[default]
+    External,
+    /// `{ tag: "Variant", ..fields }`.
+    Internal { tag: String },
+    /// `{ tag: "Variant", content: payload }`.
+    Adjacent { tag: String, content: String },
+    /// The payload alone.
+    Untagged,
+}
+
+/// Attributes on the struct or enum.
+#[derive(Default)]
+pub struct Container {
+    pub rename_all: Option<RenameRule>,
+    pub repr: Repr,
+}
+
+impl Container {
+    pub fn parse(input: &syn::DeriveInput) -> syn::Result<Self> {
+        let mut out = Self::default();
+        let mut tag = None;
+        let mut content = None;
+        let mut untagged = false;
+
+        for attr in input.attrs.iter().filter(|a| a.path().is_ident("value")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename_all") {
+                    out.rename_all = Some(RenameRule::parse(&meta.value()?.parse()?)?);
+                } else if meta.path.is_ident("tag") {
+                    tag = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                } else if meta.path.is_ident("content") {
+                    content = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                } else if meta.path.is_ident("untagged") {
+                    untagged = true;
+                } else {
+                    return Err(meta.error("unknown container attribute"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        out.repr = match (tag, content, untagged) {
+            (None, None, false) => Repr::External,
+            (Some(tag), None, false) => Repr::Internal { tag },
+            (Some(tag), Some(content), false) => Repr::Adjacent { tag, content },
+            (None, None, true) => Repr::Untagged,
+            (None, Some(_), false) => {
+                return Err(syn::Error::new_spanned(input, "`content` requires `tag`"));
+            }
+            (_, _, true) => {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    "`untagged` cannot be combined with `tag` or `content`",
+                ));
+            }
+        };
+
+        if !matches!(input.data, syn::Data::Enum(_)) && !matches!(out.repr, Repr::External) {
+            return Err(syn::Error::new_spanned(
+                input,
+                "`tag`, `content` and `untagged` only apply to enums",
+            ));
+        }
+
+        Ok(out)
+    }
+}
+
+/// An enum variant and its `#[value(...)]` attributes.
+pub struct Variant<'a> {
+    pub ident: &'a syn::Ident,
+    /// The name the variant is recorded under.
+    pub name: String,
+    /// Attributes for the variant's own fields.
+    pub fields: Container,
+}
+
+impl<'a> Variant<'a> {
+    pub fn parse(variant: &'a syn::Variant, container: &Container) -> syn::Result<Self> {
+        let mut rename = None;
+        let mut fields = Container::default();
+
+        for attr in variant.attrs.iter().filter(|a| a.path().is_ident("value")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    rename = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                } else if meta.path.is_ident("rename_all") {
+                    fields.rename_all = Some(RenameRule::parse(&meta.value()?.parse()?)?);
+                } else {
+                    return Err(meta.error("unknown variant attribute"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        let ident = &variant.ident;
+        let unraw = ident.unraw().to_string();
+        let name = match (rename, container.rename_all) {
+            (Some(name), _) => name,
+            (None, Some(rule)) => rule.apply_to_variant(&unraw),
+            (None, None) => unraw,
+        };
+
+        Ok(Self {
+            ident,
+            name,
+            fields,
+        })
+    }
+}
+
+/// A named field and its `#[value(...)]` attributes.
+pub struct Field<'a> {
+    pub ident: &'a syn::Ident,
+    /// The key the field is written under.
+    pub name: String,
+    pub skip: bool,
+    pub skip_if: Option<syn::Path>,
+    pub flatten: bool,
+    pub with: Option<syn::Path>,
+}
+
+impl<'a> Field<'a> {
+    pub fn parse(field: &'a syn::Field, container: &Container) -> syn::Result<Self> {
+        let ident = field.ident.as_ref().expect("named field");
+        let mut rename = None;
+        let mut out = Self {
+            ident,
+            name: String::new(),
+            skip: false,
+            skip_if: None,
+            flatten: false,
+            with: None,
+        };
+
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("value")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    rename = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                } else if meta.path.is_ident("skip") {
+                    out.skip = true;
+                } else if meta.path.is_ident("skip_if") {
+                    out.skip_if = Some(path(&meta)?);
+                } else if meta.path.is_ident("flatten") {
+                    out.flatten = true;
+                } else if meta.path.is_ident("with") {
+                    out.with = Some(path(&meta)?);
+                } else {
+                    return Err(meta.error("unknown field attribute"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        if out.flatten && (rename.is_some() || out.with.is_some()) {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "`flatten` cannot be combined with `rename` or `with`",
+            ));
+        }
+
+        let unraw = ident.unraw().to_string();
+        out.name = match (rename, container.rename_all) {
+            (Some(name), _) => name,
+            (None, Some(rule)) => rule.apply_to_field(&unraw),
+            (None, None) => unraw,
+        };
+
+        Ok(out)
+    }
+
+    /// Parse every field, in declaration order.
+    pub fn parse_all(
+        fields: &'a syn::FieldsNamed,
+        container: &Container,
+    ) -> syn::Result<Vec<Self>> {
+        fields
+            .named
+            .iter()
+            .map(|f| Self::parse(f, container))
+            .collect()
+    }
+}
+
+/// Reject `#[value(...)]` on fields that have no name to apply it to.
+pub fn reject_unnamed(fields: &syn::FieldsUnnamed) -> syn::Result<()> {
+    match fields
+        .unnamed
+        .iter()
+        .flat_map(|f| &f.attrs)
+        .find(|a| a.path().is_ident("value"))
+    {
+        Some(attr) => Err(syn::Error::new_spanned(
+            attr,
+            "field attributes are only supported on named fields",
+        )),
+        None => Ok(()),
+    }
+}
+
+/// `key = path` or `key = "path"`.
+fn path(meta: &syn::meta::ParseNestedMeta) -> syn::Result<syn::Path> {
+    let value = meta.value()?;
+
+    match value.peek(syn::LitStr) {
+        true => value.parse::<syn::LitStr>()?.parse(),
+        false => value.parse(),
+    }
+}
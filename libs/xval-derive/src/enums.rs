@@ -1,53 +1,33 @@
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+
+use crate::{
+    attrs::{self, Container, Field, Repr, Variant},
+    structs,
+};
 
 pub fn derive(input: &syn::DeriveInput, data: &syn::DataEnum) -> TokenStream {
+    match derive_enum(input, data) {
+        Ok(v) => v.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn derive_enum(
+    input: &syn::DeriveInput,
+    data: &syn::DataEnum,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let container = Container::parse(input)?;
     let ident = &input.ident;
     let (impl_generics, type_generics, where_generics) = input.generics.split_for_impl();
-    let match_arms = data.variants.iter().map(|variant| {
-        let variant_ident = &variant.ident;
-
-        match &variant.fields {
-            syn::Fields::Named(fields) => {
-                let field_idents: Vec<_> = fields
-                    .named
-                    .iter()
-                    .filter_map(|f| f.ident.as_ref())
-                    .collect();
-
-                quote! {
-                    Self::#variant_ident { #( #field_idents ),* } => {
-                        let mut map = ::std::collections::BTreeMap::new();
-                        #(
-                            map.insert(
-                                ::xval::Ident::from(stringify!(#field_idents)),
-                                #field_idents.to_value(),
-                            );
-                        )*
-                        ::xval::Value::from_struct(map)
-                    }
-                }
-            }
-            syn::Fields::Unnamed(fields) => {
-                let bindings: Vec<syn::Ident> = (0..fields.unnamed.len())
-                    .map(|i| quote::format_ident!("_{}", i))
-                    .collect();
-
-                quote! {
-                    Self::#variant_ident( #( #bindings ),* ) => {
-                        ::xval::Value::from_tuple(( #( #bindings.to_value(), )* ))
-                    }
-                }
-            }
-            syn::Fields::Unit => {
-                quote! {
-                    Self::#variant_ident => ::xval::Value::Null
-                }
-            }
-        }
-    });
+    let match_arms = data
+        .variants
+        .iter()
+        .map(|variant| match_arm(variant, &container))
+        .collect::<syn::Result<Vec<_>>>()?;
 
-    quote! {
+    Ok(quote! {
         impl #impl_generics ::xval::ToValue for #ident #type_generics #where_generics {
             fn to_value(&self) -> ::xval::Value {
                 match self {
@@ -55,6 +35,108 @@ pub fn derive(input: &syn::DeriveInput, data: &syn::DataEnum) -> TokenStream {
                 }
             }
         }
-    }
-    .into()
+    })
+}
+
+/// One `match self` arm. Every struct or tuple built for the variant is
+/// wrapped in [`xval::Named`] so `Object::name()` reports the variant.
+fn match_arm(
+    variant: &syn::Variant,
+    container: &Container,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let attrs = Variant::parse(variant, container)?;
+    let variant_ident = attrs.ident;
+    let name = &attrs.name;
+    let named = |inserts: proc_macro2::TokenStream| {
+        quote! {{
+            let mut map = ::xval::OrderedMap::new();
+            #inserts
+            ::xval::Value::from_struct(::xval::Named::new(#name, map))
+        }}
+    };
+
+    let (pattern, payload, inserts) = match &variant.fields {
+        syn::Fields::Named(fields) => {
+            let fields = Field::parse_all(fields, &attrs.fields)?;
+            let fields: Vec<_> = fields.iter().filter(|f| !f.skip).collect();
+            let bindings = fields.iter().map(|f| f.ident);
+            let inserts = structs::insert_fields(&fields, |f| {
+                let ident = f.ident;
+                quote!(#ident)
+            });
+            let inserts = quote!(#( #inserts )*);
+
+            (
+                quote!(Self::#variant_ident { #( #bindings, )* .. }),
+                Some(named(inserts.clone())),
+                Some(inserts),
+            )
+        }
+        syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            attrs::reject_unnamed(fields)?;
+            let ty = &fields.unnamed[0].ty;
+
+            (
+                quote!(Self::#variant_ident(_0)),
+                Some(quote!(::xval::ToValue::to_value(_0))),
+                // An internal tag is inserted next to the payload's fields,
+                // so the payload must be a `Struct`; anything else fails to
+                // compile here, pointing at the field's type.
+                Some(quote_spanned! {ty.span()=>
+                    for (k, v) in ::xval::Struct::items(_0) {
+                        map.insert(k, ::xval::ToValue::to_value(v));
+                    }
+                }),
+            )
+        }
+        syn::Fields::Unnamed(fields) => {
+            attrs::reject_unnamed(fields)?;
+
+            let bindings: Vec<syn::Ident> = (0..fields.unnamed.len())
+                .map(|i| quote::format_ident!("_{}", i))
+                .collect();
+
+            (
+                quote!(Self::#variant_ident( #( #bindings ),* )),
+                Some(quote! {
+                    ::xval::Value::from_tuple(::xval::Named::new(
+                        #name,
+                        ( #( ::xval::ToValue::to_value(#bindings), )* ),
+                    ))
+                }),
+                None,
+            )
+        }
+        syn::Fields::Unit => (quote!(Self::#variant_ident), None, Some(quote!())),
+    };
+
+    let value = match (&container.repr, payload) {
+        (Repr::External, None) => quote!(::xval::Value::from(#name)),
+        (Repr::External, Some(payload)) => named(quote! {
+            map.insert(::xval::Ident::from(#name), #payload);
+        }),
+        (Repr::Internal { tag }, _) => match inserts {
+            Some(inserts) => named(quote! {
+                map.insert(::xval::Ident::from(#tag), ::xval::Value::from(#name));
+                #inserts
+            }),
+            None => {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "internally tagged enums cannot hold tuple variants",
+                ));
+            }
+        },
+        (Repr::Adjacent { tag, .. }, None) => named(quote! {
+            map.insert(::xval::Ident::from(#tag), ::xval::Value::from(#name));
+        }),
+        (Repr::Adjacent { tag, content }, Some(payload)) => named(quote! {
+            map.insert(::xval::Ident::from(#tag), ::xval::Value::from(#name));
+            map.insert(::xval::Ident::from(#content), #payload);
+        }),
+        (Repr::Untagged, None) => quote!(::xval::Value::Null),
+        (Repr::Untagged, Some(payload)) => payload,
+    };
+
+    Ok(quote!(#pattern => #value))
 }
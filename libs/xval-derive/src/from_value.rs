@@ -0,0 +1,380 @@
+use proc_macro::TokenStream;
+use quote::quote;
+
+use crate::attrs::{self, Container, Field, Repr, Variant};
+
+pub fn derive(input: &syn::DeriveInput) -> TokenStream {
+    let ident = &input.ident;
+    let generics = bounded_generics(&input.generics);
+    let (impl_generics, type_generics, where_generics) = generics.split_for_impl();
+    let body = match &input.data {
+        syn::Data::Struct(data) => derive_struct(input, data),
+        syn::Data::Enum(data) => derive_enum(input, data),
+        _ => Err(syn::Error::new_spanned(
+            input,
+            "FromValue cannot be derived for unions",
+        )),
+    };
+
+    let body = match body {
+        Ok(v) => v,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    quote! {
+        impl #impl_generics ::xval::FromValue for #ident #type_generics #where_generics {
+            fn from_value(
+                value: &::xval::Value,
+            ) -> ::std::result::Result<Self, ::xval::FromValueError> {
+                #body
+            }
+        }
+    }
+    .into()
+}
+
+/// The input's generics with `T: FromValue` added for every type
+/// parameter, since each field is read through `FromValue`.
+fn bounded_generics(generics: &syn::Generics) -> syn::Generics {
+    let mut generics = generics.clone();
+    let params: Vec<_> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    let where_clause = generics.make_where_clause();
+
+    for param in params {
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#param: ::xval::FromValue));
+    }
+
+    generics
+}
+
+fn derive_struct(
+    input: &syn::DeriveInput,
+    data: &syn::DataStruct,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let ident = &input.ident;
+
+    Ok(match &data.fields {
+        syn::Fields::Named(fields) => {
+            let container = Container::parse(input)?;
+            let construct = named_fields(quote!(Self), fields, &container)?;
+
+            quote! {
+                let object = match value {
+                    ::xval::Value::Object(::xval::Object::Struct(v)) => v,
+                    v => return Err(::xval::FromValueError::expected(stringify!(#ident), v)),
+                };
+
+                Ok(#construct)
+            }
+        }
+        syn::Fields::Unnamed(fields) => {
+            attrs::reject_unnamed(fields)?;
+            let construct = unnamed_fields(quote!(Self), fields);
+
+            quote! {
+                let items: Vec<&dyn ::xval::ToValue> = match value {
+                    ::xval::Value::Object(::xval::Object::Tuple(v)) => v.items().collect(),
+                    ::xval::Value::Object(::xval::Object::Array(v)) => v.items().collect(),
+                    v => return Err(::xval::FromValueError::expected(stringify!(#ident), v)),
+                };
+
+                #construct
+            }
+        }
+        syn::Fields::Unit => {
+            quote! {
+                match value {
+                    ::xval::Value::Null => Ok(Self),
+                    v => Err(::xval::FromValueError::expected(stringify!(#ident), v)),
+                }
+            }
+        }
+    })
+}
+
+/// Variants are matched by the name the representation records, except
+/// untagged enums, where each variant is tried in declaration order and the
+/// first one whose shape decodes successfully wins.
+fn derive_enum(
+    input: &syn::DeriveInput,
+    data: &syn::DataEnum,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let ident = &input.ident;
+    let container = Container::parse(input)?;
+    let variants = data
+        .variants
+        .iter()
+        .map(|variant| Variant::parse(variant, &container))
+        .collect::<syn::Result<Vec<_>>>()?;
+    let names: Vec<_> = variants.iter().map(|v| &v.name).collect();
+
+    if let Repr::Internal { .. } = container.repr
+        && let Some(variant) = data
+            .variants
+            .iter()
+            .find(|v| matches!(&v.fields, syn::Fields::Unnamed(f) if f.unnamed.len() > 1))
+    {
+        return Err(syn::Error::new_spanned(
+            variant,
+            "internally tagged enums cannot hold tuple variants",
+        ));
+    }
+    let bodies = data
+        .variants
+        .iter()
+        .zip(&variants)
+        .map(|(variant, attrs)| variant_body(variant, attrs))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let no_match = quote! {
+        ::xval::FromValueError::new(format!(
+            "no variant of {} matches {}",
+            stringify!(#ident),
+            value.type_name(),
+        ))
+    };
+
+    let unknown = quote! {
+        ::xval::FromValueError::new(format!(
+            "unknown variant `{}` of {}",
+            other,
+            stringify!(#ident),
+        ))
+    };
+
+    let tagged = |tag: &String| {
+        quote! {
+            let object = match value {
+                ::xval::Value::Object(::xval::Object::Struct(v)) => v,
+                _ => return Err(#no_match),
+            };
+
+            let tag: String = ::xval::ext::StructExt::get_as(object.as_ref(), #tag)?;
+        }
+    };
+
+    Ok(match &container.repr {
+        Repr::External => {
+            let units = data
+                .variants
+                .iter()
+                .zip(&variants)
+                .filter(|(v, _)| matches!(v.fields, syn::Fields::Unit))
+                .map(|(v, attrs)| {
+                    let variant_ident = &v.ident;
+                    let name = &attrs.name;
+                    quote!(#name => Ok(Self::#variant_ident),)
+                });
+
+            quote! {
+                if value.is_string() {
+                    return match value.as_str() {
+                        #( #units )*
+                        other => Err(#unknown),
+                    };
+                }
+
+                if let ::xval::Value::Object(::xval::Object::Struct(object)) = value {
+                    if object.len() == 1 {
+                        let (key, payload) = object.items().next().expect("one field");
+                        let key = key.to_string();
+                        let payload = ::xval::ToValue::to_value(payload);
+                        let value = &payload;
+
+                        return match key.as_str() {
+                            #(
+                                #names => (|| -> ::std::result::Result<Self, ::xval::FromValueError> {
+                                    #bodies
+                                })()
+                                .map_err(|err| err.at(#names)),
+                            )*
+                            other => Err(#unknown),
+                        };
+                    }
+                }
+
+                Err(#no_match)
+            }
+        }
+        Repr::Internal { tag } => {
+            let tagged = tagged(tag);
+
+            quote! {
+                #tagged
+
+                match tag.as_str() {
+                    #(
+                        #names => (|| -> ::std::result::Result<Self, ::xval::FromValueError> {
+                            #bodies
+                        })(),
+                    )*
+                    other => Err(#unknown.at(#tag)),
+                }
+            }
+        }
+        Repr::Adjacent { tag, content } => {
+            let tagged = tagged(tag);
+
+            quote! {
+                #tagged
+
+                let content = match ::xval::ext::StructExt::get(object.as_ref(), #content) {
+                    Some(v) => ::xval::ToValue::to_value(v),
+                    None => ::xval::Value::Null,
+                };
+                let value = &content;
+
+                match tag.as_str() {
+                    #(
+                        #names => (|| -> ::std::result::Result<Self, ::xval::FromValueError> {
+                            #bodies
+                        })()
+                        .map_err(|err| err.at(#content)),
+                    )*
+                    other => Err(#unknown.at(#tag)),
+                }
+            }
+        }
+        Repr::Untagged => {
+            let attempts = data.variants.iter().zip(&bodies).map(|(variant, body)| {
+                let variant_ident = &variant.ident;
+
+                match variant.fields {
+                    syn::Fields::Unit => quote! {
+                        if value.is_null() {
+                            return Ok(Self::#variant_ident);
+                        }
+                    },
+                    _ => quote! {
+                        let attempt = (|| -> ::std::result::Result<Self, ::xval::FromValueError> {
+                            #body
+                        })();
+
+                        if let Ok(v) = attempt {
+                            return Ok(v);
+                        }
+                    },
+                }
+            });
+
+            quote! {
+                #( #attempts )*
+
+                Err(#no_match)
+            }
+        }
+    })
+}
+
+/// Statements decoding one variant's payload from `value`, ending in a
+/// `Result<Self, FromValueError>`.
+fn variant_body(variant: &syn::Variant, attrs: &Variant) -> syn::Result<proc_macro2::TokenStream> {
+    let variant_ident = &variant.ident;
+    let name = &attrs.name;
+
+    Ok(match &variant.fields {
+        syn::Fields::Named(fields) => {
+            let construct = named_fields(quote!(Self::#variant_ident), fields, &attrs.fields)?;
+
+            quote! {
+                let object = match value {
+                    ::xval::Value::Object(::xval::Object::Struct(v)) => v,
+                    v => return Err(::xval::FromValueError::expected(#name, v)),
+                };
+
+                Ok(#construct)
+            }
+        }
+        syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            attrs::reject_unnamed(fields)?;
+
+            quote! {
+                Ok(Self::#variant_ident(::xval::FromValue::from_value(value)?))
+            }
+        }
+        syn::Fields::Unnamed(fields) => {
+            attrs::reject_unnamed(fields)?;
+            let construct = unnamed_fields(quote!(Self::#variant_ident), fields);
+
+            quote! {
+                let items: Vec<&dyn ::xval::ToValue> = match value {
+                    ::xval::Value::Object(::xval::Object::Tuple(v)) => v.items().collect(),
+                    ::xval::Value::Object(::xval::Object::Array(v)) => v.items().collect(),
+                    v => return Err(::xval::FromValueError::expected(#name, v)),
+                };
+
+                #construct
+            }
+        }
+        syn::Fields::Unit => quote!(Ok(Self::#variant_ident)),
+    })
+}
+
+/// Builds `path { field: ..., }` reading each field from a `Struct` bound to
+/// `object`. Skipped fields take their `Default`, and flattened fields decode
+/// from the whole `value`.
+fn named_fields(
+    path: proc_macro2::TokenStream,
+    fields: &syn::FieldsNamed,
+    container: &Container,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = Field::parse_all(fields, container)?;
+    let inits = fields.iter().map(|f| {
+        let ident = f.ident;
+        let name = &f.name;
+        let init = match (&f.with, f.skip, f.flatten) {
+            (_, true, _) => quote!(::std::default::Default::default()),
+            (_, _, true) => quote!(::xval::FromValue::from_value(value)?),
+            (Some(with), _, _) => quote! {
+                match ::xval::ext::StructExt::get(object.as_ref(), #name) {
+                    Some(v) => #with::from_value(&::xval::ToValue::to_value(v))
+                        .map_err(|err| err.at(#name))?,
+                    None => #with::from_value(&::xval::Value::Null)
+                        .map_err(|_| ::xval::FromValueError::missing(#name))?,
+                }
+            },
+            (None, _, _) => quote! {
+                ::xval::ext::StructExt::get_as(object.as_ref(), #name)?
+            },
+        };
+
+        quote!(#ident: #init)
+    });
+
+    Ok(quote! {
+        #path {
+            #( #inits, )*
+        }
+    })
+}
+
+/// Builds `Ok(path(...))` reading each field from a `Vec<&dyn ToValue>` bound to `items`.
+fn unnamed_fields(
+    path: proc_macro2::TokenStream,
+    fields: &syn::FieldsUnnamed,
+) -> proc_macro2::TokenStream {
+    let len = fields.unnamed.len();
+    let indices: Vec<_> = (0..len).map(proc_macro2::Literal::usize_suffixed).collect();
+
+    quote! {
+        if items.len() != #len {
+            return Err(::xval::FromValueError::new(format!(
+                "expected tuple of length {}, received length {}",
+                #len,
+                items.len(),
+            )));
+        }
+
+        Ok(#path(
+            #(
+                ::xval::FromValue::from_value(&::xval::ToValue::to_value(items[#indices]))
+                    .map_err(|err| err.at(#indices))?,
+            )*
+        ))
+    }
+}
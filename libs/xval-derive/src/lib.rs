@@ -1,18 +1,20 @@
+mod attrs;
 mod enums;
+mod from_value;
 mod structs;
 mod tuples;
 
 use proc_macro::TokenStream;
 use quote::quote;
 
-#[proc_macro_derive(Value)]
+#[proc_macro_derive(Value, attributes(value))]
 pub fn derive_value(input: TokenStream) -> TokenStream {
     let input = syn::parse_macro_input!(input as syn::DeriveInput);
 
     match &input.data {
         syn::Data::Struct(data) => match &data.fields {
-            syn::Fields::Named(_) => structs::derive(&input, data),
-            syn::Fields::Unnamed(_) => tuples::derive(&input, data),
+            syn::Fields::Named(fields) => structs::derive(&input, fields),
+            syn::Fields::Unnamed(fields) => tuples::derive(&input, fields),
             syn::Fields::Unit => {
                 let ident = &input.ident;
                 let (ig, tg, wg) = input.generics.split_for_impl();
@@ -32,3 +34,9 @@ pub fn derive_value(input: TokenStream) -> TokenStream {
             .into(),
     }
 }
+
+#[proc_macro_derive(FromValue, attributes(value))]
+pub fn derive_from_value(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    from_value::derive(&input)
+}
@@ -1,58 +1,265 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-pub fn derive(input: &syn::DeriveInput, data: &syn::DataStruct) -> TokenStream {
+use crate::attrs::{Container, Field};
+
+pub fn derive(input: &syn::DeriveInput, fields: &syn::FieldsNamed) -> TokenStream {
+    let container = match Container::parse(input) {
+        Ok(v) => v,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let fields = match Field::parse_all(fields, &container) {
+        Ok(v) => v,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
     let ident = &input.ident;
-    let fields: Vec<_> = data.fields.iter().filter_map(|f| f.ident.clone()).collect();
-    let len = fields.len();
     let (impl_generics, type_generics, where_generics) = input.generics.split_for_impl();
+    let fields: Vec<_> = fields.iter().filter(|f| !f.skip).collect();
+    let inserts = insert_fields(&fields, |f| {
+        let ident = f.ident;
+        quote!(&self.#ident)
+    });
 
-    quote! {
-        impl #impl_generics ::xval::ToValue for #ident #type_generics #where_generics {
-            fn to_value(&self) -> ::xval::Value {
-                let mut map = ::std::collections::BTreeMap::new();
-                #(
-                    map.insert(
-                        ::xval::Ident::from(stringify!(#fields)),
-                        self.#fields.to_value(),
-                    );
-                )*
-                ::xval::Value::from_struct(map)
+    // `with` fields have no `ToValue` of their own, so the borrowed view hands
+    // out `self` cast to a wrapper whose `ToValue` calls `with::to_value`.
+    let with: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .filter_map(|(i, f)| f.with.as_ref().map(|path| (i, f.ident, path)))
+        .map(|(i, field, path)| {
+            quote! {
+                impl #impl_generics ::xval::ToValue for __With<#ident #type_generics, #i>
+                #where_generics
+                {
+                    fn to_value(&self) -> ::xval::Value {
+                        #path::to_value(&self.0.#field)
+                    }
+                }
             }
-        }
+        })
+        .collect();
+
+    let refs: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| {
+            let field = f.ident;
 
-        impl #impl_generics ::xval::Struct for #ident #type_generics #where_generics {
-            fn name(&self) -> &str {
-                stringify!(#ident)
+            match f.with {
+                // SAFETY: `__With` is `repr(transparent)` over `Self`.
+                Some(_) => quote! {
+                    unsafe { &*::std::ptr::from_ref(self).cast::<__With<Self, #i>>() }
+                        as &dyn ::xval::ToValue
+                },
+                None => quote!(&self.#field as &dyn ::xval::ToValue),
             }
+        })
+        .collect();
+
+    let items = fields.iter().zip(&refs).map(|(f, value)| {
+        let field = f.ident;
+        let name = &f.name;
+        let push = match f.flatten {
+            true => borrow_flattened(f, quote!(&self.#field), quote!(items.extend(s.items()))),
+            false => quote! {
+                items.push((::xval::Ident::from(#name), #value));
+            },
+        };
 
-            fn type_id(&self) -> ::std::any::TypeId {
-                ::std::any::TypeId::of::<Self>()
+        skip_if(f, quote!(&self.#field), push)
+    });
+
+    let lookups = fields.iter().zip(&refs).map(|(f, value)| {
+        let field = f.ident;
+        let name = &f.name;
+        let lookup = match f.flatten {
+            true => borrow_flattened(
+                f,
+                quote!(&self.#field),
+                quote! {
+                    if let Some(v) = s.field(ident.clone()) {
+                        return Some(v);
+                    }
+                },
+            ),
+            false => quote! {
+                if ident == #name {
+                    return Some(#value);
+                }
+            },
+        };
+
+        skip_if(f, quote!(&self.#field), lookup)
+    });
+
+    // A flattened field that only converts to a struct has nothing to lend
+    // the borrowed view, so the whole struct is converted instead.
+    let owned = fields.iter().filter(|f| f.flatten).map(|f| {
+        let field = f.ident;
+        let check = quote! {
+            match ::xval::ToValue::as_value_ref(&self.#field) {
+                ::xval::ValueRef::Struct(_) => {}
+                v if v.is_null() => {}
+                _ => return ::xval::ValueRef::Owned(::xval::ToValue::to_value(self)),
             }
+        };
+
+        skip_if(f, quote!(&self.#field), check)
+    });
+
+    let len = match fields.iter().any(|f| f.flatten || f.skip_if.is_some()) {
+        true => quote!(self.items().count()),
+        false => {
+            let len = fields.len();
+            quote!(#len)
+        }
+    };
+
+    let wrapper = match with.is_empty() {
+        true => quote!(),
+        false => quote! {
+            #[repr(transparent)]
+            struct __With<T, const N: usize>(T);
+
+            #( #with )*
+        },
+    };
+
+    quote! {
+        const _: () = {
+            #wrapper
 
-            fn len(&self) -> usize {
-                #len
+            impl #impl_generics ::xval::ToValue for #ident #type_generics #where_generics {
+                fn to_value(&self) -> ::xval::Value {
+                    let mut map = ::xval::OrderedMap::new();
+                    #( #inserts )*
+                    ::xval::Value::from_struct(map)
+                }
+
+                fn as_value_ref(&self) -> ::xval::ValueRef<'_> {
+                    #( #owned )*
+                    ::xval::ValueRef::Struct(self)
+                }
             }
 
-            fn items(&self) -> ::xval::StructIter<'_> {
-                ::xval::StructIter::new(
-                    [#((
-                        ::xval::Ident::from(stringify!(#fields)),
-                        &self.#fields as &dyn ::xval::ToValue,
-                    ),)*].into_iter()
-                )
+            impl #impl_generics ::xval::Struct for #ident #type_generics #where_generics {
+                fn name(&self) -> &str {
+                    stringify!(#ident)
+                }
+
+                fn type_id(&self) -> ::std::any::TypeId {
+                    ::std::any::TypeId::of::<Self>()
+                }
+
+                fn len(&self) -> usize {
+                    #len
+                }
+
+                fn items(&self) -> ::xval::StructIter<'_> {
+                    let mut items: Vec<(::xval::Ident, &dyn ::xval::ToValue)> = Vec::new();
+                    #( #items )*
+                    ::xval::StructIter::new(items.into_iter())
+                }
+
+                fn field(&self, ident: ::xval::Ident) -> Option<&dyn ::xval::ToValue> {
+                    #( #lookups )*
+                    None
+                }
             }
+        };
+    }
+    .into()
+}
 
-            fn field(&self, ident: ::xval::Ident) -> Option<&dyn ::xval::ToValue> {
-                #(
-                    if ident == stringify!(#fields) {
-                        return Some(&self.#fields as &dyn ::xval::ToValue);
+/// Statements inserting each field into an `OrderedMap` bound to `map`, where
+/// `access` gives a reference to the field. Flattened fields insert the
+/// entries of the struct they borrow or convert to, nothing for null, and
+/// panic for anything else.
+pub fn insert_fields(
+    fields: &[&Field],
+    access: impl Fn(&Field) -> proc_macro2::TokenStream,
+) -> Vec<proc_macro2::TokenStream> {
+    fields
+        .iter()
+        .map(|f| {
+            let value = access(f);
+            let name = &f.name;
+            let insert = match (&f.with, f.flatten) {
+                (_, true) => {
+                    let field = f.ident;
+
+                    quote! {
+                        match ::xval::ToValue::as_value_ref(#value) {
+                            ::xval::ValueRef::Struct(s) => {
+                                for (k, v) in s.items() {
+                                    map.insert(k, ::xval::ToValue::to_value(v));
+                                }
+                            }
+                            v => match ::xval::ValueRef::to_value(&v) {
+                                ::xval::Value::Null => {}
+                                ::xval::Value::Object(::xval::Object::Struct(s)) => {
+                                    for (k, v) in s.items() {
+                                        map.insert(k, ::xval::ToValue::to_value(v));
+                                    }
+                                }
+                                v => panic!(
+                                    "cannot flatten field `{}`: expected struct, received {}",
+                                    stringify!(#field),
+                                    v.type_name(),
+                                ),
+                            },
+                        }
                     }
-                )*
+                }
+                (Some(path), false) => quote! {
+                    map.insert(::xval::Ident::from(#name), #path::to_value(#value));
+                },
+                (None, false) => quote! {
+                    map.insert(::xval::Ident::from(#name), ::xval::ToValue::to_value(#value));
+                },
+            };
 
-                None
-            }
+            skip_if(f, value, insert)
+        })
+        .collect()
+}
+
+/// Runs `body` with the struct a flattened field borrows as bound to `s`.
+/// Null contributes nothing, and anything else can't be lent to the
+/// borrowed view, which `as_value_ref` avoids by converting instead.
+fn borrow_flattened(
+    field: &Field,
+    value: proc_macro2::TokenStream,
+    body: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let ident = field.ident;
+
+    quote! {
+        match ::xval::ToValue::as_value_ref(#value) {
+            ::xval::ValueRef::Struct(s) => { #body }
+            v if v.is_null() => {}
+            v => panic!(
+                "cannot borrow flattened field `{}`: expected struct, received {}",
+                stringify!(#ident),
+                v.type_name(),
+            ),
         }
     }
-    .into()
+}
+
+fn skip_if(
+    field: &Field,
+    value: proc_macro2::TokenStream,
+    body: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    match &field.skip_if {
+        Some(path) => quote! {
+            if !#path(#value) {
+                #body
+            }
+        },
+        None => body,
+    }
 }
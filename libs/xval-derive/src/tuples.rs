@@ -1,9 +1,15 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-pub fn derive(input: &syn::DeriveInput, data: &syn::DataStruct) -> TokenStream {
+use crate::attrs;
+
+pub fn derive(input: &syn::DeriveInput, fields: &syn::FieldsUnnamed) -> TokenStream {
+    if let Err(err) = attrs::reject_unnamed(fields) {
+        return err.to_compile_error().into();
+    }
+
     let ident = &input.ident;
-    let len = data.fields.len();
+    let len = fields.unnamed.len();
     let indices: Vec<syn::Index> = (0..len).map(syn::Index::from).collect();
     let (impl_generics, type_generics, where_generics) = input.generics.split_for_impl();
 
@@ -12,6 +18,10 @@ pub fn derive(input: &syn::DeriveInput, data: &syn::DataStruct) -> TokenStream {
             fn to_value(&self) -> ::xval::Value {
                 ::xval::Value::from_tuple(( #( self.#indices.to_value(), )* ))
             }
+
+            fn as_value_ref(&self) -> ::xval::ValueRef<'_> {
+                ::xval::ValueRef::Tuple(self)
+            }
         }
 
         impl #impl_generics ::xval::Tuple for #ident #type_generics #where_generics {
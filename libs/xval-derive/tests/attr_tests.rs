@@ -0,0 +1,241 @@
+use xval::ext::StructExt;
+use xval::{FromValue, FromValueError, Struct, ToValue, Value, valueof};
+use xval_derive::{FromValue, Value};
+
+mod upper {
+    use xval::{FromValue, FromValueError, ToValue, Value};
+
+    pub fn to_value(value: &str) -> Value {
+        value.to_uppercase().to_value()
+    }
+
+    pub fn from_value(value: &Value) -> Result<String, FromValueError> {
+        String::from_value(value).map(|v| v.to_lowercase())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Value, FromValue)]
+#[value(rename_all = "camelCase")]
+struct Meta {
+    created_by: String,
+    #[value(skip_if = "Option::is_none")]
+    updated_by: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Value, FromValue)]
+#[value(rename_all = "camelCase")]
+struct Prompt {
+    #[value(rename = "id")]
+    prompt_id: i32,
+    max_tokens: i32,
+    #[value(skip)]
+    cache: Vec<String>,
+    #[value(with = upper)]
+    model_name: String,
+    #[value(flatten)]
+    meta: Meta,
+}
+
+#[derive(Debug, Clone, PartialEq, Value, FromValue)]
+enum Event {
+    Sent {
+        #[value(rename = "to")]
+        recipient: String,
+        #[value(skip)]
+        retries: u8,
+    },
+    #[value(rename_all = "SCREAMING_SNAKE_CASE")]
+    Failed { error_code: i32 },
+}
+
+#[derive(Debug, Clone, PartialEq, Value, FromValue)]
+#[value(rename_all = "camelCase")]
+enum Accent {
+    Élan,
+    Øre,
+}
+
+fn prompt() -> Prompt {
+    Prompt {
+        prompt_id: 1,
+        max_tokens: 64,
+        cache: vec!["x".into()],
+        model_name: "small".into(),
+        meta: Meta {
+            created_by: "alice".into(),
+            updated_by: None,
+        },
+    }
+}
+
+#[test]
+fn to_value_applies_attributes() {
+    assert_eq!(
+        prompt().to_value(),
+        valueof!({
+            "id": 1_i32,
+            "maxTokens": 64_i32,
+            "modelName": "SMALL",
+            "createdBy": "alice",
+        })
+    );
+}
+
+#[test]
+fn struct_view_matches_to_value() {
+    let p = prompt();
+    let keys: Vec<_> = p.items().map(|(k, _)| k.to_string()).collect();
+
+    assert_eq!(keys, ["id", "maxTokens", "modelName", "createdBy"]);
+    assert_eq!(p.len(), 4);
+    assert_eq!(p.get("modelName").unwrap().to_value().as_str(), "SMALL");
+    assert_eq!(p.get("createdBy").unwrap().to_value().as_str(), "alice");
+    assert!(p.get("prompt_id").is_none());
+    assert!(p.get("cache").is_none());
+    assert!(p.get("updatedBy").is_none());
+    assert_eq!(p.as_value_ref(), p.to_value());
+}
+
+#[test]
+fn skip_if_keeps_present_values() {
+    let mut p = prompt();
+    p.meta.updated_by = Some("bob".into());
+
+    assert_eq!(p.len(), 5);
+    assert_eq!(p.get("updatedBy").unwrap().to_value().as_str(), "bob");
+    assert_eq!(
+        p.to_value()
+            .as_struct()
+            .get("updatedBy")
+            .unwrap()
+            .to_value(),
+        "bob".to_value()
+    );
+}
+
+#[test]
+fn from_value_applies_attributes() {
+    let mut expected = prompt();
+    expected.cache = vec![];
+
+    assert_eq!(Prompt::from_value(&prompt().to_value()).unwrap(), expected);
+}
+
+#[test]
+fn from_value_errors_use_renamed_keys() {
+    let v = valueof!({ "id": 1_i32, "modelName": "a", "createdBy": "b" });
+    let err = Prompt::from_value(&v).unwrap_err();
+    assert_eq!(err, FromValueError::missing("maxTokens"));
+
+    let v = valueof!({ "id": 1_i32, "maxTokens": 1_i32, "modelName": 2_i32, "createdBy": "b" });
+    let err = Prompt::from_value(&v).unwrap_err();
+    assert_eq!(err.path.to_string(), "modelName");
+}
+
+#[test]
+fn enum_field_attributes() {
+    let sent = Event::Sent {
+        recipient: "bob".into(),
+        retries: 3,
+    };
+
+    assert_eq!(sent.to_value(), valueof!({ "Sent": { "to": "bob" } }));
+    assert_eq!(
+        Event::from_value(&sent.to_value()).unwrap(),
+        Event::Sent {
+            recipient: "bob".into(),
+            retries: 0,
+        }
+    );
+
+    let failed = Event::Failed { error_code: 7 };
+    assert_eq!(
+        failed.to_value(),
+        valueof!({ "Failed": { "ERROR_CODE": 7_i32 } })
+    );
+    assert_eq!(Event::from_value(&failed.to_value()).unwrap(), failed);
+}
+
+#[test]
+fn flatten_ignores_non_structs() {
+    #[derive(Value)]
+    struct Outer {
+        name: String,
+        #[value(flatten)]
+        extra: Option<Meta>,
+    }
+
+    let v = Outer {
+        name: "a".into(),
+        extra: None,
+    };
+
+    assert_eq!(v.to_value(), valueof!({ "name": "a" }));
+    assert_eq!(v.len(), 1);
+    assert_eq!(Value::from_struct(v).as_struct().len(), 1);
+}
+
+#[test]
+fn flatten_converted_struct() {
+    struct Labels;
+
+    impl ToValue for Labels {
+        fn to_value(&self) -> Value {
+            valueof!({ "env": "prod" })
+        }
+    }
+
+    #[derive(Value)]
+    struct Outer {
+        name: String,
+        #[value(flatten)]
+        labels: Labels,
+    }
+
+    let v = Outer {
+        name: "a".into(),
+        labels: Labels,
+    };
+    let expected = valueof!({ "name": "a", "env": "prod" });
+
+    assert_eq!(v.to_value(), expected);
+    assert!(matches!(v.as_value_ref(), xval::ValueRef::Owned(_)));
+    assert_eq!(v.as_value_ref(), expected);
+}
+
+#[test]
+#[should_panic(expected = "cannot flatten field `n`: expected struct, received i32")]
+fn flatten_rejects_scalars() {
+    #[derive(Value)]
+    struct Outer {
+        #[value(flatten)]
+        n: i32,
+    }
+
+    Outer { n: 1 }.to_value();
+}
+
+#[test]
+fn with_on_generic_struct() {
+    #[derive(Value)]
+    struct Tagged<T: ToValue + Send + Sync + 'static> {
+        value: T,
+        #[value(with = upper)]
+        tag: String,
+    }
+
+    let v = Tagged {
+        value: 1_i32,
+        tag: "a".into(),
+    };
+
+    assert_eq!(v.get("tag").unwrap().to_value().as_str(), "A");
+    assert_eq!(v.as_value_ref(), valueof!({ "value": 1_i32, "tag": "A" }));
+}
+
+#[test]
+fn camel_case_non_ascii() {
+    assert_eq!(Accent::Élan.to_value(), valueof!("élan"));
+    assert_eq!(Accent::Øre.to_value(), valueof!("øre"));
+    assert_eq!(Accent::from_value(&valueof!("øre")).unwrap(), Accent::Øre);
+}
@@ -1,8 +1,8 @@
-use xval::ToValue;
 use xval::ext::StructExt;
-use xval_derive::Value;
+use xval::{FromValue, ToValue, valueof};
+use xval_derive::{FromValue, Value};
 
-#[derive(Value)]
+#[derive(Debug, PartialEq, Value, FromValue)]
 enum Message {
     Disconnect,
     Text(String),
@@ -10,29 +10,71 @@ enum Message {
     Chat { user: String, text: String },
 }
 
+#[derive(Debug, PartialEq, Value, FromValue)]
+#[value(tag = "type", rename_all = "snake_case")]
+enum Internal {
+    Disconnect,
+    Join(Room),
+    Chat { user: String, text: String },
+}
+
+#[derive(Debug, PartialEq, Value, FromValue)]
+#[value(tag = "t", content = "c")]
+enum Adjacent {
+    Disconnect,
+    Text(String),
+    Pair(i32, bool),
+    #[value(rename = "chat")]
+    Chat {
+        user: String,
+        text: String,
+    },
+}
+
+#[derive(Debug, PartialEq, Value, FromValue)]
+#[value(untagged)]
+enum Untagged {
+    Disconnect,
+    Text(String),
+    Pair(i32, bool),
+    Chat { user: String, text: String },
+}
+
+#[derive(Debug, PartialEq, Value, FromValue)]
+struct Room {
+    room: String,
+}
+
+/// A `Struct` backing that isn't derived.
+#[derive(Value)]
+#[value(tag = "type")]
+enum Payload {
+    Labels(xval::OrderedMap),
+}
+
 #[test]
 fn enum_unit_variant() {
     let v = Message::Disconnect.to_value();
-    assert!(v.is_null());
+    assert_eq!(v, valueof!("Disconnect"));
 }
 
 #[test]
 fn enum_single_tuple_variant() {
     let v = Message::Text("hello".into()).to_value();
-    assert!(v.is_tuple());
-    assert_eq!(v.as_tuple().len(), 1);
-    assert_eq!(v.as_tuple().index(0).unwrap().to_value().as_str(), "hello");
+    assert_eq!(v, valueof!({ "Text": "hello" }));
+    assert_eq!(v.as_object().name(), "Text");
 }
 
 #[test]
 fn enum_multi_tuple_variant() {
     let v = Message::Pair(10, true).to_value();
-    assert!(v.is_tuple());
+    let t = v.as_struct().get("Pair").unwrap().to_value();
 
-    let t = v.as_tuple();
-    assert_eq!(t.len(), 2);
-    assert_eq!(t.index(0).unwrap().to_value().to_i32(), 10);
-    assert_eq!(t.index(1).unwrap().to_value().to_bool(), true);
+    assert!(t.is_tuple());
+    assert_eq!(t.as_object().name(), "Pair");
+    assert_eq!(t.as_tuple().len(), 2);
+    assert_eq!(t.as_tuple().index(0).unwrap().to_value().to_i32(), 10);
+    assert!(t.as_tuple().index(1).unwrap().to_value().to_bool());
 }
 
 #[test]
@@ -43,10 +85,136 @@ fn enum_named_variant() {
     }
     .to_value();
 
-    assert!(v.is_struct());
+    assert_eq!(v.as_object().name(), "Chat");
+
+    let s = v.as_struct().get("Chat").unwrap().to_value();
+    assert_eq!(s.as_object().name(), "Chat");
+    assert_eq!(s.as_struct().len(), 2);
+    assert_eq!(
+        s.as_struct().get("user").unwrap().to_value().as_str(),
+        "alice"
+    );
+    assert_eq!(s.as_struct().get("text").unwrap().to_value().as_str(), "hi");
+}
+
+#[test]
+fn externally_tagged_errors() {
+    let err = Message::from_value(&valueof!("Connect")).unwrap_err();
+    assert_eq!(err.message, "unknown variant `Connect` of Message");
+
+    let err = Message::from_value(&valueof!({ "Pair": (1_i32, "x") })).unwrap_err();
+    assert_eq!(err.path.to_string(), "Pair/1");
+}
+
+#[test]
+fn internally_tagged() {
+    let chat = Internal::Chat {
+        user: "alice".into(),
+        text: "hi".into(),
+    };
+
+    assert_eq!(
+        Internal::Disconnect.to_value(),
+        valueof!({ "type": "disconnect" })
+    );
+    assert_eq!(
+        Internal::Join(Room { room: "a".into() }).to_value(),
+        valueof!({ "type": "join", "room": "a" })
+    );
+    assert_eq!(
+        chat.to_value(),
+        valueof!({ "type": "chat", "user": "alice", "text": "hi" })
+    );
+    assert_eq!(chat.to_value().as_object().name(), "chat");
+    assert_eq!(
+        xval::json::to_string(&chat.to_value()),
+        r#"{"type":"chat","user":"alice","text":"hi"}"#
+    );
+
+    for v in [
+        Internal::Disconnect,
+        Internal::Join(Room { room: "a".into() }),
+        chat,
+    ] {
+        assert_eq!(Internal::from_value(&v.to_value()).unwrap(), v);
+    }
+
+    let err = Internal::from_value(&valueof!({ "type": "leave" })).unwrap_err();
+    assert_eq!(err.message, "unknown variant `leave` of Internal");
+    assert_eq!(err.path.to_string(), "type");
+
+    let err = Internal::from_value(&valueof!({ "user": "alice" })).unwrap_err();
+    assert_eq!(err.message, "missing field `type`");
+}
+
+#[test]
+fn internally_tagged_struct_backing() {
+    let mut labels = xval::OrderedMap::new();
+    labels.insert("x".into(), valueof!(1_i32));
+
+    assert_eq!(
+        Payload::Labels(labels).to_value(),
+        valueof!({ "type": "Labels", "x": 1_i32 })
+    );
+}
+
+#[test]
+fn adjacently_tagged() {
+    assert_eq!(
+        Adjacent::Disconnect.to_value(),
+        valueof!({ "t": "Disconnect" })
+    );
+    assert_eq!(
+        Adjacent::Text("hi".into()).to_value(),
+        valueof!({ "t": "Text", "c": "hi" })
+    );
+
+    let pair = Adjacent::Pair(1, true).to_value();
+    assert_eq!(pair.as_object().name(), "Pair");
+    assert!(pair.as_struct().get("c").unwrap().to_value().is_tuple());
+
+    for v in [
+        Adjacent::Disconnect,
+        Adjacent::Text("hi".into()),
+        Adjacent::Pair(1, true),
+        Adjacent::Chat {
+            user: "alice".into(),
+            text: "hi".into(),
+        },
+    ] {
+        assert_eq!(Adjacent::from_value(&v.to_value()).unwrap(), v);
+    }
+
+    let err = Adjacent::from_value(&valueof!({ "t": "chat", "c": { "user": 1_i32 } })).unwrap_err();
+    assert_eq!(err.path.to_string(), "c/user");
+}
+
+#[test]
+fn untagged() {
+    assert!(Untagged::Disconnect.to_value().is_null());
+    assert_eq!(Untagged::Text("hi".into()).to_value(), valueof!("hi"));
+
+    let pair = Untagged::Pair(1, true).to_value();
+    assert!(pair.is_tuple());
+    assert_eq!(pair.as_object().name(), "Pair");
+
+    let chat = Untagged::Chat {
+        user: "alice".into(),
+        text: "hi".into(),
+    }
+    .to_value();
+    assert_eq!(chat, valueof!({ "user": "alice", "text": "hi" }));
+    assert_eq!(chat.as_object().name(), "Chat");
 
-    let s = v.as_struct();
-    assert_eq!(s.len(), 2);
-    assert_eq!(s.get("user").unwrap().to_value().as_str(), "alice");
-    assert_eq!(s.get("text").unwrap().to_value().as_str(), "hi");
+    assert_eq!(
+        Untagged::from_value(&chat).unwrap(),
+        Untagged::Chat {
+            user: "alice".into(),
+            text: "hi".into(),
+        }
+    );
+    assert_eq!(
+        Untagged::from_value(&pair).unwrap(),
+        Untagged::Pair(1, true)
+    );
 }
@@ -0,0 +1,157 @@
+use xval::{FromValue, ToValue, valueof};
+use xval_derive::{FromValue, Value};
+
+#[derive(Debug, Clone, PartialEq, Value, FromValue)]
+struct Pair(i32, bool);
+
+#[derive(Debug, Clone, PartialEq, Value, FromValue)]
+struct User {
+    pub name: String,
+    pub tags: Vec<String>,
+    pub nickname: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Value, FromValue)]
+struct Team {
+    pub lead: User,
+    pub members: Vec<User>,
+}
+
+#[derive(Debug, Clone, PartialEq, Value, FromValue)]
+struct Unit;
+
+#[derive(Debug, Clone, PartialEq, Value, FromValue)]
+enum Message {
+    Disconnect,
+    Text(String),
+    Pair(i32, bool),
+    Chat { user: String, text: String },
+}
+
+#[derive(Debug, Clone, PartialEq, FromValue)]
+struct Wrapper<T> {
+    value: T,
+}
+
+#[derive(Debug, Clone, PartialEq, FromValue)]
+enum Either<L, R> {
+    Left(L),
+    Right { value: R },
+}
+
+fn user(name: &str) -> User {
+    User {
+        name: name.to_string(),
+        tags: vec!["a".into()],
+        nickname: None,
+    }
+}
+
+#[test]
+fn struct_round_trip() {
+    let original = user("alice");
+    assert_eq!(User::from_value(&original.to_value()).unwrap(), original);
+}
+
+#[test]
+fn struct_missing_optional_field() {
+    let v = valueof!({ "name": "bob", "tags": [] });
+    let u = User::from_value(&v).unwrap();
+    assert_eq!(u.name, "bob");
+    assert_eq!(u.nickname, None);
+}
+
+#[test]
+fn struct_missing_required_field() {
+    let v = valueof!({ "tags": [] });
+    let err = User::from_value(&v).unwrap_err();
+    assert_eq!(err.message, "missing field `name`");
+}
+
+#[test]
+fn struct_type_mismatch() {
+    let err = User::from_value(&valueof!([1_i32])).unwrap_err();
+    assert_eq!(err.message, "expected User, received Vec");
+}
+
+#[test]
+fn nested_error_path() {
+    let v = valueof!({
+        "lead": { "name": "alice", "tags": [] },
+        "members": [
+            { "name": "bob", "tags": [] },
+            { "name": "carol", "tags": ["x", 1_i32] },
+        ],
+    });
+
+    let err = Team::from_value(&v).unwrap_err();
+    assert_eq!(err.path.to_string(), "members/1/tags/1");
+}
+
+#[test]
+fn nested_round_trip() {
+    let original = Team {
+        lead: user("alice"),
+        members: vec![user("bob"), user("carol")],
+    };
+
+    assert_eq!(Team::from_value(&original.to_value()).unwrap(), original);
+}
+
+#[test]
+fn tuple_struct_round_trip() {
+    let original = Pair(7, true);
+    assert_eq!(Pair::from_value(&original.to_value()).unwrap(), original);
+}
+
+#[test]
+fn tuple_struct_error_path() {
+    let err = Pair::from_value(&(1_i32, "no").to_value()).unwrap_err();
+    assert_eq!(err.path.to_string(), "1");
+}
+
+#[test]
+fn unit_struct() {
+    assert_eq!(Unit::from_value(&valueof!(null)).unwrap(), Unit);
+    assert!(Unit::from_value(&valueof!(1_i32)).is_err());
+}
+
+#[test]
+fn enum_variants() {
+    for original in [
+        Message::Disconnect,
+        Message::Text("hi".into()),
+        Message::Pair(1, false),
+        Message::Chat {
+            user: "alice".into(),
+            text: "hi".into(),
+        },
+    ] {
+        assert_eq!(Message::from_value(&original.to_value()).unwrap(), original);
+    }
+}
+
+#[test]
+fn enum_no_match() {
+    let err = Message::from_value(&valueof!(1_i32)).unwrap_err();
+    assert_eq!(err.message, "no variant of Message matches i32");
+}
+
+#[test]
+fn generic_struct() {
+    let w = Wrapper::<i64>::from_value(&valueof!({ "value": 5_i32 })).unwrap();
+    assert_eq!(w, Wrapper { value: 5 });
+}
+
+#[test]
+fn generic_enum() {
+    let v = valueof!({ "Right": { "value": "x" } });
+    assert_eq!(
+        Either::<i32, String>::from_value(&v).unwrap(),
+        Either::Right { value: "x".into() }
+    );
+    assert_eq!(
+        Either::<i32, String>::from_value(&valueof!({ "Left": 1_i32 })).unwrap(),
+        Either::Left(1)
+    );
+}
@@ -72,14 +72,13 @@ fn generic_struct_where_clause() {
 #[test]
 fn generic_enum_unit_variant() {
     let v = Maybe::<i32>::Nothing.to_value();
-    assert!(v.is_null());
+    assert_eq!(v.as_str(), "Nothing");
 }
 
 #[test]
 fn generic_enum_tuple_variant() {
     let v = Maybe::Just(42i32).to_value();
-    assert!(v.is_tuple());
-    assert_eq!(v.as_tuple().index(0).unwrap().to_value().to_i32(), 42);
+    assert_eq!(v.as_struct().get("Just").unwrap().to_value().to_i32(), 42);
 }
 
 #[test]
@@ -89,6 +88,9 @@ fn generic_enum_named_variant() {
     }
     .to_value();
     assert!(v.is_struct());
-    let s = v.as_struct();
-    assert_eq!(s.get("value").unwrap().to_value().as_str(), "hi");
+    let s = v.as_struct().get("Wrapped").unwrap().to_value();
+    assert_eq!(
+        s.as_struct().get("value").unwrap().to_value().as_str(),
+        "hi"
+    );
 }
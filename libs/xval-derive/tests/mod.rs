@@ -1,4 +1,7 @@
+pub mod attr_tests;
 pub mod enum_tests;
+pub mod from_value_tests;
 pub mod generic_tests;
 pub mod struct_tests;
 pub mod tuple_tests;
+pub mod ui_tests;
@@ -11,6 +11,12 @@ struct User {
     pub tags: Vec<String>,
 }
 
+#[derive(Value)]
+struct Unsorted {
+    pub zeta: i32,
+    pub alpha: i32,
+}
+
 #[derive(Value)]
 struct WithTuple {
     pub pair: Pair,
@@ -63,3 +69,54 @@ fn struct_with_tuple_field() {
     assert!(name.is_string());
     assert_eq!(name.as_str(), "test");
 }
+
+#[test]
+fn struct_to_value_is_mutable() {
+    let mut v = User {
+        name: "Bob".to_string(),
+        tags: vec![],
+    }
+    .to_value();
+
+    let path = xpath::Path::parse("name").unwrap();
+    v.set(&path, "Alice".to_value()).unwrap();
+    assert_eq!(v.get(&path).unwrap().as_str(), "Alice");
+}
+
+#[test]
+fn struct_backing_is_immutable() {
+    let mut v = xval::Value::from_struct(User {
+        name: "Bob".to_string(),
+        tags: vec![],
+    });
+
+    let path = xpath::Path::parse("name").unwrap();
+    assert_eq!(
+        v.set(&path, "Alice".to_value()),
+        Err(xval::ValueError::immutable("User"))
+    );
+}
+
+#[test]
+fn struct_value_ref_borrows() {
+    let v = WithTuple {
+        pair: Pair(42, true),
+        name: "test".to_string(),
+    };
+
+    let path = xpath::Path::parse("pair/0").unwrap();
+    assert!(matches!(v.as_value_ref(), xval::ValueRef::Struct(_)));
+    assert_eq!(v.as_value_ref().get(&path).unwrap(), 42_i32.to_value());
+    assert_eq!(v.as_value_ref(), v.to_value());
+}
+
+#[test]
+fn struct_keeps_declaration_order() {
+    let v = Unsorted { zeta: 1, alpha: 2 };
+
+    assert_eq!(xval::json::to_string(&v), r#"{"zeta":1,"alpha":2}"#);
+    assert_eq!(
+        xval::json::to_string(&v.to_value()),
+        xval::json::to_string(&v)
+    );
+}
@@ -0,0 +1,18 @@
+use xval_derive::Value;
+
+#[derive(Value)]
+struct Room {
+    room: String,
+}
+
+type Code = i32;
+
+#[derive(Value)]
+#[value(tag = "type")]
+enum Event {
+    Join(Room),
+    Code(Code),
+    Leave(Option<Room>),
+}
+
+fn main() {}
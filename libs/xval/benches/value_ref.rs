@@ -0,0 +1,85 @@
+use std::collections::BTreeMap;
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use xpath::{Ident, Path};
+use xval::ToValue;
+
+type Document = BTreeMap<Ident, Vec<BTreeMap<Ident, Vec<i64>>>>;
+
+/// A native document with nested maps and arrays, nothing converted yet.
+fn document() -> Document {
+    let mut doc = BTreeMap::new();
+
+    for i in 0..16 {
+        let rows = (0..16)
+            .map(|j| {
+                (0..8)
+                    .map(|k| (Ident::key(&format!("col{k}")), vec![i * j; 16]))
+                    .collect()
+            })
+            .collect();
+
+        doc.insert(Ident::key(&format!("table{i}")), rows);
+    }
+
+    doc
+}
+
+fn navigation(c: &mut Criterion) {
+    let doc = document();
+    let value = doc.to_value();
+    let path = Path::parse("table7/3/col5/9").unwrap();
+
+    let mut group = c.benchmark_group("navigation");
+    group.bench_function("to_value", |b| {
+        b.iter(|| black_box(&doc).to_value().get(black_box(&path)))
+    });
+    group.bench_function("as_value_ref", |b| {
+        b.iter(|| black_box(&doc).as_value_ref().get(black_box(&path)))
+    });
+    group.bench_function("value_get", |b| {
+        b.iter(|| black_box(&value).get(black_box(&path)))
+    });
+    group.finish();
+}
+
+fn equality(c: &mut Criterion) {
+    let doc = document();
+    let other = document();
+    let value = doc.to_value();
+
+    let mut group = c.benchmark_group("equality");
+    group.bench_function("to_value", |b| {
+        b.iter(|| black_box(&doc).to_value() == black_box(&other).to_value())
+    });
+    group.bench_function("as_value_ref", |b| {
+        b.iter(|| black_box(&doc).as_value_ref() == black_box(&other).as_value_ref())
+    });
+    group.bench_function("against_value", |b| {
+        b.iter(|| black_box(&doc).as_value_ref() == *black_box(&value))
+    });
+    group.finish();
+}
+
+fn serialization(c: &mut Criterion) {
+    let doc = document();
+
+    let mut group = c.benchmark_group("serialization");
+    group.bench_function("to_value", |b| {
+        b.iter(|| xval::json::to_string(&black_box(&doc).to_value()))
+    });
+    group.bench_function("as_value_ref", |b| {
+        b.iter(|| xval::json::to_string(black_box(&doc)))
+    });
+    group.bench_function("msgpack_to_value", |b| {
+        b.iter(|| xval::binary::msgpack::to_vec(&black_box(&doc).to_value()))
+    });
+    group.bench_function("msgpack_as_value_ref", |b| {
+        b.iter(|| xval::binary::msgpack::to_vec(black_box(&doc)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, navigation, equality, serialization);
+criterion_main!(benches);
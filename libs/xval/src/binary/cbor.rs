@@ -0,0 +1,584 @@
+//! CBOR ([RFC 8949](https://www.rfc-editor.org/rfc/rfc8949)) encoding of
+//! [`Value`].
+//!
+//! Integers are written with an argument as wide as their type, never
+//! packed into the initial byte, and read back as a signed integer of that
+//! width. Kinds CBOR can't tell apart natively are tagged:
+//!
+//! | tag    | kind                                                        |
+//! |--------|-------------------------------------------------------------|
+//! | 0      | `DateTime`, as RFC 3339 text                                |
+//! | 1004   | `Date`, as `YYYY-MM-DD` text                                |
+//! | 2, 3   | `i128`, as a 16 byte bignum                                 |
+//! | 4      | `Decimal`, as `[-scale, coefficient]`                       |
+//! | 41200  | unsigned: wraps an integer, or a tag 2 bignum for `u128`    |
+//! | 41201  | tuple: wraps an array of the items                          |
+//! | 41202  | `Duration`, as ISO 8601 text                                |
+//!
+//! Tags 1 (epoch seconds), 100 (epoch days) and 55799 (self-described
+//! CBOR) are also read. Indefinite-length items are not supported.
+
+use std::io::{Read, Write};
+
+use super::{BinaryError, Input};
+use crate::{
+    Date, DateTime, Decimal, Duration, Float, Ident, Int, Number, OrderedMap, Time, ToValue,
+    TupleVec, UInt, Value, ValueRef,
+};
+
+const TAG_DATETIME: u64 = 0;
+const TAG_EPOCH: u64 = 1;
+const TAG_BIGNUM: u64 = 2;
+const TAG_NEGATIVE_BIGNUM: u64 = 3;
+const TAG_DECIMAL: u64 = 4;
+const TAG_EPOCH_DAYS: u64 = 100;
+const TAG_DATE: u64 = 1004;
+const TAG_SELF_DESCRIBED: u64 = 55799;
+const TAG_UNSIGNED: u64 = 41200;
+const TAG_TUPLE: u64 = 41201;
+const TAG_DURATION: u64 = 41202;
+
+const UINT: u8 = 0;
+const NEGATIVE: u8 = 1;
+const BYTES: u8 = 2;
+const TEXT: u8 = 3;
+const ARRAY: u8 = 4;
+const MAP: u8 = 5;
+const TAG: u8 = 6;
+const SIMPLE: u8 = 7;
+
+pub fn to_vec(value: &(impl ToValue + ?Sized)) -> Vec<u8> {
+    let mut out = Vec::new();
+    write(&mut out, value.as_value_ref());
+    out
+}
+
+pub fn to_writer(mut out: impl Write, value: &(impl ToValue + ?Sized)) -> std::io::Result<()> {
+    out.write_all(&to_vec(value))
+}
+
+/// Decode exactly one value, failing on trailing bytes.
+pub fn from_slice(bytes: &[u8]) -> Result<Value, BinaryError> {
+    let mut input = Input::new(bytes);
+    let value = read(&mut input, 0)?;
+    input.end()?;
+    Ok(value)
+}
+
+pub fn from_reader(mut reader: impl Read) -> Result<Value, BinaryError> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes).map_err(BinaryError::io)?;
+    from_slice(&bytes)
+}
+
+fn write(out: &mut Vec<u8>, value: ValueRef<'_>) {
+    match value {
+        ValueRef::Null => out.push(0xf6),
+        ValueRef::Bool(v) => out.push(if v { 0xf5 } else { 0xf4 }),
+        ValueRef::Number(v) => write_number(out, &v),
+        ValueRef::String(v) => write_text(out, v),
+        ValueRef::Bytes(v) => {
+            head(out, BYTES, v.len() as u64);
+            out.extend_from_slice(v);
+        }
+        ValueRef::Time(Time::DateTime(v)) => {
+            head(out, TAG, TAG_DATETIME);
+            write_text(out, &v.to_string());
+        }
+        ValueRef::Time(Time::Date(v)) => {
+            head(out, TAG, TAG_DATE);
+            write_text(out, &v.to_string());
+        }
+        ValueRef::Time(Time::Duration(v)) => {
+            head(out, TAG, TAG_DURATION);
+            write_text(out, &v.to_string());
+        }
+        ValueRef::Struct(v) => {
+            head(out, MAP, v.len() as u64);
+
+            for (ident, item) in v.items() {
+                match ident {
+                    Ident::Key(k) => write_text(out, &k),
+                    Ident::Index(i) => head(out, UINT, i as u64),
+                }
+
+                write(out, item.as_value_ref());
+            }
+        }
+        ValueRef::Array(v) => {
+            head(out, ARRAY, v.len() as u64);
+
+            for item in v.items() {
+                write(out, item.as_value_ref());
+            }
+        }
+        ValueRef::Tuple(v) => {
+            head(out, TAG, TAG_TUPLE);
+            head(out, ARRAY, v.len() as u64);
+
+            for item in v.items() {
+                write(out, item.as_value_ref());
+            }
+        }
+        ValueRef::Owned(v) => write(out, v.as_value_ref()),
+    }
+}
+
+fn write_number(out: &mut Vec<u8>, value: &Number) {
+    match value {
+        Number::Int(Int::I8(v)) => write_int(out, *v as i64, 1),
+        Number::Int(Int::I16(v)) => write_int(out, *v as i64, 2),
+        Number::Int(Int::I32(v)) => write_int(out, *v as i64, 4),
+        Number::Int(Int::I64(v)) => write_int(out, *v, 8),
+        Number::Int(Int::I128(v)) => match *v < 0 {
+            true => write_bignum(out, TAG_NEGATIVE_BIGNUM, !*v as u128),
+            false => write_bignum(out, TAG_BIGNUM, *v as u128),
+        },
+        Number::UInt(v) => {
+            head(out, TAG, TAG_UNSIGNED);
+
+            match v {
+                UInt::U8(v) => head_sized(out, UINT, *v as u64, 1),
+                UInt::U16(v) => head_sized(out, UINT, *v as u64, 2),
+                UInt::U32(v) => head_sized(out, UINT, *v as u64, 4),
+                UInt::U64(v) => head_sized(out, UINT, *v, 8),
+                UInt::U128(v) => write_bignum(out, TAG_BIGNUM, *v),
+            }
+        }
+        Number::Float(Float::F32(v)) => {
+            out.push(0xfa);
+            out.extend_from_slice(&v.to_be_bytes());
+        }
+        Number::Float(Float::F64(v)) => {
+            out.push(0xfb);
+            out.extend_from_slice(&v.to_be_bytes());
+        }
+        Number::Decimal(v) => {
+            head(out, TAG, TAG_DECIMAL);
+            head(out, ARRAY, 2);
+            write_int(out, -(v.scale() as i64), 1);
+
+            match i64::try_from(v.coefficient()) {
+                Ok(c) => write_int(out, c, 8),
+                Err(_) => write_number(out, &Number::from_i128(v.coefficient())),
+            }
+        }
+    }
+}
+
+fn write_int(out: &mut Vec<u8>, value: i64, size: usize) {
+    match value < 0 {
+        true => head_sized(out, NEGATIVE, !value as u64, size),
+        false => head_sized(out, UINT, value as u64, size),
+    }
+}
+
+fn write_bignum(out: &mut Vec<u8>, tag: u64, value: u128) {
+    head(out, TAG, tag);
+    head(out, BYTES, 16);
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+fn write_text(out: &mut Vec<u8>, value: &str) {
+    head(out, TEXT, value.len() as u64);
+    out.extend_from_slice(value.as_bytes());
+}
+
+/// Write an initial byte and the shortest argument that holds `arg`.
+fn head(out: &mut Vec<u8>, major: u8, arg: u64) {
+    match arg {
+        0..24 => out.push(major << 5 | arg as u8),
+        24..=0xff => head_sized(out, major, arg, 1),
+        0x100..=0xffff => head_sized(out, major, arg, 2),
+        0x1_0000..=0xffff_ffff => head_sized(out, major, arg, 4),
+        _ => head_sized(out, major, arg, 8),
+    }
+}
+
+/// Write an initial byte and an argument of exactly `size` bytes.
+fn head_sized(out: &mut Vec<u8>, major: u8, arg: u64, size: usize) {
+    let info = 24 + size.trailing_zeros() as u8;
+    out.push(major << 5 | info);
+    out.extend_from_slice(&arg.to_be_bytes()[8 - size..]);
+}
+
+/// An initial byte and its argument. `size` is how many bytes the
+/// argument took, `0` when it was packed into the initial byte.
+struct Head {
+    major: u8,
+    arg: u64,
+    size: usize,
+}
+
+fn read_head(input: &mut Input) -> Result<Head, BinaryError> {
+    let start = input.pos();
+    let initial = input.byte()?;
+    let major = initial >> 5;
+    let (arg, size) = match initial & 0x1f {
+        info @ 0..24 => (info as u64, 0),
+        24 => (input.byte()? as u64, 1),
+        25 => (u16::from_be_bytes(input.array()?) as u64, 2),
+        26 => (u32::from_be_bytes(input.array()?) as u64, 4),
+        27 => (u64::from_be_bytes(input.array()?), 8),
+        31 if major == SIMPLE => return Err(BinaryError::new("unexpected break", start)),
+        31 => {
+            return Err(BinaryError::new(
+                "indefinite-length items are not supported",
+                start,
+            ));
+        }
+        info => {
+            return Err(BinaryError::new(
+                format!("reserved additional info {info}"),
+                start,
+            ));
+        }
+    };
+
+    Ok(Head { major, arg, size })
+}
+
+fn read(input: &mut Input, depth: usize) -> Result<Value, BinaryError> {
+    let start = input.pos();
+    let head = read_head(input)?;
+
+    Ok(match head.major {
+        UINT => signed(head.arg as i128, head.size),
+        NEGATIVE => signed(-1 - head.arg as i128, head.size),
+        BYTES => {
+            let len = input.items(head.arg, 1)?;
+            Value::from_bytes(input.take(len)?)
+        }
+        TEXT => {
+            let len = input.items(head.arg, 1)?;
+            Value::from_str(input.str(len)?)
+        }
+        ARRAY => Value::from(read_array(input, head.arg, depth)?),
+        MAP => {
+            let depth = input.nest(depth)?;
+            let len = input.items(head.arg, 2)?;
+            let mut map = OrderedMap::with_capacity(len);
+
+            for _ in 0..len {
+                let start = input.pos();
+                let ident = match read(input, depth)? {
+                    Value::String(v) => Ident::key(v.as_str()),
+                    Value::Number(v) if !v.is_float() => match usize::try_from(v.to_i128()) {
+                        Ok(i) => Ident::index(i),
+                        Err(_) => return Err(BinaryError::new("map key out of range", start)),
+                    },
+                    _ => {
+                        return Err(BinaryError::new(
+                            "map keys must be strings or unsigned integers",
+                            start,
+                        ));
+                    }
+                };
+
+                map.insert(ident, read(input, depth)?);
+            }
+
+            Value::from(map)
+        }
+        TAG => {
+            let depth = input.nest(depth)?;
+            read_tag(input, head.arg, depth)?
+        }
+        _ => match (head.arg, head.size) {
+            (20, 0) => Value::from_bool(false),
+            (21, 0) => Value::from_bool(true),
+            (22 | 23, 0) => Value::Null,
+            (bits, 2) => Value::from_f32(f16_to_f32(bits as u16)),
+            (bits, 4) => Value::from_f32(f32::from_bits(bits as u32)),
+            (bits, 8) => Value::from_f64(f64::from_bits(bits)),
+            (simple, _) => {
+                return Err(BinaryError::new(
+                    format!("unsupported simple value {simple}"),
+                    start,
+                ));
+            }
+        },
+    })
+}
+
+/// The narrowest signed integer at least `size` bytes wide that holds
+/// `value`, or `u64` for large positive values.
+fn signed(value: i128, size: usize) -> Value {
+    if size <= 1
+        && let Ok(v) = i8::try_from(value)
+    {
+        return Value::from_i8(v);
+    }
+
+    if size <= 2
+        && let Ok(v) = i16::try_from(value)
+    {
+        return Value::from_i16(v);
+    }
+
+    if size <= 4
+        && let Ok(v) = i32::try_from(value)
+    {
+        return Value::from_i32(v);
+    }
+
+    match (i64::try_from(value), u64::try_from(value)) {
+        (Ok(v), _) => Value::from_i64(v),
+        (_, Ok(v)) => Value::from_u64(v),
+        _ => Value::from_i128(value),
+    }
+}
+
+fn read_array(input: &mut Input, len: u64, depth: usize) -> Result<Vec<Value>, BinaryError> {
+    let depth = input.nest(depth)?;
+    let len = input.items(len, 1)?;
+    let mut items = Vec::with_capacity(len);
+
+    for _ in 0..len {
+        items.push(read(input, depth)?);
+    }
+
+    Ok(items)
+}
+
+fn read_tag(input: &mut Input, tag: u64, depth: usize) -> Result<Value, BinaryError> {
+    let start = input.pos();
+    let invalid = |err: crate::ValueError| BinaryError::new(err.to_string(), start);
+
+    Ok(match tag {
+        TAG_DATETIME => Value::from(DateTime::parse(read_text(input, tag)?).map_err(invalid)?),
+        TAG_EPOCH => {
+            let nanos = match read(input, depth)? {
+                Value::Number(Number::Float(v)) if v.to_f64().is_finite() => {
+                    (v.to_f64() * 1e9).round() as i128
+                }
+                Value::Number(v) if !v.is_float() => v.to_i128().saturating_mul(1_000_000_000),
+                _ => return Err(BinaryError::new("expected epoch seconds", start)),
+            };
+
+            Value::from(DateTime::from_unix_nanos(nanos).map_err(invalid)?)
+        }
+        TAG_BIGNUM => {
+            let value = read_bignum(input, tag)?;
+            match i128::try_from(value) {
+                Ok(v) => Value::from_i128(v),
+                Err(_) => Value::from_u128(value),
+            }
+        }
+        TAG_NEGATIVE_BIGNUM => match i128::try_from(read_bignum(input, tag)?) {
+            Ok(v) => Value::from_i128(-1 - v),
+            Err(_) => return Err(BinaryError::new("bignum out of range", start)),
+        },
+        TAG_DECIMAL => {
+            let head = read_head(input)?;
+
+            if head.major != ARRAY || head.arg != 2 {
+                return Err(BinaryError::new(
+                    "expected an [exponent, mantissa] array",
+                    start,
+                ));
+            }
+
+            let (exp, mantissa) = match (read(input, depth)?, read(input, depth)?) {
+                (Value::Number(e), Value::Number(m)) if !e.is_float() && !m.is_float() => (e, m),
+                _ => return Err(BinaryError::new("expected integer decimal parts", start)),
+            };
+
+            let text = format!("{}e{}", mantissa, exp);
+            Value::from(text.parse::<Decimal>().map_err(invalid)?)
+        }
+        TAG_EPOCH_DAYS => match read(input, depth)? {
+            Value::Number(v) if !v.is_float() => {
+                let days = i64::try_from(v.to_i128()).unwrap_or(i64::MAX);
+                Value::from(Date::from_unix_days(days).map_err(invalid)?)
+            }
+            _ => return Err(BinaryError::new("expected epoch days", start)),
+        },
+        TAG_DATE => Value::from(Date::parse(read_text(input, tag)?).map_err(invalid)?),
+        TAG_DURATION => Value::from(Duration::parse(read_text(input, tag)?).map_err(invalid)?),
+        TAG_SELF_DESCRIBED => read(input, depth)?,
+        TAG_UNSIGNED => {
+            let head = read_head(input)?;
+
+            match (head.major, head.size) {
+                (UINT, 0 | 1) => Value::from_u8(head.arg as u8),
+                (UINT, 2) => Value::from_u16(head.arg as u16),
+                (UINT, 4) => Value::from_u32(head.arg as u32),
+                (UINT, _) => Value::from_u64(head.arg),
+                (TAG, _) if head.arg == TAG_BIGNUM => Value::from_u128(read_bignum(input, tag)?),
+                _ => return Err(BinaryError::new("expected an unsigned integer", start)),
+            }
+        }
+        TAG_TUPLE => {
+            let head = read_head(input)?;
+
+            if head.major != ARRAY {
+                return Err(BinaryError::new("expected an array", start));
+            }
+
+            Value::from(TupleVec::new(read_array(input, head.arg, depth)?))
+        }
+        _ => return Err(BinaryError::new(format!("unsupported tag {tag}"), start)),
+    })
+}
+
+fn read_text<'a>(input: &mut Input<'a>, tag: u64) -> Result<&'a str, BinaryError> {
+    let start = input.pos();
+    let head = read_head(input)?;
+
+    if head.major != TEXT {
+        return Err(BinaryError::new(
+            format!("expected text after tag {tag}"),
+            start,
+        ));
+    }
+
+    let len = input.items(head.arg, 1)?;
+    input.str(len)
+}
+
+fn read_bignum(input: &mut Input, tag: u64) -> Result<u128, BinaryError> {
+    let start = input.pos();
+    let head = read_head(input)?;
+
+    if head.major != BYTES {
+        return Err(BinaryError::new(
+            format!("expected bytes after tag {tag}"),
+            start,
+        ));
+    }
+
+    let len = input.items(head.arg, 1)?;
+    let bytes = input.take(len)?;
+    let skip = bytes.iter().take_while(|&&b| b == 0).count();
+
+    if len - skip > 16 {
+        return Err(BinaryError::new("bignum out of range", start));
+    }
+
+    Ok(bytes[skip..].iter().fold(0, |acc, &b| acc << 8 | b as u128))
+}
+
+fn f16_to_f32(bits: u16) -> f32 {
+    let sign = if bits & 0x8000 == 0 { 1.0 } else { -1.0 };
+    let exp = (bits >> 10) & 0x1f;
+    let frac = (bits & 0x3ff) as f32;
+
+    sign * match exp {
+        0 => frac * 2f32.powi(-24),
+        0x1f if frac == 0.0 => f32::INFINITY,
+        0x1f => f32::NAN,
+        _ => (1.0 + frac / 1024.0) * 2f32.powi(exp as i32 - 15),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn sample() -> Value {
+        valueof!({
+            "ints": [(i8::MIN), (i16::MAX), 7_i32, (i64::MIN), (i128::MIN), 5_i128],
+            "uints": [0_u8, (u16::MAX), 7_u32, (u64::MAX), (u128::MAX)],
+            "floats": [1.5_f32, (f64::MIN_POSITIVE)],
+            "decimals": [
+                ("12.30".parse::<Decimal>().unwrap()),
+                ("-170141183460469231731687303715.88410572".parse::<Decimal>().unwrap())
+            ],
+            "text": "héllo",
+            "bytes": b"\x00\xff",
+            "tuple": (1_u8, "a", (2_i64,)),
+            "time": [
+                (DateTime::parse("2024-05-01T10:30:00.5-08:00").unwrap()),
+                (Date::from_ymd(2024, 2, 29).unwrap()),
+                (Duration::from_secs(-90))
+            ],
+            "none": null,
+            "yes": false,
+        })
+    }
+
+    #[test]
+    fn round_trip() {
+        let v = sample();
+        assert!(
+            binary::cbor::from_slice(&binary::cbor::to_vec(&v))
+                .unwrap()
+                .strict_eq(&v)
+        );
+    }
+
+    #[test]
+    fn decimal_fraction() {
+        // RFC 8949's example: 273.15 as tag 4 [-2, 27315]
+        let v = binary::cbor::from_slice(&[0xc4, 0x82, 0x21, 0x19, 0x6a, 0xb3]).unwrap();
+        assert!(v.strict_eq(&Value::from("273.15".parse::<Decimal>().unwrap())));
+    }
+
+    #[test]
+    fn exact_widths() {
+        assert_eq!(binary::cbor::to_vec(&valueof!(1_i8)), [0x18, 0x01]);
+        assert_eq!(binary::cbor::to_vec(&valueof!(-1_i16)), [0x39, 0x00, 0x00]);
+        assert_eq!(
+            binary::cbor::to_vec(&valueof!(1_u8)),
+            [0xd9, 0xa0, 0xf0, 0x18, 0x01]
+        );
+        assert_eq!(
+            binary::cbor::to_vec(&Value::from(Date::from_ymd(2024, 5, 1).unwrap())),
+            b"\xd9\x03\xec\x6a2024-05-01"
+        );
+    }
+
+    #[test]
+    fn foreign_input() {
+        // packed ints, a half float, tag 1 epoch seconds and a plain bignum
+        let v = binary::cbor::from_slice(&[
+            0x85, 0x05, 0x20, 0xf9, 0x3e, 0x00, 0xc1, 0x18, 0x3c, 0xc2, 0x41, 0x01,
+        ])
+        .unwrap();
+
+        assert!(v.strict_eq(&valueof!([
+            5_i8,
+            (-1_i8),
+            1.5_f32,
+            (DateTime::from_unix_secs(60).unwrap()),
+            1_i128
+        ])));
+
+        let v = binary::cbor::from_slice(&[0x19, 0xff, 0xff]).unwrap();
+        assert!(v.strict_eq(&valueof!(65_535_i32)));
+    }
+
+    #[test]
+    fn hostile_input() {
+        let cases: [(&[u8], &str); 8] = [
+            (
+                &[0x9b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+                "length exceeds input",
+            ),
+            (
+                &[0x7a, 0xff, 0xff, 0xff, 0xff, b'a'],
+                "length exceeds input",
+            ),
+            (&[0x62, 0xc3, 0x28], "invalid utf-8 in string"),
+            (
+                &[0xa1, 0xf6, 0xf6],
+                "map keys must be strings or unsigned integers",
+            ),
+            (&[0x9f, 0xff], "indefinite-length items are not supported"),
+            (&[0xd8, 0x40, 0xf6], "unsupported tag 64"),
+            (&[0x1c], "reserved additional info 28"),
+            (&[0xf6, 0xf6], "trailing bytes"),
+        ];
+
+        for (input, message) in cases {
+            let err = binary::cbor::from_slice(input).unwrap_err();
+            assert_eq!(err.message, message, "{input:?}");
+        }
+
+        for deep in [vec![0x81; 10_000], [0xd9, 0xd9, 0xf7].repeat(10_000)] {
+            let err = binary::cbor::from_slice(&deep).unwrap_err();
+            assert_eq!(err.message, "nesting too deep");
+        }
+    }
+}
@@ -0,0 +1,42 @@
+/// An error produced while decoding MessagePack or CBOR, with the byte
+/// offset where it occurred.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BinaryError {
+    pub message: String,
+    pub offset: usize,
+}
+
+impl BinaryError {
+    pub fn new(message: impl Into<String>, offset: usize) -> Self {
+        Self {
+            message: message.into(),
+            offset,
+        }
+    }
+
+    pub(crate) fn io(err: std::io::Error) -> Self {
+        Self::new(err.to_string(), 0)
+    }
+}
+
+impl std::fmt::Display for BinaryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at offset {}", &self.message, self.offset)
+    }
+}
+
+impl std::error::Error for BinaryError {}
+
+impl xok::XError for BinaryError {
+    fn name(&self) -> &'static str {
+        "BinaryError"
+    }
+
+    fn module(&self) -> &'static str {
+        module_path!()
+    }
+
+    fn code(&self) -> xok::Code {
+        xok::Code::BadArgument
+    }
+}
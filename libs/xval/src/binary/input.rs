@@ -0,0 +1,75 @@
+use super::{BinaryError, MAX_DEPTH};
+
+/// A bounds-checked cursor over the bytes being decoded.
+pub(crate) struct Input<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> BinaryError {
+        BinaryError::new(message, self.pos)
+    }
+
+    pub fn byte(&mut self) -> Result<u8, BinaryError> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    pub fn take(&mut self, len: usize) -> Result<&'a [u8], BinaryError> {
+        if len > self.remaining() {
+            return Err(self.error("unexpected end of input"));
+        }
+
+        let bytes = &self.bytes[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    pub fn array<const N: usize>(&mut self) -> Result<[u8; N], BinaryError> {
+        let mut out = [0; N];
+        out.copy_from_slice(self.take(N)?);
+        Ok(out)
+    }
+
+    pub fn str(&mut self, len: usize) -> Result<&'a str, BinaryError> {
+        let start = self.pos;
+        let bytes = self.take(len)?;
+        std::str::from_utf8(bytes)
+            .map_err(|err| BinaryError::new("invalid utf-8 in string", start + err.valid_up_to()))
+    }
+
+    /// Check that `len` items of at least `min` bytes each can still follow,
+    /// so a hostile length prefix can't trigger a huge allocation.
+    pub fn items(&self, len: u64, min: usize) -> Result<usize, BinaryError> {
+        match usize::try_from(len) {
+            Ok(len) if len.saturating_mul(min) <= self.remaining() => Ok(len),
+            _ => Err(self.error("length exceeds input")),
+        }
+    }
+
+    pub fn nest(&self, depth: usize) -> Result<usize, BinaryError> {
+        match depth < MAX_DEPTH {
+            true => Ok(depth + 1),
+            false => Err(self.error("nesting too deep")),
+        }
+    }
+
+    pub fn end(&self) -> Result<(), BinaryError> {
+        match self.remaining() {
+            0 => Ok(()),
+            _ => Err(self.error("trailing bytes")),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! Compact binary encodings of [`Value`](crate::Value) in MessagePack and
+//! CBOR, for passing values between processes or persisting them.
+//!
+//! Unlike [`json`](crate::json), both codecs round-trip every value
+//! exactly: numbers keep their width and signedness, tuples stay tuples
+//! and times keep their kind and offset. Where a format has no native
+//! type for a value it uses an extension type or tag, documented on
+//! [`msgpack`] and [`cbor`].
+//!
+//! Decoding is safe on untrusted input: nesting is limited to
+//! [`MAX_DEPTH`] and length prefixes are checked against the remaining
+//! input before anything is allocated.
+
+mod error;
+mod input;
+
+pub mod cbor;
+pub mod msgpack;
+
+pub use error::*;
+
+pub(crate) use input::*;
+
+/// How deeply arrays, maps, tuples and tags may nest before decoding fails.
+pub const MAX_DEPTH: usize = 128;
@@ -0,0 +1,522 @@
+//! MessagePack encoding of [`Value`].
+//!
+//! Integers are always written in the format of their exact width, never
+//! as a fixint, and read back as that width. Kinds MessagePack has no
+//! format for use application extension types:
+//!
+//! | type | kind       | payload                                         |
+//! |------|------------|-------------------------------------------------|
+//! | 1    | `i128`     | 16 bytes, big-endian                            |
+//! | 2    | `u128`     | 16 bytes, big-endian                            |
+//! | 3    | tuple      | a MessagePack array of the items                |
+//! | 4    | `DateTime` | `i128` unix nanos then `i32` offset seconds     |
+//! | 5    | `Date`     | `i32` days since 1970-01-01                     |
+//! | 6    | `Duration` | `i128` nanos                                    |
+//! | 7    | `Decimal`  | `u8` scale then `i128` coefficient              |
+//!
+//! The standard timestamp extension (`-1`) is also read, as a UTC
+//! [`DateTime`]. Map keys are written as strings, or as unsigned integers
+//! for index fields.
+
+use std::io::{Read, Write};
+
+use super::{BinaryError, Input};
+use crate::{
+    Date, DateTime, Decimal, Duration, Float, Ident, Int, Number, Object, OrderedMap, Time,
+    ToValue, TupleVec, UInt, Value, ValueRef,
+};
+
+const EXT_I128: i8 = 1;
+const EXT_U128: i8 = 2;
+const EXT_TUPLE: i8 = 3;
+const EXT_DATETIME: i8 = 4;
+const EXT_DATE: i8 = 5;
+const EXT_DURATION: i8 = 6;
+const EXT_DECIMAL: i8 = 7;
+const EXT_TIMESTAMP: i8 = -1;
+
+/// Panics when a string, byte string or container is longer than
+/// `u32::MAX`, the most MessagePack can represent.
+pub fn to_vec(value: &(impl ToValue + ?Sized)) -> Vec<u8> {
+    encode(value).unwrap_or_else(|err| panic!("{err}"))
+}
+
+pub fn to_writer(mut out: impl Write, value: &(impl ToValue + ?Sized)) -> std::io::Result<()> {
+    out.write_all(&encode(value)?)
+}
+
+/// Decode exactly one value, failing on trailing bytes.
+pub fn from_slice(bytes: &[u8]) -> Result<Value, BinaryError> {
+    let mut input = Input::new(bytes);
+    let value = read(&mut input, 0)?;
+    input.end()?;
+    Ok(value)
+}
+
+pub fn from_reader(mut reader: impl Read) -> Result<Value, BinaryError> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes).map_err(BinaryError::io)?;
+    from_slice(&bytes)
+}
+
+fn encode(value: &(impl ToValue + ?Sized)) -> std::io::Result<Vec<u8>> {
+    let mut out = Vec::new();
+    write(&mut out, value.as_value_ref())?;
+    Ok(out)
+}
+
+fn write(out: &mut Vec<u8>, value: ValueRef<'_>) -> std::io::Result<()> {
+    match value {
+        ValueRef::Null => out.push(0xc0),
+        ValueRef::Bool(v) => out.push(if v { 0xc3 } else { 0xc2 }),
+        ValueRef::Number(v) => write_number(out, &v),
+        ValueRef::String(v) => {
+            header(out, v.len(), [0xa0, 0xd9, 0xda, 0xdb], 32)?;
+            out.extend_from_slice(v.as_bytes());
+        }
+        ValueRef::Bytes(v) => {
+            header(out, v.len(), [0, 0xc4, 0xc5, 0xc6], 0)?;
+            out.extend_from_slice(v);
+        }
+        ValueRef::Time(Time::DateTime(v)) => {
+            let mut payload = v.unix_nanos().to_be_bytes().to_vec();
+            payload.extend_from_slice(&v.offset_secs().to_be_bytes());
+            ext(out, EXT_DATETIME, &payload)?;
+        }
+        ValueRef::Time(Time::Date(v)) => {
+            ext(out, EXT_DATE, &(v.unix_days() as i32).to_be_bytes())?;
+        }
+        ValueRef::Time(Time::Duration(v)) => {
+            ext(out, EXT_DURATION, &v.as_nanos().to_be_bytes())?;
+        }
+        ValueRef::Struct(v) => {
+            header(out, v.len(), [0x80, 0, 0xde, 0xdf], 16)?;
+
+            for (ident, item) in v.items() {
+                match ident {
+                    Ident::Key(k) => {
+                        header(out, k.len(), [0xa0, 0xd9, 0xda, 0xdb], 32)?;
+                        out.extend_from_slice(k.as_bytes());
+                    }
+                    Ident::Index(i) => {
+                        out.push(0xcf);
+                        out.extend_from_slice(&(i as u64).to_be_bytes());
+                    }
+                }
+
+                write(out, item.as_value_ref())?;
+            }
+        }
+        ValueRef::Array(v) => {
+            header(out, v.len(), [0x90, 0, 0xdc, 0xdd], 16)?;
+
+            for item in v.items() {
+                write(out, item.as_value_ref())?;
+            }
+        }
+        ValueRef::Tuple(v) => {
+            let mut payload = Vec::new();
+            header(&mut payload, v.len(), [0x90, 0, 0xdc, 0xdd], 16)?;
+
+            for item in v.items() {
+                write(&mut payload, item.as_value_ref())?;
+            }
+
+            ext(out, EXT_TUPLE, &payload)?;
+        }
+        ValueRef::Owned(v) => write(out, v.as_value_ref())?,
+    }
+
+    Ok(())
+}
+
+fn write_number(out: &mut Vec<u8>, value: &Number) {
+    match value {
+        Number::Int(Int::I8(v)) => {
+            out.push(0xd0);
+            out.extend_from_slice(&v.to_be_bytes());
+        }
+        Number::Int(Int::I16(v)) => {
+            out.push(0xd1);
+            out.extend_from_slice(&v.to_be_bytes());
+        }
+        Number::Int(Int::I32(v)) => {
+            out.push(0xd2);
+            out.extend_from_slice(&v.to_be_bytes());
+        }
+        Number::Int(Int::I64(v)) => {
+            out.push(0xd3);
+            out.extend_from_slice(&v.to_be_bytes());
+        }
+        Number::Int(Int::I128(v)) => {
+            out.extend_from_slice(&[0xd8, EXT_I128 as u8]);
+            out.extend_from_slice(&v.to_be_bytes());
+        }
+        Number::UInt(UInt::U8(v)) => out.extend_from_slice(&[0xcc, *v]),
+        Number::UInt(UInt::U16(v)) => {
+            out.push(0xcd);
+            out.extend_from_slice(&v.to_be_bytes());
+        }
+        Number::UInt(UInt::U32(v)) => {
+            out.push(0xce);
+            out.extend_from_slice(&v.to_be_bytes());
+        }
+        Number::UInt(UInt::U64(v)) => {
+            out.push(0xcf);
+            out.extend_from_slice(&v.to_be_bytes());
+        }
+        Number::UInt(UInt::U128(v)) => {
+            out.extend_from_slice(&[0xd8, EXT_U128 as u8]);
+            out.extend_from_slice(&v.to_be_bytes());
+        }
+        Number::Float(Float::F32(v)) => {
+            out.push(0xca);
+            out.extend_from_slice(&v.to_be_bytes());
+        }
+        Number::Float(Float::F64(v)) => {
+            out.push(0xcb);
+            out.extend_from_slice(&v.to_be_bytes());
+        }
+        Number::Decimal(v) => {
+            out.extend_from_slice(&[0xc7, 17, EXT_DECIMAL as u8, v.scale() as u8]);
+            out.extend_from_slice(&v.coefficient().to_be_bytes());
+        }
+    }
+}
+
+/// Write a length header: the fix format (`markers[0]`) below `fix`, then
+/// the 8, 16 and 32 bit formats. A `0` marker means the format doesn't
+/// exist for this kind.
+fn header(out: &mut Vec<u8>, len: usize, markers: [u8; 4], fix: usize) -> std::io::Result<()> {
+    if len < fix {
+        out.push(markers[0] | len as u8);
+    } else if len <= u8::MAX as usize && markers[1] != 0 {
+        out.extend_from_slice(&[markers[1], len as u8]);
+    } else if len <= u16::MAX as usize {
+        out.push(markers[2]);
+        out.extend_from_slice(&(len as u16).to_be_bytes());
+    } else if len <= u32::MAX as usize {
+        out.push(markers[3]);
+        out.extend_from_slice(&(len as u32).to_be_bytes());
+    } else {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "length exceeds u32::MAX",
+        ));
+    }
+
+    Ok(())
+}
+
+fn ext(out: &mut Vec<u8>, kind: i8, payload: &[u8]) -> std::io::Result<()> {
+    match payload.len() {
+        1 => out.push(0xd4),
+        2 => out.push(0xd5),
+        4 => out.push(0xd6),
+        8 => out.push(0xd7),
+        16 => out.push(0xd8),
+        len => header(out, len, [0, 0xc7, 0xc8, 0xc9], 0)?,
+    }
+
+    out.push(kind as u8);
+    out.extend_from_slice(payload);
+    Ok(())
+}
+
+fn read(input: &mut Input, depth: usize) -> Result<Value, BinaryError> {
+    let start = input.pos();
+    let marker = input.byte()?;
+
+    Ok(match marker {
+        0x00..=0x7f => Value::from_i8(marker as i8),
+        0x80..=0x8f => read_map(input, (marker & 0x0f) as u64, depth)?,
+        0x90..=0x9f => read_array(input, (marker & 0x0f) as u64, depth)?,
+        0xa0..=0xbf => read_str(input, (marker & 0x1f) as u64)?,
+        0xc0 => Value::Null,
+        0xc2 => Value::from_bool(false),
+        0xc3 => Value::from_bool(true),
+        0xc4..=0xc6 => {
+            let len = read_len(input, marker - 0xc4)?;
+            let len = input.items(len, 1)?;
+            Value::from_bytes(input.take(len)?)
+        }
+        0xc7..=0xc9 => {
+            let len = read_len(input, marker - 0xc7)?;
+            read_ext(input, len, depth)?
+        }
+        0xca => Value::from_f32(f32::from_be_bytes(input.array()?)),
+        0xcb => Value::from_f64(f64::from_be_bytes(input.array()?)),
+        0xcc => Value::from_u8(input.byte()?),
+        0xcd => Value::from_u16(u16::from_be_bytes(input.array()?)),
+        0xce => Value::from_u32(u32::from_be_bytes(input.array()?)),
+        0xcf => Value::from_u64(u64::from_be_bytes(input.array()?)),
+        0xd0 => Value::from_i8(input.byte()? as i8),
+        0xd1 => Value::from_i16(i16::from_be_bytes(input.array()?)),
+        0xd2 => Value::from_i32(i32::from_be_bytes(input.array()?)),
+        0xd3 => Value::from_i64(i64::from_be_bytes(input.array()?)),
+        0xd4..=0xd8 => read_ext(input, 1 << (marker - 0xd4), depth)?,
+        0xd9..=0xdb => {
+            let len = read_len(input, marker - 0xd9)?;
+            read_str(input, len)?
+        }
+        0xdc | 0xdd => {
+            let len = read_len(input, marker - 0xdc + 1)?;
+            read_array(input, len, depth)?
+        }
+        0xde | 0xdf => {
+            let len = read_len(input, marker - 0xde + 1)?;
+            read_map(input, len, depth)?
+        }
+        0xe0..=0xff => Value::from_i8(marker as i8),
+        0xc1 => return Err(BinaryError::new("reserved marker 0xc1", start)),
+    })
+}
+
+/// Read a length of 1, 2 or 4 bytes for `size` 0, 1 and 2.
+fn read_len(input: &mut Input, size: u8) -> Result<u64, BinaryError> {
+    Ok(match size {
+        0 => input.byte()? as u64,
+        1 => u16::from_be_bytes(input.array()?) as u64,
+        _ => u32::from_be_bytes(input.array()?) as u64,
+    })
+}
+
+fn read_str(input: &mut Input, len: u64) -> Result<Value, BinaryError> {
+    let len = input.items(len, 1)?;
+    Ok(Value::from_str(input.str(len)?))
+}
+
+fn read_array(input: &mut Input, len: u64, depth: usize) -> Result<Value, BinaryError> {
+    let depth = input.nest(depth)?;
+    let len = input.items(len, 1)?;
+    let mut items = Vec::with_capacity(len);
+
+    for _ in 0..len {
+        items.push(read(input, depth)?);
+    }
+
+    Ok(Value::from(items))
+}
+
+fn read_map(input: &mut Input, len: u64, depth: usize) -> Result<Value, BinaryError> {
+    let depth = input.nest(depth)?;
+    let len = input.items(len, 2)?;
+    let mut map = OrderedMap::with_capacity(len);
+
+    for _ in 0..len {
+        let start = input.pos();
+        let ident = match read(input, depth)? {
+            Value::String(v) => Ident::key(v.as_str()),
+            Value::Number(Number::UInt(v)) => match usize::try_from(v.to_u128()) {
+                Ok(i) => Ident::index(i),
+                Err(_) => return Err(BinaryError::new("map key out of range", start)),
+            },
+            _ => {
+                return Err(BinaryError::new(
+                    "map keys must be strings or unsigned integers",
+                    start,
+                ));
+            }
+        };
+
+        map.insert(ident, read(input, depth)?);
+    }
+
+    Ok(Value::from(map))
+}
+
+fn read_ext(input: &mut Input, len: u64, depth: usize) -> Result<Value, BinaryError> {
+    let kind = input.byte()? as i8;
+    let start = input.pos();
+    let len = input.items(len, 1)?;
+    let invalid = || BinaryError::new(format!("invalid length {len} for extension {kind}"), start);
+
+    let value = match (kind, len) {
+        (EXT_I128, 16) => Value::from_i128(i128::from_be_bytes(input.array()?)),
+        (EXT_U128, 16) => Value::from_u128(u128::from_be_bytes(input.array()?)),
+        (EXT_TUPLE, _) => {
+            let depth = input.nest(depth)?;
+            let items = match read(input, depth)? {
+                Value::Object(Object::Array(v)) => v.items().map(|v| v.to_value()).collect(),
+                _ => return Err(BinaryError::new("tuple payload must be an array", start)),
+            };
+
+            if input.pos() - start != len {
+                return Err(invalid());
+            }
+
+            Value::from(TupleVec::new(items))
+        }
+        (EXT_DATETIME, 20) => {
+            let nanos = i128::from_be_bytes(input.array()?);
+            let offset = i32::from_be_bytes(input.array()?);
+            let value = DateTime::from_unix_nanos(nanos)
+                .and_then(|v| v.with_offset(offset))
+                .map_err(|err| BinaryError::new(err.to_string(), start))?;
+            Value::from(value)
+        }
+        (EXT_DATE, 4) => {
+            let days = i32::from_be_bytes(input.array()?);
+            let value = Date::from_unix_days(days as i64)
+                .map_err(|err| BinaryError::new(err.to_string(), start))?;
+            Value::from(value)
+        }
+        (EXT_DURATION, 16) => {
+            Value::from(Duration::from_nanos(i128::from_be_bytes(input.array()?)))
+        }
+        (EXT_TIMESTAMP, 4 | 8 | 12) => {
+            let (secs, nanos) = match len {
+                4 => (u32::from_be_bytes(input.array()?) as i128, 0),
+                8 => {
+                    let v = u64::from_be_bytes(input.array()?);
+                    ((v & 0x3_ffff_ffff) as i128, (v >> 34) as i128)
+                }
+                _ => {
+                    let nanos = u32::from_be_bytes(input.array()?) as i128;
+                    (i64::from_be_bytes(input.array()?) as i128, nanos)
+                }
+            };
+
+            let value = DateTime::from_unix_nanos(secs * 1_000_000_000 + nanos)
+                .map_err(|err| BinaryError::new(err.to_string(), start))?;
+            Value::from(value)
+        }
+        (EXT_DECIMAL, 17) => {
+            let scale = input.byte()? as u32;
+            let value = Decimal::new(i128::from_be_bytes(input.array()?), scale)
+                .map_err(|err| BinaryError::new(err.to_string(), start))?;
+            Value::from(value)
+        }
+        (
+            EXT_I128 | EXT_U128 | EXT_DATETIME | EXT_DATE | EXT_DURATION | EXT_DECIMAL
+            | EXT_TIMESTAMP,
+            _,
+        ) => {
+            return Err(invalid());
+        }
+        _ => {
+            return Err(BinaryError::new(
+                format!("unsupported extension type {kind}"),
+                start,
+            ));
+        }
+    };
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn sample() -> Value {
+        valueof!({
+            "ints": [(i8::MIN), (i16::MAX), (-7_i32), (i64::MIN), (i128::MIN)],
+            "uints": [0_u8, (u16::MAX), 7_u32, (u64::MAX), (u128::MAX)],
+            "floats": [1.5_f32, (f64::MIN_POSITIVE)],
+            "decimals": [
+                ("12.30".parse::<Decimal>().unwrap()),
+                ("-170141183460469231731687303715.88410572".parse::<Decimal>().unwrap())
+            ],
+            "text": "héllo",
+            "bytes": b"\x00\xff",
+            "tuple": (1_u8, "a", (2_i64,)),
+            "empty": [],
+            "none": null,
+            "yes": true,
+        })
+    }
+
+    #[test]
+    fn round_trip() {
+        let v = sample();
+        assert!(
+            binary::msgpack::from_slice(&binary::msgpack::to_vec(&v))
+                .unwrap()
+                .strict_eq(&v)
+        );
+    }
+
+    #[test]
+    fn round_trip_time() {
+        let v = valueof!([
+            (DateTime::parse("2024-05-01T10:30:00.123456789+05:30").unwrap()),
+            (Date::from_ymd(1969, 12, 31).unwrap()),
+            (Duration::from_nanos(-1_500))
+        ]);
+
+        let back = binary::msgpack::from_slice(&binary::msgpack::to_vec(&v)).unwrap();
+        assert!(back.strict_eq(&v));
+        assert_eq!(
+            back.as_array().index(0).unwrap().to_value().to_string(),
+            "2024-05-01T10:30:00.123456789+05:30"
+        );
+    }
+
+    #[test]
+    fn exact_widths() {
+        assert_eq!(binary::msgpack::to_vec(&valueof!(1_i8)), [0xd0, 0x01]);
+        assert_eq!(
+            binary::msgpack::to_vec(&valueof!(1_u16)),
+            [0xcd, 0x00, 0x01]
+        );
+        assert_eq!(
+            binary::msgpack::to_vec(&valueof!(1.0_f32)),
+            [0xca, 0x3f, 0x80, 0x00, 0x00]
+        );
+        assert_eq!(
+            binary::msgpack::to_vec(&valueof!({ "a": null })),
+            [0x81, 0xa1, b'a', 0xc0]
+        );
+    }
+
+    #[test]
+    fn foreign_input() {
+        // fixints, a str8 and the standard 32-bit timestamp
+        let v = binary::msgpack::from_slice(&[
+            0x94, 0x05, 0xff, 0xd9, 0x01, b'x', 0xd6, 0xff, 0x00, 0x00, 0x00, 0x3c,
+        ])
+        .unwrap();
+
+        assert!(v.strict_eq(&valueof!([
+            5_i8,
+            (-1_i8),
+            "x",
+            (DateTime::from_unix_secs(60).unwrap())
+        ])));
+    }
+
+    #[test]
+    fn hostile_input() {
+        let cases: [(&[u8], &str); 7] = [
+            (&[0xdd, 0xff, 0xff, 0xff, 0xff], "length exceeds input"),
+            (
+                &[0xdb, 0xff, 0xff, 0xff, 0xff, b'a'],
+                "length exceeds input",
+            ),
+            (&[0xa2, 0xc3, 0x28], "invalid utf-8 in string"),
+            (
+                &[0x81, 0xc0, 0xc0],
+                "map keys must be strings or unsigned integers",
+            ),
+            (&[0xc1], "reserved marker 0xc1"),
+            (&[0xd4, 0x7f, 0x00], "unsupported extension type 127"),
+            (&[0xc0, 0xc0], "trailing bytes"),
+        ];
+
+        for (input, message) in cases {
+            let err = binary::msgpack::from_slice(input).unwrap_err();
+            assert_eq!(err.message, message, "{input:?}");
+        }
+
+        let deep = vec![0x91; 10_000];
+        let err = binary::msgpack::from_slice(&deep).unwrap_err();
+        assert_eq!(err.message, "nesting too deep");
+        assert_eq!(err.offset, binary::MAX_DEPTH + 1);
+
+        assert_eq!(
+            binary::msgpack::from_slice(&[0xcd, 0x01]).unwrap_err(),
+            binary::BinaryError::new("unexpected end of input", 1)
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{ToValue, Value};
+use crate::{ToValue, Value, ValueRef};
 
 /// A type-safe wrapper around a [`bool`] value.
 #[repr(transparent)]
@@ -84,12 +84,20 @@ impl ToValue for Bool {
     fn to_value(&self) -> Value {
         Value::Bool(*self)
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Bool(self.to_bool())
+    }
 }
 
 impl ToValue for bool {
     fn to_value(&self) -> Value {
         Value::from_bool(*self)
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Bool(*self)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,375 @@
+use std::sync::Arc;
+
+use crate::{ToValue, Value, ValueRef};
+
+/// An immutable byte buffer that is cheap to clone.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bytes(Arc<[u8]>);
+
+impl Bytes {
+    pub fn from_slice(value: &[u8]) -> Self {
+        Self(value.into())
+    }
+
+    pub fn from_vec(value: Vec<u8>) -> Self {
+        Self(value.into())
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.0.to_vec()
+    }
+
+    /// Standard, padded base64, as used by human-readable serde formats.
+    pub fn to_base64(&self) -> String {
+        base64::encode(&self.0)
+    }
+
+    pub fn from_base64(value: &str) -> Option<Self> {
+        base64::decode(value).map(Self::from_vec)
+    }
+
+    pub fn type_id(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<Vec<u8>>()
+    }
+}
+
+impl From<&[u8]> for Bytes {
+    fn from(value: &[u8]) -> Self {
+        Self::from_slice(value)
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for Bytes {
+    fn from(value: [u8; N]) -> Self {
+        Self::from_slice(&value)
+    }
+}
+
+impl<const N: usize> From<&[u8; N]> for Bytes {
+    fn from(value: &[u8; N]) -> Self {
+        Self::from_slice(value)
+    }
+}
+
+impl From<Vec<u8>> for Bytes {
+    fn from(value: Vec<u8>) -> Self {
+        Self::from_vec(value)
+    }
+}
+
+impl From<Box<[u8]>> for Bytes {
+    fn from(value: Box<[u8]>) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<Arc<[u8]>> for Bytes {
+    fn from(value: Arc<[u8]>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Bytes> for Value {
+    fn from(value: Bytes) -> Self {
+        Self::Bytes(value)
+    }
+}
+
+impl From<&[u8]> for Value {
+    fn from(value: &[u8]) -> Self {
+        Self::from_bytes(value)
+    }
+}
+
+impl From<Vec<u8>> for Value {
+    fn from(value: Vec<u8>) -> Self {
+        Self::from_bytes(value)
+    }
+}
+
+impl Value {
+    pub fn from_bytes(value: impl Into<Bytes>) -> Self {
+        Self::Bytes(value.into())
+    }
+}
+
+impl std::ops::Deref for Bytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for Bytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for Bytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "b\"{}\"", self.0.escape_ascii())
+    }
+}
+
+impl std::fmt::Display for Bytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_base64())
+    }
+}
+
+impl PartialEq<[u8]> for Bytes {
+    fn eq(&self, other: &[u8]) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl PartialEq<&[u8]> for Bytes {
+    fn eq(&self, other: &&[u8]) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<Vec<u8>> for Bytes {
+    fn eq(&self, other: &Vec<u8>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl PartialEq<[u8]> for Value {
+    fn eq(&self, other: &[u8]) -> bool {
+        matches!(self, Self::Bytes(v) if v == other)
+    }
+}
+
+impl PartialEq<&[u8]> for Value {
+    fn eq(&self, other: &&[u8]) -> bool {
+        self == *other
+    }
+}
+
+impl ToValue for Bytes {
+    fn to_value(&self) -> Value {
+        Value::Bytes(self.clone())
+    }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Bytes(self.as_slice())
+    }
+}
+
+// `Vec<u8>` and `[u8]` already convert to arrays through the generic
+// `Vec<T>`/`[T]` impls, so only fixed-size byte arrays, which have no
+// generic impl, map to bytes. Use `Bytes::from` or `valueof!(v as bytes)`
+// for the rest.
+impl<const N: usize> ToValue for [u8; N] {
+    fn to_value(&self) -> Value {
+        Value::from_bytes(self)
+    }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Bytes(self)
+    }
+}
+
+impl<const N: usize> ToValue for &[u8; N] {
+    fn to_value(&self) -> Value {
+        Value::from_bytes(*self)
+    }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Bytes(*self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Bytes {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_base64())
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Bytes {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(BytesVisitor)
+        } else {
+            deserializer.deserialize_bytes(BytesVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+struct BytesVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for BytesVisitor {
+    type Value = Bytes;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("bytes or a base64 string")
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(Bytes::from_slice(v))
+    }
+
+    fn visit_byte_buf<E: serde::de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(Bytes::from_vec(v))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Bytes::from_base64(v).ok_or_else(|| E::custom("invalid base64"))
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+
+        while let Some(byte) = seq.next_element::<u8>()? {
+            bytes.push(byte);
+        }
+
+        Ok(Bytes::from_vec(bytes))
+    }
+}
+
+pub(crate) mod base64 {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    pub fn encode(input: &[u8]) -> String {
+        let mut out = String::with_capacity(input.len().div_ceil(3) * 4);
+
+        for chunk in input.chunks(3) {
+            let b = [
+                chunk[0],
+                *chunk.get(1).unwrap_or(&0),
+                *chunk.get(2).unwrap_or(&0),
+            ];
+            let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+                } else {
+                    out.push('=');
+                }
+            }
+        }
+
+        out
+    }
+
+    pub fn decode(input: &str) -> Option<Vec<u8>> {
+        let input = input.as_bytes();
+
+        if !input.len().is_multiple_of(4) {
+            return None;
+        }
+
+        let mut out = Vec::with_capacity(input.len() / 4 * 3);
+
+        for (i, chunk) in input.chunks(4).enumerate() {
+            let last = i == input.len() / 4 - 1;
+            let pad = chunk.iter().rev().take_while(|&&c| c == b'=').count();
+
+            if pad > 2 || (pad > 0 && !last) {
+                return None;
+            }
+
+            let mut n = 0_u32;
+
+            for &c in &chunk[..4 - pad] {
+                let digit = ALPHABET.iter().position(|&a| a == c)? as u32;
+                n = n << 6 | digit;
+            }
+
+            n <<= 6 * pad as u32;
+            out.extend_from_slice(&n.to_be_bytes()[1..4 - pad]);
+        }
+
+        Some(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn base64_round_trip() {
+        for (raw, encoded) in [
+            (&b""[..], ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foobar", "Zm9vYmFy"),
+            (&[0xff, 0x00, 0xfe], "/wD+"),
+        ] {
+            let bytes = Bytes::from(raw);
+            assert_eq!(bytes.to_base64(), encoded);
+            assert_eq!(Bytes::from_base64(encoded), Some(bytes));
+        }
+
+        assert_eq!(Bytes::from_base64("Zg="), None);
+        assert_eq!(Bytes::from_base64("Zg==Zm8="), None);
+        assert_eq!(Bytes::from_base64("Z!=="), None);
+    }
+
+    #[test]
+    fn value() {
+        let v = valueof!(b"abc");
+        assert!(v.is_bytes());
+        assert_eq!(v.as_bytes(), &b"abc"[..]);
+        assert_eq!(v.len(), 3);
+        assert_eq!(v.type_name(), "bytes");
+
+        let data = vec![1_u8, 2];
+        let v = valueof!(data as bytes);
+        assert_eq!(Value::from(v), Value::from_bytes([1, 2]));
+        assert!(valueof!((vec![1_u8, 2])).is_array());
+    }
+
+    #[test]
+    fn clone_shares_buffer() {
+        let a = Bytes::from_vec(vec![0; 1024]);
+        let b = a.clone();
+        assert_eq!(a.as_slice().as_ptr(), b.as_slice().as_ptr());
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", Bytes::from(b"hi\n")), "b\"hi\\n\"");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json_uses_base64() {
+        let bytes = Bytes::from(b"hello");
+        let json = serde_json::to_string(&bytes).unwrap();
+        assert_eq!(json, "\"aGVsbG8=\"");
+        assert_eq!(serde_json::from_str::<Bytes>(&json).unwrap(), bytes);
+        assert_eq!(
+            serde_json::from_str::<Bytes>("[104, 105]").unwrap(),
+            b"hi".to_vec()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_value() {
+        let v = valueof!(b"hi");
+        assert_eq!(serde_json::to_string(&v).unwrap(), "\"aGk=\"");
+        assert_eq!(crate::from_value::<Vec<u8>>(&v).unwrap(), b"hi");
+        assert!(crate::from_value::<Bytes>(&v).is_ok());
+    }
+}
@@ -0,0 +1,498 @@
+use crate::num::CanonicalNumber;
+use crate::{Date, DateTime, Duration, Number, Time, ToValue, Value, ValueError};
+
+/// The target of [`Value::coerce`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Kind {
+    Null,
+    Bool,
+    String,
+    Bytes,
+    /// Any number, keeping its current width.
+    Number,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    F32,
+    F64,
+    Decimal,
+    DateTime,
+    Date,
+    Duration,
+}
+
+impl Kind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Null => "null",
+            Self::Bool => "bool",
+            Self::String => "string",
+            Self::Bytes => "bytes",
+            Self::Number => "number",
+            Self::I8 => "i8",
+            Self::I16 => "i16",
+            Self::I32 => "i32",
+            Self::I64 => "i64",
+            Self::I128 => "i128",
+            Self::U8 => "u8",
+            Self::U16 => "u16",
+            Self::U32 => "u32",
+            Self::U64 => "u64",
+            Self::U128 => "u128",
+            Self::F32 => "f32",
+            Self::F64 => "f64",
+            Self::Decimal => "decimal",
+            Self::DateTime => "datetime",
+            Self::Date => "date",
+            Self::Duration => "duration",
+        }
+    }
+}
+
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// How forgiving [`Value::coerce`] is.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CoercePolicy {
+    /// Only conversions that neither lose nor guess information:
+    ///
+    /// - numbers change width when the value fits exactly
+    /// - strings parse when they are exactly `true`/`false`, a JSON number,
+    ///   or an RFC 3339 / ISO 8601 time
+    /// - bools, numbers and times format as strings
+    #[default]
+    Strict,
+    /// Everything [`CoercePolicy::Strict`] allows, and:
+    ///
+    /// - strings are trimmed before parsing, numbers may have a leading `+`
+    ///   or be `inf`/`NaN`, and bools may be any case of `true`/`false`,
+    ///   `yes`/`no`, `y`/`n`, `on`/`off`, `t`/`f` or `1`/`0`
+    /// - the numbers `1` and `0` become `true` and `false`, and back
+    /// - floats round to the nearest `f32`/`f64`, though a finite number
+    ///   still can't become infinite; integers still need to fit
+    /// - an array or tuple of one item is replaced by that item
+    /// - strings and bytes convert as UTF-8, and dates become datetimes at
+    ///   midnight UTC
+    Lenient,
+}
+
+impl Value {
+    /// Convert this value to `kind`, failing with
+    /// [`ValueError::TypeMismatch`] when `policy` has no rule for it,
+    /// [`ValueError::Parse`] for unreadable text and the numeric errors of
+    /// the `checked_to_*` conversions for numbers that don't fit.
+    pub fn coerce(&self, kind: Kind, policy: CoercePolicy) -> Result<Self, ValueError> {
+        let lenient = policy == CoercePolicy::Lenient;
+
+        if lenient && (self.is_array() || self.is_tuple()) && self.as_object().len() == 1 {
+            let item = match self.is_array() {
+                true => self.as_array().index(0),
+                false => self.as_tuple().index(0),
+            };
+
+            if let Some(item) = item {
+                return item.to_value().coerce(kind, policy);
+            }
+        }
+
+        let mismatch = || ValueError::type_mismatch(kind.as_str(), self.type_name());
+
+        match kind {
+            Kind::Null if self.is_null() => Ok(Self::Null),
+            Kind::Null => Err(mismatch()),
+            Kind::Bool => match self {
+                Self::Bool(_) => Ok(self.clone()),
+                Self::String(v) => parse_bool(v.as_str(), lenient).map(|v| v.to_value()),
+                Self::Number(v) if lenient => match v.canonical() {
+                    CanonicalNumber::Int(0) => Ok(false.to_value()),
+                    CanonicalNumber::Int(1) => Ok(true.to_value()),
+                    _ => Err(ValueError::overflow(v, "bool")),
+                },
+                _ => Err(mismatch()),
+            },
+            Kind::String => match self {
+                Self::String(_) => Ok(self.clone()),
+                Self::Bool(_) | Self::Number(_) | Self::Time(_) => Ok(self.to_string().to_value()),
+                Self::Bytes(v) if lenient => match std::str::from_utf8(v) {
+                    Ok(v) => Ok(v.to_value()),
+                    Err(_) => Err(ValueError::parse("string", "invalid utf-8")),
+                },
+                _ => Err(mismatch()),
+            },
+            Kind::Bytes => match self {
+                Self::Bytes(_) => Ok(self.clone()),
+                Self::String(v) if lenient => Ok(Self::from_bytes(v.as_str().as_bytes())),
+                _ => Err(mismatch()),
+            },
+            Kind::DateTime | Kind::Date | Kind::Duration => match self {
+                Self::Time(v) if v.type_name() == kind.as_str() => Ok(self.clone()),
+                Self::Time(Time::Date(v)) if lenient && kind == Kind::DateTime => {
+                    Ok(DateTime::from_date(*v).to_value())
+                }
+                Self::String(v) => {
+                    let text = if lenient {
+                        v.as_str().trim()
+                    } else {
+                        v.as_str()
+                    };
+
+                    match kind {
+                        Kind::DateTime => DateTime::parse(text).map(Self::from),
+                        Kind::Date => Date::parse(text).map(Self::from),
+                        _ => Duration::parse(text).map(Self::from),
+                    }
+                }
+                _ => Err(mismatch()),
+            },
+            _ => {
+                let number = match self {
+                    Self::Number(v) => *v,
+                    Self::String(v) => parse_number(v.as_str(), kind, lenient)?,
+                    Self::Bool(v) if lenient => Number::from_u8(v.to_bool() as u8),
+                    _ => return Err(mismatch()),
+                };
+
+                narrow(number, kind, lenient)
+            }
+        }
+    }
+}
+
+fn parse_bool(text: &str, lenient: bool) -> Result<bool, ValueError> {
+    if !lenient {
+        return match text {
+            "true" => Ok(true),
+            "false" => Ok(false),
+            _ => Err(ValueError::parse("bool", text)),
+        };
+    }
+
+    match text.trim().to_ascii_lowercase().as_str() {
+        "true" | "yes" | "y" | "on" | "t" | "1" => Ok(true),
+        "false" | "no" | "n" | "off" | "f" | "0" => Ok(false),
+        _ => Err(ValueError::parse("bool", text)),
+    }
+}
+
+/// JSON number syntax, so integers keep their exact value; lenient parsing
+/// falls back to Rust's float syntax. Decimal and integer targets read a
+/// fraction or exponent as a decimal, so `narrow` sees the exact value
+/// rather than the nearest float.
+fn parse_number(text: &str, kind: Kind, lenient: bool) -> Result<Number, ValueError> {
+    let trimmed = if lenient { text.trim() } else { text };
+    let unsigned = match lenient {
+        true => trimmed.strip_prefix('+').unwrap_or(trimmed),
+        false => trimmed,
+    };
+
+    // `json::from_str` skips surrounding whitespace, strict parsing doesn't
+    if unsigned.trim() == unsigned
+        && let Ok(Value::Number(v)) = match kind {
+            Kind::Decimal
+            | Kind::I8
+            | Kind::I16
+            | Kind::I32
+            | Kind::I64
+            | Kind::I128
+            | Kind::U8
+            | Kind::U16
+            | Kind::U32
+            | Kind::U64
+            | Kind::U128 => crate::json::from_str_decimal(unsigned),
+            _ => crate::json::from_str(unsigned),
+        }
+    {
+        return Ok(v);
+    }
+
+    let err = || ValueError::parse(kind.as_str(), text);
+
+    if !lenient {
+        return Err(err());
+    }
+
+    let value = trimmed.parse::<f64>().map_err(|_| err())?;
+    let digits = unsigned.trim_start_matches('-').to_ascii_lowercase();
+
+    // `1e400` parses as infinity; only `inf` and `infinity` should
+    if value.is_infinite() && !digits.starts_with("inf") {
+        return Err(ValueError::overflow(text, kind.as_str()));
+    }
+
+    Ok(Number::from_f64(value))
+}
+
+fn narrow(number: Number, kind: Kind, lenient: bool) -> Result<Value, ValueError> {
+    Ok(match kind {
+        Kind::I8 => number.checked_to_i8()?.to_value(),
+        Kind::I16 => number.checked_to_i16()?.to_value(),
+        Kind::I32 => number.checked_to_i32()?.to_value(),
+        Kind::I64 => number.checked_to_i64()?.to_value(),
+        Kind::I128 => number.checked_to_i128()?.to_value(),
+        Kind::U8 => number.checked_to_u8()?.to_value(),
+        Kind::U16 => number.checked_to_u16()?.to_value(),
+        Kind::U32 => number.checked_to_u32()?.to_value(),
+        Kind::U64 => number.checked_to_u64()?.to_value(),
+        Kind::U128 => number.checked_to_u128()?.to_value(),
+        Kind::F32 | Kind::F64 if lenient => round_float(number, kind)?,
+        Kind::F32 => number.checked_to_f32()?.to_value(),
+        Kind::F64 => number.checked_to_f64()?.to_value(),
+        Kind::Decimal => number.checked_to_decimal()?.to_value(),
+        _ => Value::Number(number),
+    })
+}
+
+/// Lenient float targets round to the nearest value, but a finite number
+/// only the target's infinity can hold is still an overflow.
+fn round_float(number: Number, kind: Kind) -> Result<Value, ValueError> {
+    let rounded = match kind {
+        Kind::F32 => Number::from_f32(number.to_f32()),
+        _ => Number::from_f64(number.to_f64()),
+    };
+
+    if rounded.to_f64().is_infinite() && number.to_f64().is_finite() {
+        return Err(ValueError::overflow(number, kind.as_str()));
+    }
+
+    Ok(Value::Number(rounded))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn strict(v: Value, kind: Kind) -> Result<Value, ValueError> {
+        v.coerce(kind, CoercePolicy::Strict)
+    }
+
+    fn lenient(v: Value, kind: Kind) -> Result<Value, ValueError> {
+        v.coerce(kind, CoercePolicy::Lenient)
+    }
+
+    #[test]
+    fn numeric_strings() {
+        assert!(
+            strict(valueof!("42"), Kind::I32)
+                .unwrap()
+                .strict_eq(&valueof!(42_i32))
+        );
+        assert!(
+            strict(valueof!("-2.5"), Kind::F64)
+                .unwrap()
+                .strict_eq(&valueof!(-2.5_f64))
+        );
+        assert!(
+            strict(valueof!("1e2"), Kind::U8)
+                .unwrap()
+                .strict_eq(&valueof!(100_u8))
+        );
+        assert!(
+            strict(valueof!("9007199254740993.0"), Kind::I64)
+                .unwrap()
+                .strict_eq(&valueof!(9_007_199_254_740_993_i64))
+        );
+        assert!(strict(valueof!("9007199254740993.5"), Kind::I64).is_err());
+        assert!(
+            strict(
+                valueof!("340282366920938463463374607431768211455"),
+                Kind::U128
+            )
+            .unwrap()
+            .strict_eq(&valueof!((u128::MAX)))
+        );
+        assert!(strict(valueof!("42"), Kind::Number).unwrap().is_i64());
+
+        for text in [" 42", "+42", "42x", "", "inf", "0x10"] {
+            assert_eq!(
+                strict(valueof!((text)), Kind::I32),
+                Err(ValueError::parse("i32", text))
+            );
+        }
+
+        assert_eq!(
+            lenient(valueof!(" +42 "), Kind::I32).unwrap(),
+            valueof!(42_i32)
+        );
+        assert!(
+            lenient(valueof!("inf"), Kind::F64)
+                .unwrap()
+                .to_f64()
+                .is_infinite()
+        );
+        assert!(lenient(valueof!("0x10"), Kind::I32).is_err());
+    }
+
+    #[test]
+    fn decimals() {
+        assert_eq!(
+            strict(valueof!("12.30"), Kind::Decimal)
+                .unwrap()
+                .as_decimal()
+                .to_string(),
+            "12.30"
+        );
+        assert_eq!(
+            strict(valueof!(0.1_f64), Kind::Decimal)
+                .unwrap()
+                .as_decimal()
+                .to_string(),
+            "0.1"
+        );
+        assert_eq!(
+            strict(valueof!("1.5"), Kind::Decimal)
+                .and_then(|v| v.coerce(Kind::I32, CoercePolicy::Strict)),
+            Err(ValueError::precision_loss("1.5", "i32"))
+        );
+    }
+
+    #[test]
+    fn narrowing() {
+        assert!(
+            strict(valueof!(300_i64), Kind::U16)
+                .unwrap()
+                .strict_eq(&valueof!(300_u16))
+        );
+        assert_eq!(
+            strict(valueof!(300_i64), Kind::U8),
+            Err(ValueError::overflow(300, "u8"))
+        );
+        assert!(strict(valueof!(-1_i32), Kind::U32).is_err());
+        assert!(
+            strict(valueof!(2.0_f64), Kind::I8)
+                .unwrap()
+                .strict_eq(&valueof!(2_i8))
+        );
+        assert!(strict(valueof!(2.5_f64), Kind::I8).is_err());
+        assert!(lenient(valueof!(2.5_f64), Kind::I8).is_err());
+        assert!(strict(valueof!(0.1_f64), Kind::F32).is_err());
+        assert!(lenient(valueof!(0.1_f64), Kind::F32).unwrap().is_f32());
+        assert_eq!(
+            lenient(valueof!(1e300_f64), Kind::F32),
+            Err(ValueError::overflow(1e300_f64, "f32"))
+        );
+        assert!(lenient(valueof!((f64::INFINITY)), Kind::F32).is_ok());
+        assert_eq!(
+            lenient(valueof!("1e400"), Kind::F64),
+            Err(ValueError::overflow("1e400", "f64"))
+        );
+        assert!(
+            lenient(valueof!("-Infinity"), Kind::F64)
+                .unwrap()
+                .to_f64()
+                .is_infinite()
+        );
+        assert!(strict(valueof!(7_u8), Kind::Number).unwrap().is_u8());
+    }
+
+    #[test]
+    fn bools() {
+        assert_eq!(
+            strict(valueof!("true"), Kind::Bool).unwrap(),
+            valueof!(true)
+        );
+        assert!(strict(valueof!("True"), Kind::Bool).is_err());
+        assert!(strict(valueof!(1_i32), Kind::Bool).is_err());
+        assert!(strict(valueof!(true), Kind::I32).is_err());
+
+        for (text, expected) in [("Yes", true), (" off ", false), ("1", true), ("F", false)] {
+            assert_eq!(
+                lenient(valueof!((text)), Kind::Bool).unwrap(),
+                valueof!((expected))
+            );
+        }
+
+        assert_eq!(lenient(valueof!(1_u8), Kind::Bool).unwrap(), valueof!(true));
+        assert_eq!(
+            lenient(valueof!(0.0_f64), Kind::Bool).unwrap(),
+            valueof!(false)
+        );
+        assert!(lenient(valueof!(2_i32), Kind::Bool).is_err());
+        assert!(lenient(valueof!("maybe"), Kind::Bool).is_err());
+        assert!(
+            lenient(valueof!(true), Kind::I32)
+                .unwrap()
+                .strict_eq(&valueof!(1_i32))
+        );
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(
+            strict(valueof!(42_i32), Kind::String).unwrap(),
+            valueof!("42")
+        );
+        assert_eq!(
+            strict(valueof!(false), Kind::String).unwrap(),
+            valueof!("false")
+        );
+        assert!(strict(valueof!(null), Kind::String).is_err());
+        assert!(strict(valueof!([1_i32]), Kind::String).is_err());
+
+        let bytes = Value::from_bytes(b"hi".as_slice());
+        assert!(strict(bytes.clone(), Kind::String).is_err());
+        assert_eq!(
+            lenient(bytes.clone(), Kind::String).unwrap(),
+            valueof!("hi")
+        );
+        assert_eq!(lenient(valueof!("hi"), Kind::Bytes).unwrap(), bytes);
+    }
+
+    #[test]
+    fn times() {
+        let date = strict(valueof!("2024-05-01"), Kind::Date).unwrap();
+        assert!(date.is_date());
+        assert!(strict(valueof!(" 2024-05-01"), Kind::Date).is_err());
+        assert!(lenient(valueof!(" 2024-05-01"), Kind::Date).is_ok());
+        assert!(
+            strict(valueof!("PT1H"), Kind::Duration)
+                .unwrap()
+                .is_duration()
+        );
+
+        assert!(strict(date.clone(), Kind::DateTime).is_err());
+        assert_eq!(
+            lenient(date, Kind::DateTime).unwrap().to_string(),
+            "2024-05-01T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn unwrap_single() {
+        assert!(strict(valueof!(["42"]), Kind::I32).is_err());
+        assert_eq!(
+            lenient(valueof!(["42"]), Kind::I32).unwrap(),
+            valueof!(42_i32)
+        );
+        assert_eq!(
+            lenient(valueof!((true,)), Kind::String).unwrap(),
+            valueof!("true")
+        );
+        assert!(lenient(valueof!([1_i32, 2_i32]), Kind::I32).is_err());
+    }
+
+    #[test]
+    fn null() {
+        assert_eq!(strict(valueof!(null), Kind::Null).unwrap(), valueof!(null));
+        assert_eq!(
+            strict(valueof!(0_i32), Kind::Null),
+            Err(ValueError::type_mismatch("null", "i32"))
+        );
+        assert!(lenient(valueof!(null), Kind::I32).is_err());
+    }
+}
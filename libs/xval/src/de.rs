@@ -0,0 +1,562 @@
+use serde::de::{self, DeserializeOwned, Visitor};
+
+use crate::{
+    ArrayIter, FromValueError, Ident, Int, Number, StructIter, ToValue, UInt, Value, ValueRef,
+};
+
+/// Read any `T: DeserializeOwned` out of a [`Value`]. This is the inverse
+/// of [`to_value`](crate::to_value), and errors carry the path of the
+/// node that failed.
+pub fn from_value<T: DeserializeOwned>(value: &Value) -> Result<T, FromValueError> {
+    T::deserialize(value)
+}
+
+fn expected(expected: &str, received: &ValueRef<'_>) -> FromValueError {
+    FromValueError::new(format!(
+        "expected {}, received {}",
+        expected,
+        received.type_name()
+    ))
+}
+
+impl<'de> de::Deserializer<'de> for &Value {
+    type Error = FromValueError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FromValueError> {
+        self.as_value_ref().deserialize_any(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FromValueError> {
+        self.as_value_ref().deserialize_option(visitor)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FromValueError> {
+        self.as_value_ref().deserialize_seq(visitor)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FromValueError> {
+        self.as_value_ref().deserialize_f64(visitor)
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FromValueError> {
+        self.as_value_ref().deserialize_f32(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, FromValueError> {
+        self.as_value_ref()
+            .deserialize_newtype_struct(name, visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, FromValueError> {
+        self.as_value_ref()
+            .deserialize_enum(name, variants, visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, FromValueError> {
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 char str string
+        bytes byte_buf unit unit_struct tuple tuple_struct map struct identifier
+    }
+}
+
+/// Reads straight from the borrowed view, so nested items of a [`Value`]
+/// or a derived type are never converted to be deserialized.
+impl<'de> de::Deserializer<'de> for ValueRef<'_> {
+    type Error = FromValueError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FromValueError> {
+        match self {
+            ValueRef::Null => visitor.visit_unit(),
+            ValueRef::Bool(v) => visitor.visit_bool(v),
+            ValueRef::Number(Number::Int(v)) => match v {
+                Int::I8(v) => visitor.visit_i8(v),
+                Int::I16(v) => visitor.visit_i16(v),
+                Int::I32(v) => visitor.visit_i32(v),
+                Int::I64(v) => visitor.visit_i64(v),
+                Int::I128(v) => visitor.visit_i128(v),
+            },
+            ValueRef::Number(Number::UInt(v)) => match v {
+                UInt::U8(v) => visitor.visit_u8(v),
+                UInt::U16(v) => visitor.visit_u16(v),
+                UInt::U32(v) => visitor.visit_u32(v),
+                UInt::U64(v) => visitor.visit_u64(v),
+                UInt::U128(v) => visitor.visit_u128(v),
+            },
+            ValueRef::Number(Number::Float(v)) => match v {
+                crate::Float::F32(v) => visitor.visit_f32(v),
+                crate::Float::F64(v) => visitor.visit_f64(v),
+            },
+            ValueRef::Number(Number::Decimal(v)) => visitor.visit_str(&v.to_string()),
+            ValueRef::String(v) => visitor.visit_str(v),
+            ValueRef::Bytes(v) => visitor.visit_bytes(v),
+            ValueRef::Time(v) => visitor.visit_str(&v.to_string()),
+            ValueRef::Struct(v) => visitor.visit_map(StructAccess {
+                len: v.len(),
+                iter: v.items(),
+                value: None,
+            }),
+            ValueRef::Array(v) => visitor.visit_seq(SeqAccess {
+                len: v.len(),
+                iter: v.items(),
+                index: 0,
+            }),
+            ValueRef::Tuple(v) => visitor.visit_seq(SeqAccess {
+                len: v.len(),
+                iter: ArrayIter::new(v.items()),
+                index: 0,
+            }),
+            ValueRef::Owned(v) => v.as_value_ref().deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FromValueError> {
+        match self.is_null() {
+            true => visitor.visit_none(),
+            false => visitor.visit_some(self),
+        }
+    }
+
+    /// Bytes read as a sequence of `u8` here, so `Vec<u8>` fields accept them.
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FromValueError> {
+        match self {
+            ValueRef::Bytes(v) => {
+                visitor.visit_seq(de::value::SeqDeserializer::new(v.iter().copied()))
+            }
+            ValueRef::Owned(v) => v.as_value_ref().deserialize_seq(visitor),
+            v => v.deserialize_any(visitor),
+        }
+    }
+
+    /// Decimals read as their text elsewhere, so [`Decimal`](crate::Decimal)
+    /// fields keep every digit, and as their nearest float here.
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FromValueError> {
+        match self {
+            ValueRef::Number(Number::Decimal(v)) => visitor.visit_f64(v.to_f64()),
+            ValueRef::Owned(v) => v.as_value_ref().deserialize_f64(visitor),
+            v => v.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FromValueError> {
+        match self {
+            ValueRef::Number(Number::Decimal(v)) => visitor.visit_f32(v.to_f32()),
+            ValueRef::Owned(v) => v.as_value_ref().deserialize_f32(visitor),
+            v => v.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, FromValueError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, FromValueError> {
+        match self {
+            ValueRef::String(v) => visitor.visit_enum(EnumAccess {
+                variant: v.to_string(),
+                value: None,
+            }),
+            ValueRef::Struct(v) if v.len() == 1 => {
+                let (ident, value) = v.items().next().expect("struct has one field");
+
+                visitor.visit_enum(EnumAccess {
+                    variant: ident.to_string(),
+                    value: Some(value.as_value_ref()),
+                })
+            }
+            ValueRef::Owned(v) => v.as_value_ref().deserialize_enum(name, variants, visitor),
+            v => Err(expected(name, &v)),
+        }
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, FromValueError> {
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 char str string
+        bytes byte_buf unit unit_struct tuple tuple_struct map struct identifier
+    }
+}
+
+struct SeqAccess<'a> {
+    len: usize,
+    iter: ArrayIter<'a>,
+    index: usize,
+}
+
+impl<'de> de::SeqAccess<'de> for SeqAccess<'_> {
+    type Error = FromValueError;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, FromValueError> {
+        let Some(item) = self.iter.next() else {
+            return Ok(None);
+        };
+
+        let index = self.index;
+        self.index += 1;
+
+        seed.deserialize(item.as_value_ref())
+            .map(Some)
+            .map_err(|err| err.at(index))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len - self.index)
+    }
+}
+
+struct StructAccess<'a> {
+    len: usize,
+    iter: StructIter<'a>,
+    value: Option<(Ident, ValueRef<'a>)>,
+}
+
+impl<'de> de::MapAccess<'de> for StructAccess<'_> {
+    type Error = FromValueError;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, FromValueError> {
+        let Some((ident, value)) = self.iter.next() else {
+            return Ok(None);
+        };
+
+        self.len -= 1;
+        self.value = Some((ident.clone(), value.as_value_ref()));
+        seed.deserialize(KeyDeserializer(ident)).map(Some)
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, FromValueError> {
+        let (ident, value) = self
+            .value
+            .take()
+            .ok_or_else(|| FromValueError::new("map value requested before its key"))?;
+
+        seed.deserialize(value).map_err(|err| err.at(ident))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+/// Deserializes a struct field name. Keys are always strings, but integer
+/// targets (`HashMap<u32, _>`) are parsed from them.
+struct KeyDeserializer(Ident);
+
+macro_rules! deserialize_key_number {
+    ($($method:ident => $visit:ident: $T:ty),+ $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FromValueError> {
+                let key = self.0.to_string();
+
+                match key.parse::<$T>() {
+                    Ok(v) => visitor.$visit(v),
+                    Err(_) => visitor.visit_str(&key),
+                }
+            }
+        )+
+    };
+}
+
+impl<'de> de::Deserializer<'de> for KeyDeserializer {
+    type Error = FromValueError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FromValueError> {
+        visitor.visit_str(&self.0.to_string())
+    }
+
+    deserialize_key_number! {
+        deserialize_i8 => visit_i8: i8,
+        deserialize_i16 => visit_i16: i16,
+        deserialize_i32 => visit_i32: i32,
+        deserialize_i64 => visit_i64: i64,
+        deserialize_i128 => visit_i128: i128,
+        deserialize_u8 => visit_u8: u8,
+        deserialize_u16 => visit_u16: u16,
+        deserialize_u32 => visit_u32: u32,
+        deserialize_u64 => visit_u64: u64,
+        deserialize_u128 => visit_u128: u128,
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, FromValueError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, FromValueError> {
+        visitor.visit_enum(EnumAccess {
+            variant: self.0.to_string(),
+            value: None,
+        })
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool f32 f64 char str string bytes byte_buf option unit unit_struct
+        seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// An externally tagged enum: the variant name and, for non-unit
+/// variants, its content.
+struct EnumAccess<'a> {
+    variant: String,
+    value: Option<ValueRef<'a>>,
+}
+
+impl<'de, 'a> de::EnumAccess<'de> for EnumAccess<'a> {
+    type Error = FromValueError;
+    type Variant = VariantAccess<'a>;
+
+    fn variant_seed<V: de::DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, VariantAccess<'a>), FromValueError> {
+        let variant = seed.deserialize(de::value::StrDeserializer::<FromValueError>::new(
+            &self.variant,
+        ))?;
+
+        Ok((
+            variant,
+            VariantAccess {
+                variant: self.variant,
+                value: self.value,
+            },
+        ))
+    }
+}
+
+struct VariantAccess<'a> {
+    variant: String,
+    value: Option<ValueRef<'a>>,
+}
+
+impl<'a> VariantAccess<'a> {
+    fn content(self, kind: &str) -> Result<(String, ValueRef<'a>), FromValueError> {
+        match self.value {
+            Some(v) => Ok((self.variant, v)),
+            None => Err(FromValueError::new(format!(
+                "expected {} variant, received unit variant",
+                kind
+            ))),
+        }
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for VariantAccess<'_> {
+    type Error = FromValueError;
+
+    fn unit_variant(self) -> Result<(), FromValueError> {
+        match self.value {
+            None => Ok(()),
+            Some(v) if v.is_null() => Ok(()),
+            Some(v) => Err(expected("unit variant", &v).at(self.variant)),
+        }
+    }
+
+    fn newtype_variant_seed<T: de::DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, FromValueError> {
+        let (variant, value) = self.content("newtype")?;
+        seed.deserialize(value).map_err(|err| err.at(variant))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, FromValueError> {
+        let (variant, value) = self.content("tuple")?;
+        de::Deserializer::deserialize_seq(value, visitor).map_err(|err| err.at(variant))
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, FromValueError> {
+        let (variant, value) = self.content("struct")?;
+        de::Deserializer::deserialize_map(value, visitor).map_err(|err| err.at(variant))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct User {
+        name: String,
+        age: u8,
+        score: f32,
+        tags: Vec<String>,
+        pair: (i64, bool),
+        nickname: Option<String>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Line(i32, i32),
+        Rect { w: u16, h: u16 },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Meters(f64);
+
+    #[test]
+    fn primitives() {
+        assert_eq!(from_value::<i8>(&valueof!(-1_i8)), Ok(-1));
+        assert_eq!(from_value::<u128>(&valueof!(u128::MAX)), Ok(u128::MAX));
+        assert_eq!(from_value::<i64>(&valueof!(5_u8)), Ok(5));
+        assert_eq!(from_value::<f32>(&valueof!(1.5_f32)), Ok(1.5));
+        assert_eq!(from_value::<char>(&valueof!("x")), Ok('x'));
+        assert_eq!(from_value::<()>(&valueof!(null)), Ok(()));
+        assert_eq!(from_value::<Option<i32>>(&valueof!(null)), Ok(None));
+        assert_eq!(from_value::<Option<i32>>(&valueof!(1_i32)), Ok(Some(1)));
+        assert!(from_value::<u8>(&valueof!(300_i32)).is_err());
+    }
+
+    #[test]
+    fn round_trip_struct() {
+        let user = User {
+            name: "alice".to_string(),
+            age: 30,
+            score: 9.5,
+            tags: vec!["a".to_string(), "b".to_string()],
+            pair: (-1, true),
+            nickname: Some("al".to_string()),
+        };
+
+        assert_eq!(from_value::<User>(&to_value(&user).unwrap()), Ok(user));
+    }
+
+    #[test]
+    fn round_trip_enum() {
+        for shape in [
+            Shape::Empty,
+            Shape::Circle(1.5),
+            Shape::Line(1, 2),
+            Shape::Rect { w: 2, h: 3 },
+        ] {
+            assert_eq!(from_value::<Shape>(&to_value(&shape).unwrap()), Ok(shape));
+        }
+    }
+
+    #[test]
+    fn newtype_struct() {
+        assert_eq!(from_value::<Meters>(&valueof!(2.0_f64)), Ok(Meters(2.0)));
+    }
+
+    #[test]
+    fn maps() {
+        let mut map = BTreeMap::new();
+        map.insert(1_u32, "one".to_string());
+        map.insert(2_u32, "two".to_string());
+
+        let v = to_value(&map).unwrap();
+        assert_eq!(from_value::<BTreeMap<u32, String>>(&v), Ok(map));
+
+        let v = valueof!({ "a": [1_i32, 2_i32] });
+        let map = from_value::<HashMap<String, Vec<i32>>>(&v).unwrap();
+        assert_eq!(map["a"], vec![1, 2]);
+    }
+
+    #[test]
+    fn error_path() {
+        let v = valueof!({ "users": [{ "name": "a" }, { "name": 1_i32 }] });
+
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct Named {
+            name: String,
+        }
+
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct Root {
+            users: Vec<Named>,
+        }
+
+        let err = from_value::<Root>(&v).unwrap_err();
+        assert_eq!(err.path.to_string(), "users/1/name");
+    }
+
+    #[test]
+    fn enum_mismatch() {
+        let err = from_value::<Shape>(&valueof!(1_i32)).unwrap_err();
+        assert_eq!(err.message, "expected Shape, received i32");
+
+        let err = from_value::<Shape>(&valueof!("Circle")).unwrap_err();
+        assert_eq!(
+            err.message,
+            "expected newtype variant, received unit variant"
+        );
+    }
+
+    #[test]
+    fn borrowed_view() {
+        let tags = vec!["a".to_string(), "b".to_string()];
+        assert_eq!(
+            Vec::<String>::deserialize(tags.as_value_ref()),
+            Ok(tags.clone())
+        );
+
+        let shapes = HashMap::from([(Ident::key("c"), valueof!({ "Circle": 1.5_f64 }))]);
+        assert_eq!(
+            HashMap::<String, Shape>::deserialize(shapes.as_value_ref()),
+            Ok(HashMap::from([("c".to_string(), Shape::Circle(1.5))]))
+        );
+
+        let owned = ValueRef::Owned(valueof!([1_i32, 2_i32]));
+        assert_eq!(<(u8, u8)>::deserialize(owned), Ok((1, 2)));
+    }
+}
@@ -0,0 +1,276 @@
+use std::fmt::{self, Write};
+
+use crate::{Ident, Object, ToValue, Value, ValueRef};
+
+/// How [`ValueDisplay`] writes strings and struct keys.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Quote {
+    /// `"json"` escapes, the default.
+    #[default]
+    Double,
+    /// The same escapes, with `'` as the delimiter.
+    Single,
+    /// Written as-is.
+    None,
+}
+
+/// JSON-like text for a value: compact with `{}`, indented by two spaces
+/// with `{:#}`. Strings, keys, bytes and times are quoted per [`Quote`].
+/// Unlike JSON, numbers print as their [`crate::Number`] does, so `NaN` and
+/// `inf` stay readable.
+#[derive(Clone)]
+pub struct ValueDisplay<'a> {
+    value: ValueRef<'a>,
+    quote: Quote,
+}
+
+impl<'a> ValueDisplay<'a> {
+    pub fn new(value: ValueRef<'a>) -> Self {
+        Self {
+            value,
+            quote: Quote::default(),
+        }
+    }
+
+    pub fn quote(mut self, quote: Quote) -> Self {
+        self.quote = quote;
+        self
+    }
+}
+
+impl Value {
+    /// A [`ValueDisplay`] of this value. Unlike `Value`'s own `Display`, a
+    /// top-level string is quoted and `null` prints as JSON does.
+    pub fn display(&self) -> ValueDisplay<'_> {
+        ValueDisplay::new(self.as_value_ref())
+    }
+}
+
+impl Object {
+    pub fn display(&self) -> ValueDisplay<'_> {
+        ValueDisplay::new(self.as_value_ref())
+    }
+}
+
+impl fmt::Display for ValueDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut printer = Printer {
+            quote: self.quote,
+            pretty: f.alternate(),
+            depth: 0,
+            f,
+        };
+
+        printer.value(&self.value)
+    }
+}
+
+struct Printer<'a, 'f> {
+    f: &'a mut fmt::Formatter<'f>,
+    quote: Quote,
+    pretty: bool,
+    depth: usize,
+}
+
+impl Printer<'_, '_> {
+    fn value(&mut self, value: &ValueRef<'_>) -> fmt::Result {
+        match value {
+            ValueRef::Null => self.f.write_str("null"),
+            ValueRef::Bool(v) => write!(self.f, "{}", v),
+            ValueRef::Number(v) => write!(self.f, "{}", v),
+            ValueRef::String(v) => self.string(v),
+            ValueRef::Bytes(v) => self.string(&crate::bytes::base64::encode(v)),
+            ValueRef::Time(v) => self.string(&v.to_string()),
+            ValueRef::Struct(v) => {
+                self.open('{', v.is_empty())?;
+
+                for (i, (ident, item)) in v.items().enumerate() {
+                    self.separator(i)?;
+
+                    match &ident {
+                        Ident::Key(k) => self.string(k)?,
+                        Ident::Index(n) => self.string(&n.to_string())?,
+                    }
+
+                    self.f.write_str(if self.pretty { ": " } else { ":" })?;
+                    self.value(&item.as_value_ref())?;
+                }
+
+                self.close('}', v.is_empty())
+            }
+            ValueRef::Array(v) => {
+                self.open('[', v.is_empty())?;
+
+                for (i, item) in v.items().enumerate() {
+                    self.separator(i)?;
+                    self.value(&item.as_value_ref())?;
+                }
+
+                self.close(']', v.is_empty())
+            }
+            ValueRef::Tuple(v) => {
+                self.open('[', v.is_empty())?;
+
+                for (i, item) in v.items().enumerate() {
+                    self.separator(i)?;
+                    self.value(&item.as_value_ref())?;
+                }
+
+                self.close(']', v.is_empty())
+            }
+            ValueRef::Owned(v) => self.value(&v.as_value_ref()),
+        }
+    }
+
+    fn open(&mut self, c: char, empty: bool) -> fmt::Result {
+        self.f.write_char(c)?;
+
+        if !empty {
+            self.depth += 1;
+        }
+
+        Ok(())
+    }
+
+    fn close(&mut self, c: char, empty: bool) -> fmt::Result {
+        if !empty {
+            self.depth -= 1;
+            self.newline()?;
+        }
+
+        self.f.write_char(c)
+    }
+
+    fn separator(&mut self, index: usize) -> fmt::Result {
+        if index > 0 {
+            self.f.write_char(',')?;
+        }
+
+        self.newline()
+    }
+
+    fn newline(&mut self) -> fmt::Result {
+        if !self.pretty {
+            return Ok(());
+        }
+
+        self.f.write_char('\n')?;
+
+        for _ in 0..self.depth {
+            self.f.write_str("  ")?;
+        }
+
+        Ok(())
+    }
+
+    fn string(&mut self, value: &str) -> fmt::Result {
+        let delim = match self.quote {
+            Quote::Double => '"',
+            Quote::Single => '\'',
+            Quote::None => return self.f.write_str(value),
+        };
+
+        let mut start = 0;
+        self.f.write_char(delim)?;
+
+        for (i, c) in value.char_indices() {
+            let escape = match c {
+                '\\' => "\\\\",
+                '\n' => "\\n",
+                '\r' => "\\r",
+                '\t' => "\\t",
+                '"' if delim == '"' => "\\\"",
+                '\'' if delim == '\'' => "\\'",
+                c if c.is_control() => "",
+                _ => continue,
+            };
+
+            self.f.write_str(&value[start..i])?;
+            start = i + c.len_utf8();
+
+            match escape.is_empty() {
+                true => write!(self.f, "\\u{:04x}", c as u32)?,
+                false => self.f.write_str(escape)?,
+            }
+        }
+
+        self.f.write_str(&value[start..])?;
+        self.f.write_char(delim)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn sample() -> Value {
+        valueof!({
+            "name": "bob \"b\"",
+            "tags": ["a", 1_i32, null],
+            "pair": (true, 2.5_f64),
+            "empty": [],
+        })
+    }
+
+    #[test]
+    fn compact() {
+        assert_eq!(
+            sample().to_string(),
+            r#"{"name":"bob \"b\"","tags":["a",1,null],"pair":[true,2.5],"empty":[]}"#
+        );
+        assert_eq!(valueof!({}).to_string(), "{}");
+    }
+
+    #[test]
+    fn pretty() {
+        assert_eq!(
+            format!("{:#}", sample()),
+            concat!(
+                "{\n",
+                "  \"name\": \"bob \\\"b\\\"\",\n",
+                "  \"tags\": [\n",
+                "    \"a\",\n",
+                "    1,\n",
+                "    null\n",
+                "  ],\n",
+                "  \"pair\": [\n",
+                "    true,\n",
+                "    2.5\n",
+                "  ],\n",
+                "  \"empty\": []\n",
+                "}",
+            )
+        );
+    }
+
+    #[test]
+    fn quote() {
+        let v = valueof!({ "k": ["it's", "\n"] });
+
+        assert_eq!(
+            v.display().quote(Quote::Single).to_string(),
+            r#"{'k':['it\'s','\n']}"#
+        );
+        assert_eq!(v.display().quote(Quote::None).to_string(), "{k:[it's,\n]}");
+    }
+
+    #[test]
+    fn scalars() {
+        assert_eq!(valueof!("x").to_string(), "x");
+        assert_eq!(valueof!("x").display().to_string(), r#""x""#);
+        assert_eq!(valueof!(null).to_string(), "<null>");
+        assert_eq!(valueof!(null).display().to_string(), "null");
+        assert_eq!(
+            Value::from(vec![(*b"hi").to_value()]).to_string(),
+            r#"["aGk="]"#
+        );
+        assert_eq!(valueof!(["\u{1}"]).to_string(), r#"["\u0001"]"#);
+    }
+
+    #[test]
+    fn object_name() {
+        let v = sample();
+        assert_eq!(v.as_object().name(), "OrderedMap");
+        assert_eq!(v.as_object().to_string(), v.to_string());
+    }
+}
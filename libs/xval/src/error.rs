@@ -0,0 +1,368 @@
+use crate::{Ident, Value};
+
+/// An error produced by the fallible `try_as_*` accessors, `checked_to_*`
+/// numeric conversions, checked arithmetic and in-place mutation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValueError {
+    /// The value is of a different kind than requested.
+    TypeMismatch {
+        expected: &'static str,
+        received: String,
+    },
+    /// The number does not fit in the target type.
+    Overflow { value: String, target: &'static str },
+    /// The number would be rounded or truncated by the conversion.
+    PrecisionLoss { value: String, target: &'static str },
+    /// The number is NaN and has no representation in the target type.
+    NaN { target: &'static str },
+    /// The divisor of a division or remainder is zero.
+    DivisionByZero,
+    /// The backing object does not support in-place mutation.
+    Immutable { name: String },
+    /// Nothing exists at the given path.
+    NotFound { path: xpath::Path },
+    /// The index is past the end of an array or tuple.
+    OutOfBounds { index: usize, len: usize },
+    /// The text is not in the format expected for the target type.
+    Parse { input: String, target: &'static str },
+    /// A patch `test` operation found a different value.
+    TestFailed { path: xpath::Path },
+    /// A patch tried to move a value into one of its own children.
+    InvalidMove {
+        from: xpath::Path,
+        path: xpath::Path,
+    },
+    /// A strict merge found different values at these paths.
+    Conflict { paths: Vec<xpath::Path> },
+}
+
+impl ValueError {
+    pub fn type_mismatch(expected: &'static str, received: &str) -> Self {
+        Self::TypeMismatch {
+            expected,
+            received: received.to_string(),
+        }
+    }
+
+    pub fn overflow(value: impl std::fmt::Display, target: &'static str) -> Self {
+        Self::Overflow {
+            value: value.to_string(),
+            target,
+        }
+    }
+
+    pub fn precision_loss(value: impl std::fmt::Display, target: &'static str) -> Self {
+        Self::PrecisionLoss {
+            value: value.to_string(),
+            target,
+        }
+    }
+
+    pub fn nan(target: &'static str) -> Self {
+        Self::NaN { target }
+    }
+
+    pub fn division_by_zero() -> Self {
+        Self::DivisionByZero
+    }
+
+    pub fn immutable(name: &str) -> Self {
+        Self::Immutable {
+            name: name.to_string(),
+        }
+    }
+
+    pub fn not_found(path: xpath::Path) -> Self {
+        Self::NotFound { path }
+    }
+
+    pub fn out_of_bounds(index: usize, len: usize) -> Self {
+        Self::OutOfBounds { index, len }
+    }
+
+    pub fn parse(target: &'static str, input: impl std::fmt::Display) -> Self {
+        Self::Parse {
+            input: input.to_string(),
+            target,
+        }
+    }
+
+    pub fn test_failed(path: xpath::Path) -> Self {
+        Self::TestFailed { path }
+    }
+
+    pub fn invalid_move(from: xpath::Path, path: xpath::Path) -> Self {
+        Self::InvalidMove { from, path }
+    }
+
+    pub fn conflict(paths: Vec<xpath::Path>) -> Self {
+        Self::Conflict { paths }
+    }
+}
+
+impl std::fmt::Display for ValueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TypeMismatch { expected, received } => {
+                write!(f, "expected {}, received {}", expected, received)
+            }
+            Self::Overflow { value, target } => {
+                write!(f, "{} is out of range for {}", value, target)
+            }
+            Self::PrecisionLoss { value, target } => {
+                write!(f, "{} cannot be represented exactly as {}", value, target)
+            }
+            Self::NaN { target } => write!(f, "NaN cannot be converted to {}", target),
+            Self::DivisionByZero => write!(f, "division by zero"),
+            Self::Immutable { name } => write!(f, "{} does not support mutation", name),
+            Self::NotFound { path } => write!(f, "no value at {}", path),
+            Self::OutOfBounds { index, len } => {
+                write!(f, "index {} is out of bounds for length {}", index, len)
+            }
+            Self::Parse { input, target } => write!(f, "{:?} is not a valid {}", input, target),
+            Self::TestFailed { path } => write!(f, "test failed at {}", path),
+            Self::InvalidMove { from, path } => {
+                write!(f, "cannot move {} into its child {}", from, path)
+            }
+            Self::Conflict { paths } => {
+                write!(f, "conflicting values at ")?;
+
+                for (i, path) in paths.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(f, "{}", path)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValueError {}
+
+impl xok::XError for ValueError {
+    fn name(&self) -> &'static str {
+        "ValueError"
+    }
+
+    fn module(&self) -> &'static str {
+        module_path!()
+    }
+
+    fn code(&self) -> xok::Code {
+        xok::Code::BadArgument
+    }
+}
+
+/// An error produced when a [`Value`] cannot be converted into a Rust type,
+/// carrying the [`xpath::Path`] of the node that failed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FromValueError {
+    pub path: xpath::Path,
+    pub message: String,
+}
+
+impl FromValueError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            path: xpath::Path::default(),
+            message: message.into(),
+        }
+    }
+
+    pub fn expected(expected: &str, received: &Value) -> Self {
+        Self::new(format!(
+            "expected {}, received {}",
+            expected,
+            received.type_name()
+        ))
+    }
+
+    pub fn missing(field: &str) -> Self {
+        Self::new(format!("missing field `{}`", field))
+    }
+
+    /// Prefix the error path with `ident`, used as the error bubbles up
+    /// out of a nested struct, array or tuple.
+    pub fn at(mut self, ident: impl Into<Ident>) -> Self {
+        let mut path = xpath::Path::default();
+        path.push(ident);
+
+        for ident in self.path.iter() {
+            path.push(ident.clone());
+        }
+
+        self.path = path;
+        self
+    }
+}
+
+impl std::fmt::Display for FromValueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            return write!(f, "{}", &self.message);
+        }
+
+        write!(f, "{} @ {}", &self.message, &self.path)
+    }
+}
+
+impl std::error::Error for FromValueError {}
+
+impl From<ValueError> for FromValueError {
+    fn from(value: ValueError) -> Self {
+        Self::new(value.to_string())
+    }
+}
+
+impl xok::XError for FromValueError {
+    fn name(&self) -> &'static str {
+        "FromValueError"
+    }
+
+    fn module(&self) -> &'static str {
+        module_path!()
+    }
+
+    fn code(&self) -> xok::Code {
+        xok::Code::BadArgument
+    }
+}
+
+/// An error produced when a `serde::Serialize` type cannot be converted
+/// into a [`Value`].
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToValueError {
+    pub message: String,
+}
+
+#[cfg(feature = "serde")]
+impl ToValueError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::fmt::Display for ToValueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", &self.message)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::error::Error for ToValueError {}
+
+#[cfg(feature = "serde")]
+impl serde::ser::Error for ToValueError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Self::new(msg.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl xok::XError for ToValueError {
+    fn name(&self) -> &'static str {
+        "ToValueError"
+    }
+
+    fn module(&self) -> &'static str {
+        module_path!()
+    }
+
+    fn code(&self) -> xok::Code {
+        xok::Code::BadArgument
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::de::Error for FromValueError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Self::new(msg.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_without_path() {
+        let err = FromValueError::new("bad value");
+        assert_eq!(err.to_string(), "bad value");
+    }
+
+    #[test]
+    fn display_with_path() {
+        let err = FromValueError::new("bad value")
+            .at("name")
+            .at(0)
+            .at("users");
+        assert_eq!(err.path.to_string(), "users/0/name");
+        assert_eq!(err.to_string(), "bad value @ users/0/name");
+    }
+
+    #[test]
+    fn expected() {
+        let err = FromValueError::expected("bool", &Value::from_i32(1));
+        assert_eq!(err.message, "expected bool, received i32");
+    }
+
+    #[test]
+    fn code() {
+        use xok::XError;
+
+        assert_eq!(FromValueError::new("x").code(), xok::Code::BadArgument);
+        assert_eq!(ValueError::nan("i32").code(), xok::Code::BadArgument);
+    }
+
+    #[test]
+    fn value_error_display() {
+        assert_eq!(
+            ValueError::type_mismatch("bool", "i32").to_string(),
+            "expected bool, received i32"
+        );
+        assert_eq!(
+            ValueError::overflow(300, "u8").to_string(),
+            "300 is out of range for u8"
+        );
+        assert_eq!(
+            ValueError::precision_loss(1.5, "i32").to_string(),
+            "1.5 cannot be represented exactly as i32"
+        );
+        assert_eq!(
+            ValueError::nan("i64").to_string(),
+            "NaN cannot be converted to i64"
+        );
+        assert_eq!(
+            ValueError::immutable("User").to_string(),
+            "User does not support mutation"
+        );
+        assert_eq!(
+            ValueError::not_found(xpath::Path::parse("a/0").unwrap()).to_string(),
+            "no value at a/0"
+        );
+        assert_eq!(
+            ValueError::out_of_bounds(3, 2).to_string(),
+            "index 3 is out of bounds for length 2"
+        );
+        assert_eq!(
+            ValueError::parse("date", "2024-13-01").to_string(),
+            "\"2024-13-01\" is not a valid date"
+        );
+        assert_eq!(
+            ValueError::invalid_move(xpath::Path::parse("a").unwrap(), "a/b".into()).to_string(),
+            "cannot move a into its child a/b"
+        );
+        assert_eq!(
+            ValueError::conflict(vec!["a".into(), "b/0".into()]).to_string(),
+            "conflicting values at a, b/0"
+        );
+    }
+}
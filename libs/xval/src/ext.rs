@@ -1,9 +1,18 @@
-use crate::{Ident, ToValue, object::Struct};
+use crate::{FromValue, FromValueError, Ident, ToValue, Value, object::Struct};
 
 pub trait StructExt: Struct {
     fn get(&self, key: &str) -> Option<&dyn ToValue> {
         self.field(Ident::from(key))
     }
+
+    /// Convert the field `key` into `T`. A missing field is read as
+    /// [`Value::Null`], so optional fields decode to `None`.
+    fn get_as<T: FromValue>(&self, key: &str) -> Result<T, FromValueError> {
+        match self.get(key) {
+            Some(v) => T::from_value(&v.to_value()).map_err(|err| err.at(key)),
+            None => T::from_value(&Value::Null).map_err(|_| FromValueError::missing(key)),
+        }
+    }
 }
 
 impl<T: Struct + ?Sized> StructExt for T {}
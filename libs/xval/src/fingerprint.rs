@@ -0,0 +1,325 @@
+use crate::num::CanonicalNumber;
+use crate::{Ident, Time, ToValue, Value, ValueRef};
+
+impl Value {
+    /// SHA-256 over a canonical binary encoding of this value, stable across
+    /// processes, platforms and crate versions, for use as a cache key.
+    ///
+    /// Values that are `==` always share a fingerprint, and values of different
+    /// types never do. The encoding is a one byte tag followed by the payload,
+    /// with every integer big-endian and every length a `u64`:
+    ///
+    /// | tag | kind | payload |
+    /// |-----|------|---------|
+    /// | `0` | null | |
+    /// | `1` | bool | `0` or `1` |
+    /// | `2` | number that fits an `i128` | `i128` |
+    /// | `3` | number that only fits a `u128` | `u128` |
+    /// | `4` | any other number | `f64` bits, NaN as `0x7ff8000000000000` |
+    /// | `5` | string | length, UTF-8 bytes |
+    /// | `6` | bytes | length, bytes |
+    /// | `7` | datetime | `i128` nanoseconds since the Unix epoch, offset ignored |
+    /// | `8` | date | `i64` days since the Unix epoch |
+    /// | `9` | duration | `i128` nanoseconds |
+    /// | `10` | struct | field count, then each key and value, keys sorted |
+    /// | `11` | array | length, items |
+    /// | `12` | tuple | length, items |
+    /// | `13` | decimal with no exact `f64` value | normalized `i128` coefficient, `u32` scale |
+    ///
+    /// Numbers are compared by value: integers, floats and decimals with no
+    /// fractional part are encoded as integers, so `1_u8`, `1_i64`, `1.0_f32`
+    /// and the decimal `1.00` match, and a decimal equal to a float is
+    /// encoded as that float.
+    /// Struct keys are encoded as strings (tag `5`) or, for index keys, as
+    /// numbers (tag `2`), and sorted with string keys first, by their bytes.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        self.as_value_ref().fingerprint()
+    }
+}
+
+impl ValueRef<'_> {
+    /// Like [`Value::fingerprint`], without converting into a [`Value`].
+    pub fn fingerprint(&self) -> [u8; 32] {
+        let mut hasher = sha256::Sha256::new();
+        encode(&mut hasher, self);
+        hasher.finish()
+    }
+}
+
+fn encode(out: &mut sha256::Sha256, value: &ValueRef<'_>) {
+    match value {
+        ValueRef::Null => out.update(&[0]),
+        ValueRef::Bool(v) => out.update(&[1, *v as u8]),
+        ValueRef::Number(v) => match v.canonical() {
+            CanonicalNumber::Int(v) => tagged(out, 2, &v.to_be_bytes()),
+            CanonicalNumber::UInt(v) => tagged(out, 3, &v.to_be_bytes()),
+            CanonicalNumber::Float(v) if v.is_nan() => {
+                tagged(out, 4, &f64::NAN.to_bits().to_be_bytes())
+            }
+            CanonicalNumber::Float(v) => tagged(out, 4, &v.to_bits().to_be_bytes()),
+            CanonicalNumber::Decimal(v) => {
+                tagged(out, 13, &v.coefficient().to_be_bytes());
+                out.update(&v.scale().to_be_bytes());
+            }
+        },
+        ValueRef::String(v) => bytes(out, 5, v.as_bytes()),
+        ValueRef::Bytes(v) => bytes(out, 6, v),
+        ValueRef::Time(Time::DateTime(v)) => tagged(out, 7, &v.unix_nanos().to_be_bytes()),
+        ValueRef::Time(Time::Date(v)) => tagged(out, 8, &v.unix_days().to_be_bytes()),
+        ValueRef::Time(Time::Duration(v)) => tagged(out, 9, &v.as_nanos().to_be_bytes()),
+        ValueRef::Struct(v) => {
+            let mut fields: Vec<_> = v.items().collect();
+            fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+            tagged(out, 10, &(fields.len() as u64).to_be_bytes());
+
+            for (ident, item) in fields {
+                match ident {
+                    Ident::Key(k) => bytes(out, 5, k.as_bytes()),
+                    Ident::Index(i) => tagged(out, 2, &(i as i128).to_be_bytes()),
+                }
+
+                encode(out, &item.as_value_ref());
+            }
+        }
+        ValueRef::Array(v) => {
+            tagged(out, 11, &(v.len() as u64).to_be_bytes());
+            v.items().for_each(|item| encode(out, &item.as_value_ref()));
+        }
+        ValueRef::Tuple(v) => {
+            tagged(out, 12, &(v.len() as u64).to_be_bytes());
+            v.items().for_each(|item| encode(out, &item.as_value_ref()));
+        }
+        ValueRef::Owned(v) => encode(out, &v.as_value_ref()),
+    }
+}
+
+fn tagged(out: &mut sha256::Sha256, tag: u8, payload: &[u8]) {
+    out.update(&[tag]);
+    out.update(payload);
+}
+
+fn bytes(out: &mut sha256::Sha256, tag: u8, payload: &[u8]) {
+    tagged(out, tag, &(payload.len() as u64).to_be_bytes());
+    out.update(payload);
+}
+
+/// SHA-256 (FIPS 180-4), kept here so the crate has no hashing dependency.
+mod sha256 {
+    const K: [u32; 64] = [
+        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4,
+        0xab1c5ed5, 0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe,
+        0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f,
+        0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7,
+        0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc,
+        0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b,
+        0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116,
+        0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+        0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+        0xc67178f2,
+    ];
+
+    pub struct Sha256 {
+        state: [u32; 8],
+        block: [u8; 64],
+        filled: usize,
+        len: u64,
+    }
+
+    impl Sha256 {
+        pub fn new() -> Self {
+            Self {
+                state: [
+                    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c,
+                    0x1f83d9ab, 0x5be0cd19,
+                ],
+                block: [0; 64],
+                filled: 0,
+                len: 0,
+            }
+        }
+
+        pub fn update(&mut self, mut data: &[u8]) {
+            self.len += data.len() as u64;
+
+            while !data.is_empty() {
+                let n = (64 - self.filled).min(data.len());
+                self.block[self.filled..self.filled + n].copy_from_slice(&data[..n]);
+                self.filled += n;
+                data = &data[n..];
+
+                if self.filled == 64 {
+                    self.compress();
+                    self.filled = 0;
+                }
+            }
+        }
+
+        pub fn finish(mut self) -> [u8; 32] {
+            let bits = self.len.wrapping_mul(8);
+            self.update(&[0x80]);
+
+            while self.filled != 56 {
+                self.update(&[0]);
+            }
+
+            self.update(&bits.to_be_bytes());
+
+            let mut out = [0; 32];
+
+            for (chunk, word) in out.chunks_exact_mut(4).zip(self.state) {
+                chunk.copy_from_slice(&word.to_be_bytes());
+            }
+
+            out
+        }
+
+        fn compress(&mut self) {
+            let mut w = [0u32; 64];
+
+            for (i, chunk) in self.block.chunks_exact(4).enumerate() {
+                w[i] = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+            }
+
+            for i in 16..64 {
+                let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+                let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+                w[i] = w[i - 16]
+                    .wrapping_add(s0)
+                    .wrapping_add(w[i - 7])
+                    .wrapping_add(s1);
+            }
+
+            let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = self.state;
+
+            for i in 0..64 {
+                let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+                let ch = (e & f) ^ (!e & g);
+                let t1 = h
+                    .wrapping_add(s1)
+                    .wrapping_add(ch)
+                    .wrapping_add(K[i])
+                    .wrapping_add(w[i]);
+                let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+                let maj = (a & b) ^ (a & c) ^ (b & c);
+                let t2 = s0.wrapping_add(maj);
+
+                h = g;
+                g = f;
+                f = e;
+                e = d.wrapping_add(t1);
+                d = c;
+                c = b;
+                b = a;
+                a = t1.wrapping_add(t2);
+            }
+
+            for (s, v) in self.state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+                *s = s.wrapping_add(v);
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::Sha256;
+
+        fn hex(input: &[u8]) -> String {
+            let mut hasher = Sha256::new();
+            hasher.update(input);
+            hasher.finish().iter().map(|b| format!("{b:02x}")).collect()
+        }
+
+        #[test]
+        fn vectors() {
+            assert_eq!(
+                hex(b""),
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+            );
+            assert_eq!(
+                hex(b"abc"),
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+            );
+            assert_eq!(
+                hex(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+                "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+            );
+            assert_eq!(
+                hex(&[b'a'; 1000]),
+                "41edece42d63e8d9bf515a9ba6932e1c20cbc9f5a5d134645adb5db1b9737ea3"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::*;
+
+    #[test]
+    fn numbers_by_value() {
+        let one = valueof!(1_i64).fingerprint();
+        assert_eq!(valueof!(1_u8).fingerprint(), one);
+        assert_eq!(valueof!(1.0_f32).fingerprint(), one);
+        assert_eq!(
+            valueof!(-0.0_f64).fingerprint(),
+            valueof!(0_i32).fingerprint()
+        );
+        assert_ne!(valueof!(1.5_f64).fingerprint(), one);
+        assert_eq!(
+            valueof!(f64::NAN).fingerprint(),
+            valueof!(f32::NAN).fingerprint()
+        );
+    }
+
+    #[test]
+    fn field_order() {
+        let a = valueof!({ "a": 1_i32, "b": [true, null] });
+        let b = Value::from_struct(HashMap::from([
+            (Ident::key("b"), valueof!([true, null])),
+            (Ident::key("a"), valueof!(1_u64)),
+        ]));
+
+        assert_eq!(a.fingerprint(), b.fingerprint());
+        assert_ne!(a.fingerprint(), valueof!({ "a": 1_i32 }).fingerprint());
+    }
+
+    #[test]
+    fn types_differ() {
+        let prints = [
+            valueof!(null).fingerprint(),
+            valueof!("1").fingerprint(),
+            valueof!(1_i32).fingerprint(),
+            (*b"1").to_value().fingerprint(),
+            valueof!([1_i32]).fingerprint(),
+            valueof!((1_i32,)).fingerprint(),
+            valueof!({ "0": 1_i32 }).fingerprint(),
+        ];
+
+        for (i, a) in prints.iter().enumerate() {
+            for b in &prints[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn stable() {
+        let v = valueof!({ "name": "alice", "tags": ["a", 1_i32], "ok": true });
+        let hex: String = v.fingerprint().iter().map(|b| format!("{b:02x}")).collect();
+        assert_eq!(
+            hex,
+            "e0591e05c0b3358c0377b4a65a31f4210511e25d1f32e938166560e8fbda8556"
+        );
+    }
+
+    #[test]
+    fn native() {
+        let map = HashMap::from([(Ident::key("n"), vec![1_i32, 2])]);
+        assert_eq!(
+            map.as_value_ref().fingerprint(),
+            map.to_value().fingerprint()
+        );
+    }
+}
@@ -0,0 +1,475 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{
+    Bool, Date, DateTime, Decimal, Duration, Float, FromValueError, Ident, Int, Number, Object,
+    Str, Time, ToValue, UInt, Value,
+};
+
+/// A trait for types that can be constructed from a [`Value`], the inverse of [`ToValue`].
+pub trait FromValue: Sized {
+    fn from_value(value: &Value) -> Result<Self, FromValueError>;
+}
+
+impl Value {
+    /// Convert this value into `T`, see [`FromValue`].
+    pub fn to<T: FromValue>(&self) -> Result<T, FromValueError> {
+        T::from_value(self)
+    }
+}
+
+impl FromValue for Value {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        Ok(value.clone())
+    }
+}
+
+impl FromValue for () {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::Null => Ok(()),
+            v => Err(FromValueError::expected("null", v)),
+        }
+    }
+}
+
+impl FromValue for Bool {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::Bool(v) => Ok(*v),
+            v => Err(FromValueError::expected("bool", v)),
+        }
+    }
+}
+
+impl FromValue for bool {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        Bool::from_value(value).map(|v| v.to_bool())
+    }
+}
+
+impl FromValue for Str {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::String(v) => Ok(v.clone()),
+            v => Err(FromValueError::expected("string", v)),
+        }
+    }
+}
+
+impl FromValue for String {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        Str::from_value(value).map(|v| v.as_str().to_string())
+    }
+}
+
+impl FromValue for char {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        let s = Str::from_value(value)?;
+        let mut chars = s.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(FromValueError::new(format!(
+                "expected char, received string of length {}",
+                s.len()
+            ))),
+        }
+    }
+}
+
+impl FromValue for Number {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::Number(v) => Ok(*v),
+            v => Err(FromValueError::expected("number", v)),
+        }
+    }
+}
+
+impl FromValue for Int {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::Number(Number::Int(v)) => Ok(*v),
+            v => Err(FromValueError::expected("int", v)),
+        }
+    }
+}
+
+impl FromValue for UInt {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::Number(Number::UInt(v)) => Ok(*v),
+            v => Err(FromValueError::expected("uint", v)),
+        }
+    }
+}
+
+impl FromValue for Float {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::Number(Number::Float(v)) => Ok(*v),
+            v => Err(FromValueError::expected("float", v)),
+        }
+    }
+}
+
+/// Any number converts, see [`Number::checked_to_decimal`].
+impl FromValue for Decimal {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::Number(v) => Ok(v.checked_to_decimal()?),
+            v => Err(FromValueError::expected("decimal", v)),
+        }
+    }
+}
+
+macro_rules! impl_from_value_int {
+    ($($T:ident => $checked:ident),+) => {
+        $(
+            impl FromValue for $T {
+                fn from_value(value: &Value) -> Result<Self, FromValueError> {
+                    let number = match value {
+                        Value::Number(v) => v,
+                        v => return Err(FromValueError::expected(stringify!($T), v)),
+                    };
+
+                    if number.is_float() {
+                        return Err(FromValueError::expected(stringify!($T), value));
+                    }
+
+                    Ok(number.$checked()?)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_value_int!(
+    i8 => checked_to_i8,
+    i16 => checked_to_i16,
+    i32 => checked_to_i32,
+    i64 => checked_to_i64,
+    i128 => checked_to_i128,
+    isize => checked_to_isize,
+    u8 => checked_to_u8,
+    u16 => checked_to_u16,
+    u32 => checked_to_u32,
+    u64 => checked_to_u64,
+    u128 => checked_to_u128,
+    usize => checked_to_usize
+);
+
+impl FromValue for f32 {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::Number(v) => Ok(v.checked_to_f32()?),
+            v => Err(FromValueError::expected("f32", v)),
+        }
+    }
+}
+
+impl FromValue for f64 {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::Number(v) => Ok(v.checked_to_f64()?),
+            v => Err(FromValueError::expected("f64", v)),
+        }
+    }
+}
+
+impl FromValue for Object {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::Object(v) => Ok(v.clone()),
+            v => Err(FromValueError::expected("object", v)),
+        }
+    }
+}
+
+/// Strings are parsed as well, so values read from text formats convert.
+impl FromValue for Time {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::Time(v) => Ok(*v),
+            Value::String(v) => Ok(Time::parse(v.as_str())?),
+            v => Err(FromValueError::expected("time", v)),
+        }
+    }
+}
+
+impl FromValue for DateTime {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::String(v) => Ok(DateTime::parse(v.as_str())?),
+            v => Ok(*Time::from_value(v)?.try_as_datetime()?),
+        }
+    }
+}
+
+impl FromValue for Date {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::String(v) => Ok(Date::parse(v.as_str())?),
+            v => Ok(*Time::from_value(v)?.try_as_date()?),
+        }
+    }
+}
+
+impl FromValue for Duration {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::String(v) => Ok(Duration::parse(v.as_str())?),
+            v => Ok(*Time::from_value(v)?.try_as_duration()?),
+        }
+    }
+}
+
+impl FromValue for std::time::SystemTime {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        Ok(DateTime::from_value(value)?.to_system_time()?)
+    }
+}
+
+impl FromValue for std::time::Duration {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        Ok(Duration::from_value(value)?.to_std()?)
+    }
+}
+
+impl<T: FromValue> FromValue for Option<T> {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::Null => Ok(None),
+            v => T::from_value(v).map(Some),
+        }
+    }
+}
+
+impl<T: FromValue> FromValue for Box<T> {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        T::from_value(value).map(Box::new)
+    }
+}
+
+impl<T: FromValue> FromValue for std::rc::Rc<T> {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        T::from_value(value).map(std::rc::Rc::new)
+    }
+}
+
+impl<T: FromValue> FromValue for std::sync::Arc<T> {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        T::from_value(value).map(std::sync::Arc::new)
+    }
+}
+
+impl<T: FromValue> FromValue for Vec<T> {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        let items: Vec<&dyn ToValue> = match value {
+            Value::Object(Object::Array(v)) => v.items().collect(),
+            Value::Object(Object::Tuple(v)) => v.items().collect(),
+            v => return Err(FromValueError::expected("array", v)),
+        };
+
+        items
+            .into_iter()
+            .enumerate()
+            .map(|(i, item)| T::from_value(&item.to_value()).map_err(|err| err.at(i)))
+            .collect()
+    }
+}
+
+fn struct_entries<K, T, C>(value: &Value, key: impl Fn(&Ident) -> K) -> Result<C, FromValueError>
+where
+    T: FromValue,
+    C: FromIterator<(K, T)>,
+{
+    let object = match value {
+        Value::Object(Object::Struct(v)) => v,
+        v => return Err(FromValueError::expected("struct", v)),
+    };
+
+    object
+        .items()
+        .map(|(ident, item)| {
+            T::from_value(&item.to_value())
+                .map(|v| (key(&ident), v))
+                .map_err(|err| err.at(ident))
+        })
+        .collect()
+}
+
+impl<T: FromValue> FromValue for HashMap<String, T> {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        struct_entries(value, |ident| ident.to_string())
+    }
+}
+
+impl<T: FromValue> FromValue for BTreeMap<String, T> {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        struct_entries(value, |ident| ident.to_string())
+    }
+}
+
+impl<T: FromValue> FromValue for HashMap<Ident, T> {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        struct_entries(value, Ident::clone)
+    }
+}
+
+impl<T: FromValue> FromValue for BTreeMap<Ident, T> {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        struct_entries(value, Ident::clone)
+    }
+}
+
+macro_rules! impl_tuple_from_value {
+    ($len:expr, $($idx:tt $T:ident),+) => {
+        impl<$($T: FromValue),+> FromValue for ($($T,)+) {
+            fn from_value(value: &Value) -> Result<Self, FromValueError> {
+                let items: Vec<&dyn ToValue> = match value {
+                    Value::Object(Object::Tuple(v)) => v.items().collect(),
+                    Value::Object(Object::Array(v)) => v.items().collect(),
+                    v => return Err(FromValueError::expected("tuple", v)),
+                };
+
+                if items.len() != $len {
+                    return Err(FromValueError::new(format!(
+                        "expected tuple of length {}, received length {}",
+                        $len,
+                        items.len()
+                    )));
+                }
+
+                Ok(($(
+                    $T::from_value(&items[$idx].to_value()).map_err(|err| err.at($idx))?,
+                )+))
+            }
+        }
+    };
+}
+
+impl_tuple_from_value!(1, 0 A);
+impl_tuple_from_value!(2, 0 A, 1 B);
+impl_tuple_from_value!(3, 0 A, 1 B, 2 C);
+impl_tuple_from_value!(4, 0 A, 1 B, 2 C, 3 D);
+impl_tuple_from_value!(5, 0 A, 1 B, 2 C, 3 D, 4 E);
+impl_tuple_from_value!(6, 0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+impl_tuple_from_value!(7, 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+impl_tuple_from_value!(8, 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+impl_tuple_from_value!(9, 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I);
+impl_tuple_from_value!(10, 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J);
+impl_tuple_from_value!(11, 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K);
+impl_tuple_from_value!(12, 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::valueof;
+
+    #[test]
+    fn primitives() {
+        assert!(bool::from_value(&valueof!(true)).unwrap());
+        assert_eq!(i32::from_value(&valueof!(42_i32)).unwrap(), 42);
+        assert_eq!(u8::from_value(&valueof!(42_i64)).unwrap(), 42);
+        assert_eq!(f64::from_value(&valueof!(1.5_f64)).unwrap(), 1.5);
+        assert_eq!(f64::from_value(&valueof!(2_i32)).unwrap(), 2.0);
+        assert_eq!(String::from_value(&valueof!("hi")).unwrap(), "hi");
+        assert_eq!(char::from_value(&valueof!("x")).unwrap(), 'x');
+        assert!(<()>::from_value(&valueof!(null)).is_ok());
+    }
+
+    #[test]
+    fn int_out_of_range() {
+        let err = u8::from_value(&valueof!(300_i32)).unwrap_err();
+        assert_eq!(err.message, "300 is out of range for u8");
+
+        let err = u32::from_value(&valueof!(-1_i32)).unwrap_err();
+        assert_eq!(err.message, "-1 is out of range for u32");
+    }
+
+    #[test]
+    fn int_from_float_errors() {
+        let err = i32::from_value(&valueof!(1.5_f64)).unwrap_err();
+        assert_eq!(err.message, "expected i32, received f64");
+    }
+
+    #[test]
+    fn float_out_of_range() {
+        assert_eq!(f32::from_value(&valueof!(1.5_f64)).unwrap(), 1.5);
+        assert!(f32::from_value(&valueof!(1e300_f64)).is_err());
+        assert!(f32::from_value(&valueof!(16_777_217_i32)).is_err());
+        assert!(f64::from_value(&valueof!(u64::MAX)).is_err());
+    }
+
+    #[test]
+    fn type_mismatch() {
+        let err = String::from_value(&valueof!(1_i32)).unwrap_err();
+        assert_eq!(err.message, "expected string, received i32");
+        assert!(err.path.is_empty());
+    }
+
+    #[test]
+    fn option() {
+        assert_eq!(Option::<i32>::from_value(&valueof!(null)).unwrap(), None);
+        assert_eq!(
+            Option::<i32>::from_value(&valueof!(1_i32)).unwrap(),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn vec() {
+        let v = Vec::<i32>::from_value(&valueof!([1_i32, 2_i32, 3_i32])).unwrap();
+        assert_eq!(v, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn vec_error_path() {
+        let err = Vec::<i32>::from_value(&valueof!([1_i32, "two"])).unwrap_err();
+        assert_eq!(err.path.to_string(), "1");
+    }
+
+    #[test]
+    fn maps() {
+        let value = valueof!({ "a": 1_i32, "b": 2_i32 });
+
+        let map = HashMap::<String, i32>::from_value(&value).unwrap();
+        assert_eq!(map.get("a"), Some(&1));
+        assert_eq!(map.get("b"), Some(&2));
+
+        let map = BTreeMap::<String, i32>::from_value(&value).unwrap();
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn nested_error_path() {
+        let value = valueof!({ "users": [{ "age": 1_i32 }, { "age": "x" }] });
+        let err = HashMap::<String, Vec<HashMap<String, i32>>>::from_value(&value).unwrap_err();
+        assert_eq!(err.path.to_string(), "users/1/age");
+        assert_eq!(
+            err.to_string(),
+            "expected i32, received string @ users/1/age"
+        );
+    }
+
+    #[test]
+    fn tuples() {
+        let value = (1_i32, "a", true).to_value();
+        let t = <(i32, String, bool)>::from_value(&value).unwrap();
+        assert_eq!(t, (1, "a".to_string(), true));
+    }
+
+    #[test]
+    fn tuple_length_mismatch() {
+        let value = (1_i32, true).to_value();
+        assert!(<(i32, bool, bool)>::from_value(&value).is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        let original = vec![(1_i32, String::from("a")), (2, String::from("b"))];
+        let restored = original.to_value().to::<Vec<(i32, String)>>().unwrap();
+        assert_eq!(original, restored);
+    }
+}
@@ -0,0 +1,81 @@
+/// An error produced while reading JSON, with the 1-based line and column
+/// where it occurred. Both are `0` for I/O errors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonError {
+    pub message: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl JsonError {
+    pub fn new(message: impl Into<String>, line: usize, column: usize) -> Self {
+        Self {
+            message: message.into(),
+            line,
+            column,
+        }
+    }
+
+    /// An error at byte offset `pos` of `input`.
+    pub(crate) fn at(message: impl Into<String>, input: &[u8], pos: usize) -> Self {
+        let before = &input[..pos.min(input.len())];
+        let line_start = before
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |i| i + 1);
+        let line = before.iter().filter(|&&b| b == b'\n').count() + 1;
+        let column = String::from_utf8_lossy(&before[line_start..])
+            .chars()
+            .count()
+            + 1;
+        Self::new(message, line, column)
+    }
+
+    pub(crate) fn io(err: std::io::Error) -> Self {
+        Self::new(err.to_string(), 0, 0)
+    }
+}
+
+impl std::fmt::Display for JsonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.line == 0 {
+            return write!(f, "{}", &self.message);
+        }
+
+        write!(
+            f,
+            "{} at line {} column {}",
+            &self.message, self.line, self.column
+        )
+    }
+}
+
+impl std::error::Error for JsonError {}
+
+impl xok::XError for JsonError {
+    fn name(&self) -> &'static str {
+        "JsonError"
+    }
+
+    fn module(&self) -> &'static str {
+        module_path!()
+    }
+
+    fn code(&self) -> xok::Code {
+        xok::Code::BadArgument
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position() {
+        let input = "{\n  \"é\": x\n}".as_bytes();
+        let pos = input.iter().position(|&b| b == b'x').unwrap();
+        let err = JsonError::at("expected value", input, pos);
+        assert_eq!((err.line, err.column), (2, 8));
+        assert_eq!(err.to_string(), "expected value at line 2 column 8");
+    }
+}
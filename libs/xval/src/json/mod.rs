@@ -0,0 +1,19 @@
+//! A native JSON reader and writer for [`Value`], independent of serde.
+//!
+//! Integers are read as `i64`, widening to `u64`, `i128` and `u128` when
+//! needed, and anything with a fraction or exponent is read as `f64`, so
+//! `1` and `1.0` stay distinct; the `*_decimal` readers read those as an
+//! exact [`Decimal`](crate::Decimal) instead. Objects keep their key order
+//! through [`OrderedMap`](crate::OrderedMap).
+//!
+//! Writing maps kinds JSON has no syntax for onto strings: bytes as base64
+//! and times as RFC 3339 / ISO 8601. Non-finite floats are written as
+//! `null`.
+
+mod error;
+mod read;
+mod write;
+
+pub use error::*;
+pub use read::*;
+pub use write::*;
@@ -0,0 +1,537 @@
+use std::borrow::Cow;
+
+use crate::{Decimal, Ident, OrderedMap, Value, json::JsonError};
+
+/// Nesting deeper than this is rejected rather than risking the stack.
+const MAX_DEPTH: usize = 128;
+
+/// Parse a single JSON document.
+pub fn from_str(input: &str) -> Result<Value, JsonError> {
+    parse(input, false)
+}
+
+/// Parse a single JSON document from UTF-8 bytes.
+pub fn from_slice(input: &[u8]) -> Result<Value, JsonError> {
+    from_slice_with(input, false)
+}
+
+/// Read `reader` to the end and parse it as a single JSON document.
+pub fn from_reader(reader: impl std::io::Read) -> Result<Value, JsonError> {
+    from_reader_with(reader, false)
+}
+
+/// Like [`from_str`], but numbers with a fraction or exponent are read
+/// straight from their text as a [`Decimal`](crate::Decimal), keeping
+/// every digit and the scale as written. Numbers a decimal can't hold
+/// exactly are an error rather than being rounded.
+pub fn from_str_decimal(input: &str) -> Result<Value, JsonError> {
+    parse(input, true)
+}
+
+/// [`from_slice`] with decimals, see [`from_str_decimal`].
+pub fn from_slice_decimal(input: &[u8]) -> Result<Value, JsonError> {
+    from_slice_with(input, true)
+}
+
+/// [`from_reader`] with decimals, see [`from_str_decimal`].
+pub fn from_reader_decimal(reader: impl std::io::Read) -> Result<Value, JsonError> {
+    from_reader_with(reader, true)
+}
+
+fn parse(input: &str, decimal: bool) -> Result<Value, JsonError> {
+    let mut parser = Parser {
+        input,
+        bytes: input.as_bytes(),
+        pos: 0,
+        depth: 0,
+        decimal,
+    };
+
+    let value = parser.value()?;
+    parser.skip_whitespace();
+
+    if parser.pos < parser.bytes.len() {
+        return Err(parser.error("trailing characters"));
+    }
+
+    Ok(value)
+}
+
+fn from_slice_with(input: &[u8], decimal: bool) -> Result<Value, JsonError> {
+    match std::str::from_utf8(input) {
+        Ok(v) => parse(v, decimal),
+        Err(err) => Err(JsonError::at("invalid utf-8", input, err.valid_up_to())),
+    }
+}
+
+fn from_reader_with(mut reader: impl std::io::Read, decimal: bool) -> Result<Value, JsonError> {
+    let mut buf = Vec::new();
+    reader.read_to_end(&mut buf).map_err(JsonError::io)?;
+    from_slice_with(&buf, decimal)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+    depth: usize,
+    decimal: bool,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, message: &str) -> JsonError {
+        JsonError::at(message, self.bytes, self.pos)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, c: u8) -> Result<(), JsonError> {
+        self.skip_whitespace();
+
+        if self.peek() != Some(c) {
+            return Err(self.error(&format!("expected `{}`", c as char)));
+        }
+
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn value(&mut self) -> Result<Value, JsonError> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            None => Err(self.error("unexpected end of input")),
+            Some(b'n') => self.literal("null", Value::Null),
+            Some(b't') => self.literal("true", Value::from_bool(true)),
+            Some(b'f') => self.literal("false", Value::from_bool(false)),
+            Some(b'"') => Ok(match self.string()? {
+                Cow::Borrowed(v) => Value::from_str(v),
+                Cow::Owned(v) => Value::from_string(v),
+            }),
+            Some(b'[') => self.nested(Self::array),
+            Some(b'{') => self.nested(Self::object),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(_) => Err(self.error("expected value")),
+        }
+    }
+
+    fn literal(&mut self, text: &str, value: Value) -> Result<Value, JsonError> {
+        if !self.bytes[self.pos..].starts_with(text.as_bytes()) {
+            return Err(self.error("expected value"));
+        }
+
+        self.pos += text.len();
+        Ok(value)
+    }
+
+    fn nested(&mut self, f: fn(&mut Self) -> Result<Value, JsonError>) -> Result<Value, JsonError> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error("recursion limit exceeded"));
+        }
+
+        self.depth += 1;
+        let value = f(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn array(&mut self) -> Result<Value, JsonError> {
+        self.pos += 1;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(items.into());
+        }
+
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(items.into());
+                }
+                _ => return Err(self.error("expected `,` or `]`")),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, JsonError> {
+        self.pos += 1;
+        let mut map = OrderedMap::new();
+        self.skip_whitespace();
+
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(map.into());
+        }
+
+        loop {
+            self.skip_whitespace();
+
+            if self.peek() != Some(b'"') {
+                return Err(self.error("expected object key"));
+            }
+
+            let key = Ident::key(&self.string()?);
+            self.expect(b':')?;
+            map.insert(key, self.value()?);
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(map.into());
+                }
+                _ => return Err(self.error("expected `,` or `}`")),
+            }
+        }
+    }
+
+    /// Borrows from the input unless the string has escapes.
+    fn string(&mut self) -> Result<Cow<'a, str>, JsonError> {
+        self.pos += 1;
+        let start = self.pos;
+
+        loop {
+            match self.peek() {
+                None => return Err(self.error("unterminated string")),
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(Cow::Borrowed(&self.input[start..self.pos - 1]));
+                }
+                Some(b'\\') => break,
+                Some(0..0x20) => return Err(self.error("control character in string")),
+                Some(_) => self.pos += 1,
+            }
+        }
+
+        let mut out = String::from(&self.input[start..self.pos]);
+
+        loop {
+            let run = self.pos;
+
+            while self
+                .peek()
+                .is_some_and(|b| b != b'"' && b != b'\\' && b >= 0x20)
+            {
+                self.pos += 1;
+            }
+
+            out.push_str(&self.input[run..self.pos]);
+
+            match self.peek() {
+                None => return Err(self.error("unterminated string")),
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(Cow::Owned(out));
+                }
+                Some(b'\\') => {
+                    self.pos += 1;
+                    self.escape(&mut out)?;
+                }
+                Some(_) => return Err(self.error("control character in string")),
+            }
+        }
+    }
+
+    fn escape(&mut self, out: &mut String) -> Result<(), JsonError> {
+        let c = match self.peek() {
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b'/') => '/',
+            Some(b'b') => '\u{8}',
+            Some(b'f') => '\u{c}',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'u') => {
+                self.pos += 1;
+                let high = self.hex4()?;
+
+                let code = match high {
+                    0xD800..=0xDBFF => {
+                        if !self.bytes[self.pos..].starts_with(b"\\u") {
+                            return Err(self.error("unpaired surrogate in string"));
+                        }
+
+                        self.pos += 2;
+                        let low = self.hex4()?;
+
+                        if !(0xDC00..=0xDFFF).contains(&low) {
+                            return Err(self.error("unpaired surrogate in string"));
+                        }
+
+                        0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                    }
+                    0xDC00..=0xDFFF => return Err(self.error("unpaired surrogate in string")),
+                    v => v,
+                };
+
+                out.push(char::from_u32(code).expect("valid scalar value"));
+                return Ok(());
+            }
+            _ => return Err(self.error("invalid escape")),
+        };
+
+        self.pos += 1;
+        out.push(c);
+        Ok(())
+    }
+
+    fn hex4(&mut self) -> Result<u32, JsonError> {
+        let digits = self
+            .input
+            .get(self.pos..self.pos + 4)
+            .filter(|v| v.bytes().all(|b| b.is_ascii_hexdigit()))
+            .ok_or_else(|| self.error("invalid unicode escape"))?;
+
+        self.pos += 4;
+        Ok(u32::from_str_radix(digits, 16).expect("hex digits"))
+    }
+
+    fn digits(&mut self) -> usize {
+        let start = self.pos;
+
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+
+        self.pos - start
+    }
+
+    fn number(&mut self) -> Result<Value, JsonError> {
+        let start = self.pos;
+        let mut float = false;
+
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+
+        match self.peek() {
+            Some(b'0') => self.pos += 1,
+            Some(b'1'..=b'9') => {
+                self.digits();
+            }
+            _ => return Err(self.error("invalid number")),
+        }
+
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            float = true;
+
+            if self.digits() == 0 {
+                return Err(self.error("invalid number"));
+            }
+        }
+
+        if let Some(b'e' | b'E') = self.peek() {
+            self.pos += 1;
+            float = true;
+
+            if let Some(b'+' | b'-') = self.peek() {
+                self.pos += 1;
+            }
+
+            if self.digits() == 0 {
+                return Err(self.error("invalid number"));
+            }
+        }
+
+        let text = &self.input[start..self.pos];
+
+        if float && self.decimal {
+            return match text.parse::<Decimal>() {
+                Ok(v) => Ok(Value::from(v)),
+                Err(_) => Err(JsonError::at("number out of range", self.bytes, start)),
+            };
+        }
+
+        if float {
+            return match text.parse::<f64>() {
+                Ok(v) if v.is_finite() => Ok(Value::from_f64(v)),
+                _ => Err(JsonError::at("number out of range", self.bytes, start)),
+            };
+        }
+
+        if let Ok(v) = text.parse::<i64>() {
+            return Ok(Value::from_i64(v));
+        }
+
+        if let Ok(v) = text.parse::<u64>() {
+            return Ok(Value::from_u64(v));
+        }
+
+        if let Ok(v) = text.parse::<i128>() {
+            return Ok(Value::from_i128(v));
+        }
+
+        match text.parse::<u128>() {
+            Ok(v) => Ok(Value::from_u128(v)),
+            Err(_) => Err(JsonError::at("number out of range", self.bytes, start)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn scalars() {
+        assert_eq!(json::from_str("null").unwrap(), Value::Null);
+        assert_eq!(json::from_str(" true ").unwrap(), valueof!(true));
+        assert_eq!(json::from_str("false").unwrap(), valueof!(false));
+        assert_eq!(json::from_str("\"hi\"").unwrap(), valueof!("hi"));
+    }
+
+    #[test]
+    fn numbers_keep_kind_and_range() {
+        let cases = [
+            ("0", valueof!(0_i64)),
+            ("-0", valueof!(0_i64)),
+            ("42", valueof!(42_i64)),
+            ("-9223372036854775808", valueof!((i64::MIN))),
+            ("18446744073709551615", valueof!((u64::MAX))),
+            (
+                "-18446744073709551616",
+                Value::from_i128(-(u64::MAX as i128) - 1),
+            ),
+            (
+                "340282366920938463463374607431768211455",
+                valueof!((u128::MAX)),
+            ),
+            ("1.0", valueof!(1.0_f64)),
+            ("1e2", valueof!(100.0_f64)),
+            ("-2.5E-3", valueof!(-0.0025_f64)),
+        ];
+
+        for (input, expected) in cases {
+            let v = json::from_str(input).unwrap();
+            assert!(v.strict_eq(&expected), "{input}: {v:?}");
+        }
+    }
+
+    #[test]
+    fn decimals() {
+        let v = json::from_str_decimal(r#"{"price": 12.30, "tiny": 1e-20, "qty": 3}"#).unwrap();
+        let field = |name: &str| v.as_struct().field(Ident::key(name)).unwrap().to_value();
+
+        assert_eq!(field("price").as_decimal().to_string(), "12.30");
+        assert_eq!(field("tiny").as_decimal().scale(), 20);
+        assert!(field("qty").is_int());
+        assert_eq!(
+            json::to_string(&v),
+            r#"{"price":12.30,"tiny":0.00000000000000000001,"qty":3}"#
+        );
+
+        let v = json::from_slice_decimal(b"0.1").unwrap();
+        assert_ne!(v, valueof!(0.1_f64));
+        assert!(json::from_str_decimal("1e-39").is_err());
+        assert!(json::from_str("1e-39").unwrap().is_float());
+    }
+
+    #[test]
+    fn invalid_numbers() {
+        for input in [
+            "01",
+            "1.",
+            ".5",
+            "-",
+            "1e",
+            "+1",
+            "1e999",
+            "340282366920938463463374607431768211456",
+        ] {
+            assert!(json::from_str(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn strings() {
+        let v = json::from_str(r#""a\"b\\c\/d\b\f\n\r\té😀""#).unwrap();
+        assert_eq!(v.as_str(), "a\"b\\c/d\u{8}\u{c}\n\r\té😀");
+
+        for input in [
+            r#""\ud83d""#,
+            r#""\ude00""#,
+            r#""\x""#,
+            r#""\u12""#,
+            "\"a\nb\"",
+            "\"abc",
+        ] {
+            assert!(json::from_str(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn objects_keep_order() {
+        let v = json::from_str(r#"{ "b": [1, {"c": null}], "a": {}, "0": [] }"#).unwrap();
+        assert_eq!(
+            v,
+            valueof!({ "b": [1_i64, { "c": null }], "a": {}, "0": [] })
+        );
+
+        let fields: Vec<_> = v.as_struct().items().map(|(k, _)| k).collect();
+        assert_eq!(fields, [Ident::key("b"), Ident::key("a"), Ident::key("0")]);
+    }
+
+    #[test]
+    fn errors_report_position() {
+        let err = json::from_str("{\n  \"a\": 1,\n  \"b\": tru\n}").unwrap_err();
+        assert_eq!(err.message, "expected value");
+        assert_eq!((err.line, err.column), (3, 8));
+
+        let cases = [
+            ("", "unexpected end of input", 1, 1),
+            ("[1 2]", "expected `,` or `]`", 1, 4),
+            ("{\"a\" 1}", "expected `:`", 1, 6),
+            ("{1: 2}", "expected object key", 1, 2),
+            ("[1] x", "trailing characters", 1, 5),
+        ];
+
+        for (input, message, line, column) in cases {
+            let err = json::from_str(input).unwrap_err();
+            assert_eq!(
+                (err.message.as_str(), err.line, err.column),
+                (message, line, column),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn depth_limit() {
+        let deep = "[".repeat(129) + &"]".repeat(129);
+        assert_eq!(
+            json::from_str(&deep).unwrap_err().message,
+            "recursion limit exceeded"
+        );
+
+        let ok = "[".repeat(128) + &"]".repeat(128);
+        assert!(json::from_str(&ok).is_ok());
+    }
+
+    #[test]
+    fn slice_and_reader() {
+        assert_eq!(json::from_slice(b"[1]").unwrap(), valueof!([1_i64]));
+        assert_eq!(json::from_slice(b"\"\xff\"").unwrap_err().column, 2);
+        assert_eq!(
+            json::from_reader(&b"{\"a\": true}"[..]).unwrap(),
+            valueof!({ "a": true })
+        );
+    }
+}
@@ -0,0 +1,425 @@
+use std::io::Write;
+
+use crate::num::CanonicalNumber;
+use crate::{Float, Ident, Number, StructIter, Time, ToValue, ValueRef, bytes};
+
+/// Write `value` as compact JSON.
+pub fn to_string(value: &(impl ToValue + ?Sized)) -> String {
+    let mut out = Vec::new();
+    Writer::new(&mut out)
+        .write(value)
+        .expect("writing to a Vec cannot fail");
+    String::from_utf8(out).expect("json output is utf-8")
+}
+
+/// Write `value` as JSON indented by two spaces.
+pub fn to_string_pretty(value: &(impl ToValue + ?Sized)) -> String {
+    let mut out = Vec::new();
+    Writer::pretty(&mut out)
+        .write(value)
+        .expect("writing to a Vec cannot fail");
+    String::from_utf8(out).expect("json output is utf-8")
+}
+
+/// Write `value` as canonical JSON, see [`Writer::canonical`].
+pub fn to_string_canonical(value: &(impl ToValue + ?Sized)) -> String {
+    let mut out = Vec::new();
+    Writer::canonical(&mut out)
+        .write(value)
+        .expect("writing to a Vec cannot fail");
+    String::from_utf8(out).expect("json output is utf-8")
+}
+
+pub fn to_writer(out: impl Write, value: &(impl ToValue + ?Sized)) -> std::io::Result<()> {
+    Writer::new(out).write(value)
+}
+
+pub fn to_writer_pretty(out: impl Write, value: &(impl ToValue + ?Sized)) -> std::io::Result<()> {
+    Writer::pretty(out).write(value)
+}
+
+pub fn to_writer_canonical(
+    out: impl Write,
+    value: &(impl ToValue + ?Sized),
+) -> std::io::Result<()> {
+    Writer::canonical(out).write(value)
+}
+
+/// Writes values as JSON to an [`std::io::Write`], either compact or
+/// pretty. Wrap `out` in a [`std::io::BufWriter`] when it is unbuffered.
+///
+/// Any [`ToValue`] is written through [`ToValue::as_value_ref`], so native
+/// types are serialized without first being converted.
+pub struct Writer<W: Write> {
+    out: W,
+    pretty: bool,
+    canonical: bool,
+    depth: usize,
+}
+
+impl<W: Write> Writer<W> {
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            pretty: false,
+            canonical: false,
+            depth: 0,
+        }
+    }
+
+    pub fn pretty(out: W) -> Self {
+        Self {
+            out,
+            pretty: true,
+            canonical: false,
+            depth: 0,
+        }
+    }
+
+    /// A compact writer whose output is the same for any two values that
+    /// are `==`:
+    ///
+    /// - struct keys are sorted by their UTF-8 bytes, index keys by their
+    ///   decimal form
+    /// - integers, and floats and decimals with no fractional part that fit
+    ///   an `i128` or `u128`, are written as integers, so `1_u8`,
+    ///   `1.0_f64` and the decimal `1.00` are all `1`
+    /// - other finite floats, and decimals with an exact `f64` value, are
+    ///   widened to `f64` and written as the shortest decimal that reads
+    ///   back as the same `f64`, without an exponent; non-finite floats are
+    ///   `null`
+    /// - any other decimal is written without trailing zeros
+    /// - datetimes are written in UTC
+    pub fn canonical(out: W) -> Self {
+        Self {
+            out,
+            pretty: false,
+            canonical: true,
+            depth: 0,
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    pub fn write(&mut self, value: &(impl ToValue + ?Sized)) -> std::io::Result<()> {
+        self.value(value.as_value_ref())
+    }
+
+    fn value(&mut self, value: ValueRef<'_>) -> std::io::Result<()> {
+        match value {
+            ValueRef::Null => self.out.write_all(b"null"),
+            ValueRef::Bool(true) => self.out.write_all(b"true"),
+            ValueRef::Bool(false) => self.out.write_all(b"false"),
+            ValueRef::Number(v) if self.canonical => match v.canonical() {
+                CanonicalNumber::Int(v) => write!(self.out, "{}", v),
+                CanonicalNumber::UInt(v) => write!(self.out, "{}", v),
+                CanonicalNumber::Float(v) if v.is_finite() => write!(self.out, "{}", v),
+                CanonicalNumber::Float(_) => self.out.write_all(b"null"),
+                CanonicalNumber::Decimal(v) => write!(self.out, "{}", v),
+            },
+            ValueRef::Number(v) => self.number(&v),
+            ValueRef::String(v) => self.string(v),
+            ValueRef::Bytes(v) => self.string(&bytes::base64::encode(v)),
+            ValueRef::Time(Time::DateTime(v)) if self.canonical => {
+                self.string(&v.to_utc().to_string())
+            }
+            ValueRef::Time(v) => self.string(&v.to_string()),
+            ValueRef::Struct(v) => {
+                self.open(b'{', v.is_empty())?;
+
+                let items = match self.canonical {
+                    true => StructIter::new(sorted_by_key(v.items()).into_iter()),
+                    false => v.items(),
+                };
+
+                for (i, (ident, item)) in items.enumerate() {
+                    self.separator(i)?;
+                    self.string(&ident.to_string())?;
+                    self.out.write_all(if self.pretty { b": " } else { b":" })?;
+                    self.value(item.as_value_ref())?;
+                }
+
+                self.close(b'}', v.is_empty())
+            }
+            ValueRef::Array(v) => {
+                self.open(b'[', v.is_empty())?;
+
+                for (i, item) in v.items().enumerate() {
+                    self.separator(i)?;
+                    self.value(item.as_value_ref())?;
+                }
+
+                self.close(b']', v.is_empty())
+            }
+            ValueRef::Tuple(v) => {
+                self.open(b'[', v.is_empty())?;
+
+                for (i, item) in v.items().enumerate() {
+                    self.separator(i)?;
+                    self.value(item.as_value_ref())?;
+                }
+
+                self.close(b']', v.is_empty())
+            }
+            ValueRef::Owned(v) => self.write(&v),
+        }
+    }
+
+    /// Start a top-level array whose items are written one at a time, so
+    /// large outputs never need to exist as a single [`Value`](crate::Value).
+    pub fn array(mut self) -> std::io::Result<ArrayWriter<W>> {
+        self.out.write_all(b"[")?;
+        self.depth += 1;
+        Ok(ArrayWriter {
+            writer: self,
+            len: 0,
+        })
+    }
+
+    fn open(&mut self, c: u8, empty: bool) -> std::io::Result<()> {
+        self.out.write_all(&[c])?;
+
+        if !empty {
+            self.depth += 1;
+        }
+
+        Ok(())
+    }
+
+    fn close(&mut self, c: u8, empty: bool) -> std::io::Result<()> {
+        if !empty {
+            self.depth -= 1;
+            self.newline()?;
+        }
+
+        self.out.write_all(&[c])
+    }
+
+    /// The `,` before every item but the first, then the item's indent.
+    fn separator(&mut self, index: usize) -> std::io::Result<()> {
+        if index > 0 {
+            self.out.write_all(b",")?;
+        }
+
+        self.newline()
+    }
+
+    fn newline(&mut self) -> std::io::Result<()> {
+        if !self.pretty {
+            return Ok(());
+        }
+
+        self.out.write_all(b"\n")?;
+
+        for _ in 0..self.depth {
+            self.out.write_all(b"  ")?;
+        }
+
+        Ok(())
+    }
+
+    fn number(&mut self, value: &Number) -> std::io::Result<()> {
+        match value {
+            Number::Int(v) => write!(self.out, "{}", v),
+            Number::UInt(v) => write!(self.out, "{}", v),
+            // `{:?}` keeps a `.0` or exponent, so floats read back as floats
+            Number::Float(Float::F32(v)) if v.is_finite() => write!(self.out, "{:?}", v),
+            Number::Float(Float::F64(v)) if v.is_finite() => write!(self.out, "{:?}", v),
+            Number::Float(_) => self.out.write_all(b"null"),
+            Number::Decimal(v) => write!(self.out, "{}", v),
+        }
+    }
+
+    fn string(&mut self, value: &str) -> std::io::Result<()> {
+        let bytes = value.as_bytes();
+        let mut start = 0;
+        self.out.write_all(b"\"")?;
+
+        for (i, &b) in bytes.iter().enumerate() {
+            let escape: &[u8] = match b {
+                b'"' => b"\\\"",
+                b'\\' => b"\\\\",
+                b'\n' => b"\\n",
+                b'\r' => b"\\r",
+                b'\t' => b"\\t",
+                0x08 => b"\\b",
+                0x0c => b"\\f",
+                0..0x20 => b"",
+                _ => continue,
+            };
+
+            self.out.write_all(&bytes[start..i])?;
+            start = i + 1;
+
+            match escape.is_empty() {
+                true => write!(self.out, "\\u{:04x}", b)?,
+                false => self.out.write_all(escape)?,
+            }
+        }
+
+        self.out.write_all(&bytes[start..])?;
+        self.out.write_all(b"\"")
+    }
+}
+
+fn sorted_by_key(items: StructIter<'_>) -> Vec<(Ident, &dyn ToValue)> {
+    let mut items: Vec<_> = items.map(|(k, v)| (k.to_string(), (k, v))).collect();
+    items.sort_by(|(a, _), (b, _)| a.cmp(b));
+    items.into_iter().map(|(_, item)| item).collect()
+}
+
+/// A top-level JSON array written one item at a time, from
+/// [`Writer::array`]. Call [`ArrayWriter::finish`] to close it.
+pub struct ArrayWriter<W: Write> {
+    writer: Writer<W>,
+    len: usize,
+}
+
+impl<W: Write> ArrayWriter<W> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push(&mut self, value: &(impl ToValue + ?Sized)) -> std::io::Result<()> {
+        self.writer.separator(self.len)?;
+        self.writer.write(value)?;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Close the array and flush, returning the underlying writer.
+    pub fn finish(mut self) -> std::io::Result<W> {
+        self.writer.close(b']', self.len == 0)?;
+        self.writer.out.flush()?;
+        Ok(self.writer.out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn compact() {
+        let v = valueof!({
+            "a": [1_i32, 2.5_f64, 1.0_f32, null],
+            "b": { "c": "x\"\n\u{1}" },
+            "e": [],
+            "f": {},
+            "t": (true, false),
+        });
+
+        assert_eq!(
+            json::to_string(&v),
+            r#"{"a":[1,2.5,1.0,null],"b":{"c":"x\"\n\u0001"},"e":[],"f":{},"t":[true,false]}"#
+        );
+    }
+
+    #[test]
+    fn pretty() {
+        let v = valueof!({ "a": [1_i32, {}], "b": { "c": true } });
+        assert_eq!(
+            json::to_string_pretty(&v),
+            "{\n  \"a\": [\n    1,\n    {}\n  ],\n  \"b\": {\n    \"c\": true\n  }\n}"
+        );
+    }
+
+    #[test]
+    fn special_kinds() {
+        let v = valueof!([
+            (f64::NAN),
+            (f64::INFINITY),
+            1e300_f64,
+            0.1_f32,
+            b"hi",
+            (u128::MAX)
+        ]);
+        assert_eq!(
+            json::to_string(&v),
+            r#"[null,null,1e300,0.1,"aGk=",340282366920938463463374607431768211455]"#
+        );
+
+        let date = Value::from(Date::from_ymd(2024, 5, 1).unwrap());
+        assert_eq!(json::to_string(&date), "\"2024-05-01\"");
+    }
+
+    #[test]
+    fn round_trip() {
+        let input = r#"{"b":[1,-2.5,"é😀\\",18446744073709551615,1e-7],"a":{"x":null,"y":false}}"#;
+        let v = json::from_str(input).unwrap();
+        assert_eq!(json::to_string(&v), input);
+        assert!(
+            json::from_str(&json::to_string_pretty(&v))
+                .unwrap()
+                .strict_eq(&v)
+        );
+    }
+
+    #[test]
+    fn stream_array() {
+        let mut array = json::Writer::new(Vec::new()).array().unwrap();
+
+        for i in 0..3_i32 {
+            array.push(&valueof!({ "i": (i) })).unwrap();
+        }
+
+        assert_eq!(array.len(), 3);
+        let out = array.finish().unwrap();
+        assert_eq!(out, br#"[{"i":0},{"i":1},{"i":2}]"#);
+
+        let mut array = json::Writer::pretty(Vec::new()).array().unwrap();
+        array.push(&valueof!([1_i32])).unwrap();
+        array.push(&valueof!(2_i32)).unwrap();
+        let out = String::from_utf8(array.finish().unwrap()).unwrap();
+        assert_eq!(out, "[\n  [\n    1\n  ],\n  2\n]");
+
+        let empty = json::Writer::pretty(Vec::new()).array().unwrap();
+        assert_eq!(empty.finish().unwrap(), b"[]");
+    }
+
+    #[test]
+    fn native() {
+        let mut map = std::collections::BTreeMap::new();
+        map.insert(Ident::key("b"), vec![(1_i32, "x")]);
+        map.insert(Ident::key("a"), vec![]);
+
+        assert_eq!(json::to_string(&map), r#"{"a":[],"b":[[1,"x"]]}"#);
+        assert_eq!(json::to_string(&map), json::to_string(&map.to_value()));
+    }
+
+    #[test]
+    fn canonical() {
+        let v = valueof!({
+            "b": [1.0_f64, 0.5_f32, 0.1_f32, 255_u8, (f64::NAN)],
+            "a": { "z": null, "y": "\u{e9}" },
+            "9": true,
+            "10": 1e-7_f64,
+        });
+
+        assert_eq!(
+            json::to_string_canonical(&v),
+            r#"{"10":0.0000001,"9":true,"a":{"y":"é","z":null},"b":[1,0.5,0.10000000149011612,255,null]}"#
+        );
+
+        let shuffled = Value::from_struct(std::collections::HashMap::from([
+            (Ident::key("y"), valueof!("\u{e9}")),
+            (Ident::key("z"), valueof!(null)),
+        ]));
+        assert_eq!(
+            json::to_string_canonical(&shuffled),
+            json::to_string_canonical(v.as_struct().field(Ident::key("a")).unwrap())
+        );
+
+        let time = DateTime::parse("2024-05-01T10:00:00+02:00").unwrap();
+        assert_eq!(
+            json::to_string_canonical(&time),
+            r#""2024-05-01T08:00:00Z""#
+        );
+    }
+}
@@ -1,14 +1,48 @@
+pub mod binary;
 mod bool;
+mod bytes;
+mod coerce;
+#[cfg(feature = "serde")]
+mod de;
+mod display;
+mod error;
 pub mod ext;
+mod fingerprint;
+mod from_value;
+pub mod json;
 mod macros;
+mod merge;
+mod mutate;
 pub mod num;
 mod object;
+mod patch;
+mod select;
+#[cfg(feature = "serde")]
+mod ser;
 mod string;
+mod time;
+mod value_ref;
+mod visit;
 
 pub use bool::*;
+pub use bytes::*;
+pub use coerce::*;
+#[cfg(feature = "serde")]
+pub use de::*;
+pub use display::*;
+pub use error::*;
+pub use from_value::*;
+pub use merge::*;
+pub use mutate::*;
 pub use num::*;
 pub use object::*;
+pub use patch::*;
+#[cfg(feature = "serde")]
+pub use ser::{ValueSerializer, to_value};
 pub use string::*;
+pub use time::*;
+pub use value_ref::*;
+pub use visit::*;
 pub use xpath::Ident;
 
 #[cfg(feature = "derive")]
@@ -19,21 +53,26 @@ pub mod derive {
 /// A trait for types that can produce a [`Value`] from a shared reference.
 pub trait ToValue {
     fn to_value(&self) -> Value;
+
+    /// Borrow as a [`ValueRef`] without converting. The default converts
+    /// through [`ToValue::to_value`]; types that hold their data directly
+    /// override it so reads don't allocate.
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Owned(self.to_value())
+    }
 }
 
 /// A dynamically-typed value that can hold a boolean or any numeric type.
 #[derive(Clone)]
 #[repr(u8)]
-#[cfg_attr(
-    feature = "serde",
-    derive(serde::Deserialize, serde::Serialize),
-    serde(untagged)
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
 pub enum Value {
     Null,
     Bool(Bool),
     Number(Number),
     String(Str),
+    Bytes(Bytes),
+    Time(Time),
     Object(Object),
 }
 
@@ -59,6 +98,14 @@ impl Value {
         matches!(self, Self::String(_))
     }
 
+    pub fn is_bytes(&self) -> bool {
+        matches!(self, Self::Bytes(_))
+    }
+
+    pub fn is_time(&self) -> bool {
+        matches!(self, Self::Time(_))
+    }
+
     pub fn is_object(&self) -> bool {
         matches!(self, Self::Object(_))
     }
@@ -90,6 +137,20 @@ impl Value {
         }
     }
 
+    pub fn as_bytes(&self) -> &Bytes {
+        match self {
+            Self::Bytes(v) => v,
+            v => panic!("expected Bytes, received {}", std::any::type_name_of_val(v)),
+        }
+    }
+
+    pub fn as_time(&self) -> &Time {
+        match self {
+            Self::Time(v) => v,
+            v => panic!("expected Time, received {}", std::any::type_name_of_val(v)),
+        }
+    }
+
     pub fn as_object(&self) -> &Object {
         match self {
             Self::Object(v) => v,
@@ -100,20 +161,84 @@ impl Value {
         }
     }
 
+    pub fn try_as_bool(&self) -> Result<&Bool, ValueError> {
+        match self {
+            Self::Bool(v) => Ok(v),
+            v => Err(ValueError::type_mismatch("bool", v.type_name())),
+        }
+    }
+
+    pub fn try_as_number(&self) -> Result<&Number, ValueError> {
+        match self {
+            Self::Number(v) => Ok(v),
+            v => Err(ValueError::type_mismatch("number", v.type_name())),
+        }
+    }
+
+    pub fn try_as_string(&self) -> Result<&Str, ValueError> {
+        match self {
+            Self::String(v) => Ok(v),
+            v => Err(ValueError::type_mismatch("string", v.type_name())),
+        }
+    }
+
+    pub fn try_as_bytes(&self) -> Result<&Bytes, ValueError> {
+        match self {
+            Self::Bytes(v) => Ok(v),
+            v => Err(ValueError::type_mismatch("bytes", v.type_name())),
+        }
+    }
+
+    pub fn try_as_time(&self) -> Result<&Time, ValueError> {
+        match self {
+            Self::Time(v) => Ok(v),
+            v => Err(ValueError::type_mismatch("time", v.type_name())),
+        }
+    }
+
+    pub fn try_as_object(&self) -> Result<&Object, ValueError> {
+        match self {
+            Self::Object(v) => Ok(v),
+            v => Err(ValueError::type_mismatch("object", v.type_name())),
+        }
+    }
+
     pub fn to_bool(&self) -> bool {
         self.as_bool().to_bool()
     }
 
+    pub fn try_to_bool(&self) -> Result<bool, ValueError> {
+        self.try_as_bool().map(|v| v.to_bool())
+    }
+
     pub fn as_str(&self) -> &str {
         self.as_string().as_str()
     }
 
+    pub fn try_as_str(&self) -> Result<&str, ValueError> {
+        self.try_as_string().map(|v| v.as_str())
+    }
+
+    pub fn type_name(&self) -> &str {
+        match self {
+            Self::Null => "null",
+            Self::Bool(_) => "bool",
+            Self::Number(v) => v.type_name(),
+            Self::String(_) => "string",
+            Self::Bytes(_) => "bytes",
+            Self::Time(v) => v.type_name(),
+            Self::Object(v) => v.name(),
+        }
+    }
+
     pub fn type_id(&self) -> std::any::TypeId {
         match self {
             Self::Null => panic!("attempt to call Value::type_id on Value::Null"),
             Self::Bool(v) => v.type_id(),
             Self::Number(v) => v.type_id(),
             Self::String(v) => v.type_id(),
+            Self::Bytes(v) => v.type_id(),
+            Self::Time(v) => v.type_id(),
             Self::Object(v) => v.type_id(),
         }
     }
@@ -132,17 +257,38 @@ impl Value {
         matches!(self, Self::Object(v) if v.is_tuple())
     }
 
-    pub fn as_struct(&self) -> &std::sync::Arc<dyn Struct> {
+    pub fn as_struct(&self) -> &std::sync::Arc<dyn Struct + Send + Sync> {
         self.as_object().as_struct()
     }
 
-    pub fn as_array(&self) -> &std::sync::Arc<dyn Array> {
+    pub fn as_array(&self) -> &std::sync::Arc<dyn Array + Send + Sync> {
         self.as_object().as_array()
     }
 
-    pub fn as_tuple(&self) -> &std::sync::Arc<dyn Tuple> {
+    pub fn as_tuple(&self) -> &std::sync::Arc<dyn Tuple + Send + Sync> {
         self.as_object().as_tuple()
     }
+
+    pub fn try_as_struct(&self) -> Result<&std::sync::Arc<dyn Struct + Send + Sync>, ValueError> {
+        match self {
+            Self::Object(v) => v.try_as_struct(),
+            v => Err(ValueError::type_mismatch("struct", v.type_name())),
+        }
+    }
+
+    pub fn try_as_array(&self) -> Result<&std::sync::Arc<dyn Array + Send + Sync>, ValueError> {
+        match self {
+            Self::Object(v) => v.try_as_array(),
+            v => Err(ValueError::type_mismatch("array", v.type_name())),
+        }
+    }
+
+    pub fn try_as_tuple(&self) -> Result<&std::sync::Arc<dyn Tuple + Send + Sync>, ValueError> {
+        match self {
+            Self::Object(v) => v.try_as_tuple(),
+            v => Err(ValueError::type_mismatch("tuple", v.type_name())),
+        }
+    }
 }
 
 impl Value {
@@ -169,6 +315,42 @@ impl Value {
     pub fn as_uint(&self) -> &UInt {
         self.as_number().as_uint()
     }
+
+    pub fn try_as_float(&self) -> Result<&Float, ValueError> {
+        match self {
+            Self::Number(v) => v.try_as_float(),
+            v => Err(ValueError::type_mismatch("float", v.type_name())),
+        }
+    }
+
+    pub fn try_as_int(&self) -> Result<&Int, ValueError> {
+        match self {
+            Self::Number(v) => v.try_as_int(),
+            v => Err(ValueError::type_mismatch("int", v.type_name())),
+        }
+    }
+
+    pub fn try_as_uint(&self) -> Result<&UInt, ValueError> {
+        match self {
+            Self::Number(v) => v.try_as_uint(),
+            v => Err(ValueError::type_mismatch("uint", v.type_name())),
+        }
+    }
+
+    pub fn is_decimal(&self) -> bool {
+        matches!(self, Self::Number(v) if v.is_decimal())
+    }
+
+    pub fn as_decimal(&self) -> &Decimal {
+        self.as_number().as_decimal()
+    }
+
+    pub fn try_as_decimal(&self) -> Result<&Decimal, ValueError> {
+        match self {
+            Self::Number(v) => v.try_as_decimal(),
+            v => Err(ValueError::type_mismatch("decimal", v.type_name())),
+        }
+    }
 }
 
 impl Value {
@@ -187,6 +369,14 @@ impl Value {
     pub fn to_f64(&self) -> f64 {
         self.as_number().to_f64()
     }
+
+    pub fn checked_to_f32(&self) -> Result<f32, ValueError> {
+        self.try_as_number()?.checked_to_f32()
+    }
+
+    pub fn checked_to_f64(&self) -> Result<f64, ValueError> {
+        self.try_as_number()?.checked_to_f64()
+    }
 }
 
 impl Value {
@@ -233,6 +423,30 @@ impl Value {
     pub fn to_isize(&self) -> isize {
         self.as_number().to_isize()
     }
+
+    pub fn checked_to_i8(&self) -> Result<i8, ValueError> {
+        self.try_as_number()?.checked_to_i8()
+    }
+
+    pub fn checked_to_i16(&self) -> Result<i16, ValueError> {
+        self.try_as_number()?.checked_to_i16()
+    }
+
+    pub fn checked_to_i32(&self) -> Result<i32, ValueError> {
+        self.try_as_number()?.checked_to_i32()
+    }
+
+    pub fn checked_to_i64(&self) -> Result<i64, ValueError> {
+        self.try_as_number()?.checked_to_i64()
+    }
+
+    pub fn checked_to_i128(&self) -> Result<i128, ValueError> {
+        self.try_as_number()?.checked_to_i128()
+    }
+
+    pub fn checked_to_isize(&self) -> Result<isize, ValueError> {
+        self.try_as_number()?.checked_to_isize()
+    }
 }
 
 impl Value {
@@ -279,12 +493,37 @@ impl Value {
     pub fn to_usize(&self) -> usize {
         self.as_number().to_usize()
     }
+
+    pub fn checked_to_u8(&self) -> Result<u8, ValueError> {
+        self.try_as_number()?.checked_to_u8()
+    }
+
+    pub fn checked_to_u16(&self) -> Result<u16, ValueError> {
+        self.try_as_number()?.checked_to_u16()
+    }
+
+    pub fn checked_to_u32(&self) -> Result<u32, ValueError> {
+        self.try_as_number()?.checked_to_u32()
+    }
+
+    pub fn checked_to_u64(&self) -> Result<u64, ValueError> {
+        self.try_as_number()?.checked_to_u64()
+    }
+
+    pub fn checked_to_u128(&self) -> Result<u128, ValueError> {
+        self.try_as_number()?.checked_to_u128()
+    }
+
+    pub fn checked_to_usize(&self) -> Result<usize, ValueError> {
+        self.try_as_number()?.checked_to_usize()
+    }
 }
 
 impl Value {
     pub fn len(&self) -> usize {
         match self {
             Self::String(v) => v.len(),
+            Self::Bytes(v) => v.len(),
             Self::Object(v) => v.len(),
             _ => 0,
         }
@@ -294,24 +533,26 @@ impl Value {
         self.len() == 0
     }
 
-    pub fn get(&self, path: &xpath::Path) -> Option<Self> {
-        let mut value = self.clone();
-
-        for ident in path.iter() {
-            value = match ident {
-                xpath::Ident::Key(_) if value.is_struct() => value
-                    .as_object()
-                    .as_struct()
-                    .field(ident.clone())?
-                    .to_value(),
-                xpath::Ident::Index(v) if value.is_array() => {
-                    value.as_object().as_array().index(*v)?.to_value()
-                }
-                _ => return None,
-            };
+    /// Like `==`, but numbers must also share kind and width and timestamps
+    /// their offset, at any depth: `1_i32` is `==` to `1_i64` and `1.0_f64`,
+    /// but not strictly.
+    pub fn strict_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a.strict_eq(b),
+            (Self::Time(a), Self::Time(b)) => a.strict_eq(b),
+            (Self::Object(a), Self::Object(b)) => a.strict_eq(b),
+            (a, b) => a == b,
         }
+    }
+
+    /// Clone out the value at `path`. Only the value found is converted;
+    /// use [`Value::get_ref`] to read it without converting at all.
+    pub fn get(&self, path: &xpath::Path) -> Option<Self> {
+        value_ref::lookup(self, path.iter().as_slice())
+    }
 
-        Some(value)
+    pub fn get_ref(&self, path: &xpath::Path) -> Option<ValueRef<'_>> {
+        self.as_value_ref().get(path)
     }
 }
 
@@ -328,6 +569,8 @@ impl std::fmt::Debug for Value {
             Self::Bool(v) => write!(f, "{:#?}", v),
             Self::Number(v) => write!(f, "{:#?}", v),
             Self::String(v) => write!(f, "{:#?}", v),
+            Self::Bytes(v) => write!(f, "{:#?}", v),
+            Self::Time(v) => write!(f, "{:#?}", v),
             Self::Object(v) => write!(f, "{:#?}", v),
         }
     }
@@ -340,13 +583,17 @@ impl std::fmt::Display for Value {
             Self::Bool(v) => write!(f, "{}", v),
             Self::Number(v) => write!(f, "{}", v),
             Self::String(v) => write!(f, "{}", v),
-            Self::Object(v) => write!(f, "{}", v),
+            Self::Bytes(v) => write!(f, "{}", v),
+            Self::Time(v) => write!(f, "{}", v),
+            Self::Object(v) => std::fmt::Display::fmt(v, f),
         }
     }
 }
 
 impl Eq for Value {}
 
+/// Numbers compare by mathematical value across kinds and widths (see
+/// [`Number`]); use [`Value::strict_eq`] to also require identical types.
 impl PartialEq for Value {
     fn eq(&self, other: &Self) -> bool {
         match self {
@@ -354,6 +601,8 @@ impl PartialEq for Value {
             Self::Bool(v) => other.is_bool() && v.eq(other.as_bool()),
             Self::Number(v) => other.is_number() && v.eq(other.as_number()),
             Self::String(v) => other.is_string() && v.eq(other.as_string()),
+            Self::Bytes(v) => other.is_bytes() && v.eq(other.as_bytes()),
+            Self::Time(v) => other.is_time() && v.eq(other.as_time()),
             Self::Object(v) => other.is_object() && v.eq(other.as_object()),
         }
     }
@@ -366,9 +615,12 @@ impl Ord for Value {
             (Self::Bool(a), Self::Bool(b)) => a.cmp(b),
             (Self::Number(a), Self::Number(b)) => a.cmp(b),
             (Self::String(a), Self::String(b)) => a.cmp(b),
-            (Self::Object(_), Self::Object(_)) => self.to_string().cmp(&other.to_string()),
+            (Self::Bytes(a), Self::Bytes(b)) => a.cmp(b),
+            (Self::Time(a), Self::Time(b)) => a.cmp(b),
+            (Self::Object(a), Self::Object(b)) => a.cmp(b),
             // Cross-type: use #[repr(u8)] discriminant ordering
-            // Null(0) < Bool(1) < Number(2) < String(3) < Object(4)
+            // Null(0) < Bool(1) < Number(2) < String(3) < Bytes(4) < Time(5)
+            // < Object(6)
             (l, r) => l.discriminant().cmp(&r.discriminant()),
         }
     }
@@ -380,10 +632,127 @@ impl PartialOrd for Value {
     }
 }
 
+impl std::hash::Hash for Value {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_value_ref().hash(state);
+    }
+}
+
+/// Hand-written rather than derived `untagged`, so byte buffers from binary
+/// formats stay [`Bytes`] instead of being read as strings. Integers take
+/// the narrowest signed width that fits, as the derived impl did.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Value {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct ValueVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("any value")
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_some<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        serde::Deserialize::deserialize(deserializer)
+    }
+
+    fn visit_newtype_struct<D: serde::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Value, D::Error> {
+        serde::Deserialize::deserialize(deserializer)
+    }
+
+    fn visit_bool<E: serde::de::Error>(self, v: bool) -> Result<Value, E> {
+        Ok(Value::from_bool(v))
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Value, E> {
+        self.visit_i128(v as i128)
+    }
+
+    fn visit_i128<E: serde::de::Error>(self, v: i128) -> Result<Value, E> {
+        Ok(Value::Number(Number::Int(
+            i8::try_from(v)
+                .map(Int::I8)
+                .or_else(|_| i16::try_from(v).map(Int::I16))
+                .or_else(|_| i32::try_from(v).map(Int::I32))
+                .or_else(|_| i64::try_from(v).map(Int::I64))
+                .unwrap_or(Int::I128(v)),
+        )))
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Value, E> {
+        self.visit_i128(v as i128)
+    }
+
+    fn visit_u128<E: serde::de::Error>(self, v: u128) -> Result<Value, E> {
+        match i128::try_from(v) {
+            Ok(v) => self.visit_i128(v),
+            Err(_) => Ok(Value::from_u128(v)),
+        }
+    }
+
+    fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<Value, E> {
+        Ok(Value::from_f64(v))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Value, E> {
+        Ok(Value::from_str(v))
+    }
+
+    fn visit_string<E: serde::de::Error>(self, v: String) -> Result<Value, E> {
+        Ok(Value::from_string(v))
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Value, E> {
+        Ok(Value::from_bytes(v))
+    }
+
+    fn visit_byte_buf<E: serde::de::Error>(self, v: Vec<u8>) -> Result<Value, E> {
+        Ok(Value::from_bytes(v))
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, seq: A) -> Result<Value, A::Error> {
+        serde::de::Visitor::visit_seq(ObjectVisitor, seq).map(Value::Object)
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, map: A) -> Result<Value, A::Error> {
+        serde::de::Visitor::visit_map(ObjectVisitor, map).map(Value::Object)
+    }
+}
+
 impl ToValue for Value {
     fn to_value(&self) -> Value {
         self.clone()
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        match self {
+            Self::Null => ValueRef::Null,
+            Self::Bool(v) => ValueRef::Bool(v.to_bool()),
+            Self::Number(v) => ValueRef::Number(*v),
+            Self::String(v) => ValueRef::String(v.as_str()),
+            Self::Bytes(v) => ValueRef::Bytes(v.as_slice()),
+            Self::Time(v) => ValueRef::Time(*v),
+            Self::Object(v) => v.as_value_ref(),
+        }
+    }
 }
 
 impl<T: ToValue> ToValue for Option<T> {
@@ -393,24 +762,43 @@ impl<T: ToValue> ToValue for Option<T> {
             None => Value::Null,
         }
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        match self {
+            Some(v) => v.as_value_ref(),
+            None => ValueRef::Null,
+        }
+    }
 }
 
 impl<T: ToValue> ToValue for Box<T> {
     fn to_value(&self) -> Value {
         self.as_ref().to_value()
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        self.as_ref().as_value_ref()
+    }
 }
 
 impl<T: ToValue> ToValue for std::rc::Rc<T> {
     fn to_value(&self) -> Value {
         self.as_ref().to_value()
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        self.as_ref().as_value_ref()
+    }
 }
 
 impl<T: ToValue> ToValue for std::sync::Arc<T> {
     fn to_value(&self) -> Value {
         self.as_ref().to_value()
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        self.as_ref().as_value_ref()
+    }
 }
 
 impl<T: ToValue> ToValue for std::cell::RefCell<T> {
@@ -500,6 +888,23 @@ mod tests {
         assert_ne!(valueof!(null), valueof!(""));
     }
 
+    #[test]
+    fn eq_numbers_by_value() {
+        assert_eq!(valueof!(1_i32), valueof!(1_i64));
+        assert_eq!(valueof!([1_u8, 2.0_f64]), valueof!([1_i64, 2_i32]));
+        assert_eq!(valueof!({ "a": 1_u8 }), valueof!({ "a": 1.0_f32 }));
+    }
+
+    #[test]
+    fn strict_eq() {
+        assert!(valueof!(1_i32).strict_eq(&valueof!(1_i32)));
+        assert!(!valueof!(1_i32).strict_eq(&valueof!(1_i64)));
+        assert!(valueof!({ "a": [1_u8] }).strict_eq(&valueof!({ "a": [1_u8] })));
+        assert!(!valueof!({ "a": [1_u8] }).strict_eq(&valueof!({ "a": [1_u16] })));
+        assert!(!valueof!([1_i32]).strict_eq(&Value::from_tuple((valueof!(1_i32),))));
+        assert!(valueof!("a").strict_eq(&valueof!("a")));
+    }
+
     #[test]
     fn clone_null() {
         let v = valueof!(null);
@@ -547,6 +952,47 @@ mod tests {
         assert_eq!(valueof!("hello").to_string(), "hello");
     }
 
+    #[test]
+    fn try_as() {
+        assert!(valueof!(true).try_as_bool().unwrap().to_bool());
+        assert_eq!(valueof!("hi").try_as_str(), Ok("hi"));
+        assert_eq!(valueof!(1_u8).try_as_uint().unwrap().to_u8(), 1);
+        assert!(valueof!([1_i32]).try_as_array().is_ok());
+        assert_eq!(
+            valueof!(1_i32).try_as_bool().unwrap_err(),
+            ValueError::type_mismatch("bool", "i32")
+        );
+        assert_eq!(
+            valueof!(null).try_as_struct().unwrap_err(),
+            ValueError::type_mismatch("struct", "null")
+        );
+        assert_eq!(
+            valueof!([1_i32]).try_as_tuple().unwrap_err(),
+            ValueError::type_mismatch("tuple", "Vec")
+        );
+        assert_eq!(
+            valueof!(1_i32).try_as_float().unwrap_err(),
+            ValueError::type_mismatch("float", "i32")
+        );
+    }
+
+    #[test]
+    fn checked_to() {
+        assert_eq!(valueof!(200_i64).checked_to_u8(), Ok(200));
+        assert_eq!(
+            valueof!(300_i64).checked_to_u8(),
+            Err(ValueError::overflow(300, "u8"))
+        );
+        assert_eq!(
+            valueof!(f64::NAN).checked_to_i32(),
+            Err(ValueError::nan("i32"))
+        );
+        assert_eq!(
+            valueof!("1").checked_to_i32(),
+            Err(ValueError::type_mismatch("number", "string"))
+        );
+    }
+
     #[test]
     fn type_id() {
         assert_eq!(valueof!(true).type_id(), std::any::TypeId::of::<bool>());
@@ -611,6 +1057,100 @@ mod tests {
             let _ = nan.cmp(&one);
             let _ = nan.cmp(&nan);
         }
+
+        #[test]
+        fn large_ints_against_floats() {
+            let big = valueof!(i64::MAX);
+            assert!(big < valueof!(9.3e18_f64));
+            assert!(valueof!((1_u64 << 53) + 1) > valueof!(9007199254740992.0_f64));
+            assert!(valueof!(0_i32) > valueof!(-0.5_f64));
+            assert!(valueof!(u128::MAX) < valueof!(f64::INFINITY));
+        }
+
+        #[test]
+        fn structs_compare_fields() {
+            assert!(valueof!({ "a": 1_i32 }) < valueof!({ "a": 2_i32 }));
+            assert!(valueof!({ "a": 1_i32 }) < valueof!({ "b": 1_i32 }));
+            assert!(valueof!({ "a": 1_i32 }) < valueof!({ "a": 1_i32, "b": 1_i32 }));
+            assert_eq!(
+                valueof!({ "a": 1_i32, "b": true }).cmp(&valueof!({ "b": true, "a": 1_i32 })),
+                std::cmp::Ordering::Equal
+            );
+        }
+
+        #[test]
+        fn arrays_lexicographic() {
+            assert!(valueof!([1_i32, 2_i32]) < valueof!([1_i32, 3_i32]));
+            assert!(valueof!([1_i32]) < valueof!([1_i32, 0_i32]));
+            assert!(valueof!([]) < valueof!([0_i32]));
+        }
+
+        #[test]
+        fn object_kinds() {
+            assert!(valueof!({ "a": 1_i32 }) < valueof!([]));
+            assert!(valueof!([1_i32]) < Value::from_tuple((valueof!(0_i32),)));
+        }
+
+        #[test]
+        fn btree_set() {
+            let set: std::collections::BTreeSet<Value> = [
+                valueof!({ "a": 1_i32 }),
+                valueof!({ "a": 2_i32 }),
+                valueof!({ "a": 1_i32 }),
+            ]
+            .into_iter()
+            .collect();
+
+            assert_eq!(set.len(), 2);
+            assert_eq!(set.first(), Some(&valueof!({ "a": 1_i32 })));
+        }
+    }
+
+    mod hash {
+        use std::collections::{BTreeMap, HashSet};
+        use std::hash::{BuildHasher, RandomState};
+
+        use super::*;
+
+        #[test]
+        fn equal_values_hash_equal() {
+            let state = RandomState::new();
+            let mut sorted = BTreeMap::new();
+            sorted.insert(Ident::key("b"), valueof!([1_i32, 2_i32]));
+            sorted.insert(Ident::key("a"), valueof!("x"));
+
+            let a = valueof!({ "a": "x", "b": [1_i32, 2_i32] });
+            let b = Value::from_struct(sorted);
+            assert_eq!(a, b);
+            assert_eq!(state.hash_one(&a), state.hash_one(&b));
+            assert_eq!(
+                state.hash_one(valueof!(1.0_f32)),
+                state.hash_one(valueof!(1.0_f64))
+            );
+        }
+
+        #[test]
+        fn dedup() {
+            let set: HashSet<Value> = [
+                valueof!({ "a": [1_i32] }),
+                valueof!({ "a": [1_i32] }),
+                valueof!({ "a": [2_i32] }),
+                valueof!([1_i32]),
+                Value::from_tuple((valueof!(1_i32),)),
+            ]
+            .into_iter()
+            .collect();
+
+            assert_eq!(set.len(), 4);
+        }
+
+        #[test]
+        fn map_key() {
+            let mut map = std::collections::HashMap::new();
+            map.insert(valueof!({ "id": 1_i32 }), "one");
+            assert_eq!(map.get(&valueof!({ "id": 1_i32 })), Some(&"one"));
+            assert_eq!(map.get(&valueof!({ "id": 2_i32 })), None);
+        }
     }
 
     mod get {
@@ -643,6 +1183,14 @@ mod tests {
             assert_eq!(v.get(&path).unwrap().as_str(), "world");
         }
 
+        #[test]
+        fn tuple_index() {
+            let v = valueof!((1_i32, "two"));
+
+            let path = xpath::Path::parse("1").unwrap();
+            assert_eq!(v.get(&path).unwrap().as_str(), "two");
+        }
+
         #[test]
         fn nested_struct_to_array() {
             let v = valueof!({ "items": [42_i32, 99_i32] });
@@ -665,6 +1213,27 @@ mod tests {
             assert_eq!(v.get(&path).unwrap().as_str(), "bob");
         }
 
+        #[test]
+        fn pointer_and_json_path() {
+            let v = valueof!({ "users": [{ "name": "alice" }], "a/b": { "404": true } });
+
+            for path in [
+                xpath::Path::parse("users/0/name").unwrap(),
+                xpath::Path::from_json_pointer("/users/0/name").unwrap(),
+                xpath::Path::from_json_path("$.users[0].name").unwrap(),
+            ] {
+                assert_eq!(v.get(&path).unwrap().as_str(), "alice");
+            }
+
+            for path in [
+                xpath::Path::parse("'a/b'/'404'").unwrap(),
+                xpath::Path::from_json_pointer("/a~1b/404").unwrap(),
+                xpath::Path::from_json_path("$['a/b']['404']").unwrap(),
+            ] {
+                assert_eq!(v.get(&path), Some(valueof!(true)), "{}", path);
+            }
+        }
+
         #[test]
         fn missing_key() {
             let v = valueof!({ "a": 1_i32 });
@@ -44,13 +44,11 @@ macro_rules! valueof {
     };
 
     ({}) => {
-        $crate::Value::from_struct(
-            std::collections::HashMap::<$crate::Ident, $crate::Value>::new(),
-        )
+        $crate::Value::from_struct($crate::OrderedMap::new())
     };
 
     ({ $( $key:literal : $value:tt ),+ $(,)? }) => {{
-        let mut map = std::collections::HashMap::<$crate::Ident, $crate::Value>::new();
+        let mut map = $crate::OrderedMap::new();
         $(
             map.insert($crate::Ident::key($key), $crate::valueof!($value));
         )+
@@ -71,6 +69,7 @@ macro_rules! valueof {
     ($value:tt as float)  => { $crate::Float::from($value) };
     ($value:tt as bool)   => { $crate::Bool::from($value) };
     ($value:tt as string) => { $crate::Str::from($value) };
+    ($value:tt as bytes)  => { $crate::Bytes::from($value) };
     ($value:tt as object) => { $crate::Object::from($value) };
 
     ($other:expr) => {
@@ -0,0 +1,317 @@
+use xpath::Path;
+
+use crate::{Ident, OrderedMap, Value, ValueError};
+
+/// How [`Value::merge`] combines two values.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MergeStrategy {
+    /// JSON Merge Patch (RFC 7386): structs merge recursively, a `null`
+    /// field deletes the key, and anything else replaces the target.
+    Patch,
+    /// Structs merge recursively and arrays combine per [`ArrayMerge`];
+    /// everywhere else the other value wins, `null` included.
+    Deep(ArrayMerge),
+    /// Like [`MergeStrategy::Deep`], but differing values at the same path
+    /// fail with [`ValueError::Conflict`] listing every such path.
+    Strict(ArrayMerge),
+}
+
+/// How [`MergeStrategy::Deep`] and [`MergeStrategy::Strict`] combine two
+/// arrays.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArrayMerge {
+    /// The other array replaces this one.
+    Replace,
+    /// The other array's items are appended to this one's.
+    Append,
+    /// Struct items sharing the same value for this field are merged, and
+    /// the rest of the other array's items are appended.
+    ByKey(Ident),
+}
+
+impl Value {
+    /// Merge `other` into a copy of `self`. Merged structs keep this value's
+    /// field order, followed by fields only `other` has.
+    pub fn merge(&self, other: &Self, strategy: MergeStrategy) -> Result<Self, ValueError> {
+        if strategy == MergeStrategy::Patch {
+            return Ok(merge_patch(self, other));
+        }
+
+        let mut merger = Merger {
+            strategy: &strategy,
+            path: Path::default(),
+            conflicts: vec![],
+        };
+
+        let value = merger.merge(self, other);
+
+        if !merger.conflicts.is_empty() {
+            return Err(ValueError::conflict(merger.conflicts));
+        }
+
+        Ok(value)
+    }
+}
+
+fn merge_patch(target: &Value, patch: &Value) -> Value {
+    if !patch.is_struct() {
+        return patch.clone();
+    }
+
+    let mut map: OrderedMap = match target.is_struct() {
+        true => target
+            .as_struct()
+            .items()
+            .map(|(k, v)| (k, v.to_value()))
+            .collect(),
+        false => OrderedMap::new(),
+    };
+
+    for (ident, value) in patch.as_struct().items() {
+        let value = value.to_value();
+
+        if value.is_null() {
+            map.remove(&ident);
+            continue;
+        }
+
+        let merged = merge_patch(map.get(&ident).unwrap_or(&Value::Null), &value);
+        map.insert(ident, merged);
+    }
+
+    map.into()
+}
+
+struct Merger<'a> {
+    strategy: &'a MergeStrategy,
+    path: Path,
+    conflicts: Vec<Path>,
+}
+
+impl Merger<'_> {
+    fn arrays(&self) -> &ArrayMerge {
+        match self.strategy {
+            MergeStrategy::Deep(v) | MergeStrategy::Strict(v) => v,
+            MergeStrategy::Patch => &ArrayMerge::Replace,
+        }
+    }
+
+    fn merge(&mut self, a: &Value, b: &Value) -> Value {
+        if a.is_struct() && b.is_struct() {
+            return self.merge_structs(a, b);
+        }
+
+        if a.is_array() && b.is_array() {
+            match self.arrays().clone() {
+                ArrayMerge::Append => {
+                    let items = a.as_array().items().chain(b.as_array().items());
+                    return items.map(|v| v.to_value()).collect::<Vec<_>>().into();
+                }
+                ArrayMerge::ByKey(key) => return self.merge_by_key(a, b, &key),
+                ArrayMerge::Replace => {}
+            }
+        }
+
+        if matches!(self.strategy, MergeStrategy::Strict(_)) && a != b {
+            self.conflicts.push(self.path.clone());
+        }
+
+        b.clone()
+    }
+
+    fn merge_structs(&mut self, a: &Value, b: &Value) -> Value {
+        let (a, b) = (a.as_struct(), b.as_struct());
+        let mut map: OrderedMap = a.items().map(|(k, v)| (k, v.to_value())).collect();
+
+        for (ident, value) in b.items() {
+            let value = value.to_value();
+            let merged = match map.get(&ident) {
+                Some(existing) => {
+                    self.path.push(ident.clone());
+                    let merged = self.merge(existing, &value);
+                    self.path.pop();
+                    merged
+                }
+                None => value,
+            };
+
+            map.insert(ident, merged);
+        }
+
+        map.into()
+    }
+
+    fn merge_by_key(&mut self, a: &Value, b: &Value, key: &Ident) -> Value {
+        let mut items: Vec<Value> = a.as_array().items().map(|v| v.to_value()).collect();
+        let key_of = |v: &Value| -> Option<Value> {
+            v.is_struct()
+                .then(|| v.as_struct().field(key.clone()))
+                .flatten()
+                .map(|v| v.to_value())
+        };
+
+        for item in b.as_array().items() {
+            let item = item.to_value();
+            let found = key_of(&item)
+                .and_then(|k| items.iter().position(|v| key_of(v).is_some_and(|v| v == k)));
+
+            match found {
+                Some(i) => {
+                    self.path.push(i);
+                    items[i] = self.merge(&items[i], &item);
+                    self.path.pop();
+                }
+                None => items.push(item),
+            }
+        }
+
+        items.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn path(src: &str) -> xpath::Path {
+        xpath::Path::parse(src).unwrap()
+    }
+
+    #[test]
+    fn merge_patch_rfc_7386() {
+        let target = valueof!({
+            "title": "Goodbye!",
+            "author": { "givenName": "John", "familyName": "Doe" },
+            "tags": ["example", "sample"],
+            "content": "This will be unchanged",
+        });
+        let patch = valueof!({
+            "title": "Hello!",
+            "phoneNumber": "+01-123-456-7890",
+            "author": { "familyName": null },
+            "tags": ["example"],
+        });
+        let expected = valueof!({
+            "title": "Hello!",
+            "author": { "givenName": "John" },
+            "tags": ["example"],
+            "content": "This will be unchanged",
+            "phoneNumber": "+01-123-456-7890",
+        });
+
+        let merged = target.merge(&patch, MergeStrategy::Patch).unwrap();
+        assert_eq!(merged, expected);
+    }
+
+    #[test]
+    fn merge_patch_replaces_non_structs() {
+        let cases = [
+            (
+                valueof!({ "a": "b" }),
+                valueof!({ "a": "c" }),
+                valueof!({ "a": "c" }),
+            ),
+            (
+                valueof!({ "a": "b" }),
+                valueof!({ "a": null }),
+                valueof!({}),
+            ),
+            (
+                valueof!({ "a": [1_i32] }),
+                valueof!({ "a": { "b": null, "c": 1_i32 } }),
+                valueof!({ "a": { "c": 1_i32 } }),
+            ),
+            (
+                valueof!(["a"]),
+                valueof!({ "a": "c" }),
+                valueof!({ "a": "c" }),
+            ),
+            (valueof!({ "a": "foo" }), valueof!(null), valueof!(null)),
+            (
+                valueof!({ "e": null }),
+                valueof!({ "a": 1_i32 }),
+                valueof!({ "e": null, "a": 1_i32 }),
+            ),
+        ];
+
+        for (target, patch, expected) in cases {
+            assert_eq!(
+                target.merge(&patch, MergeStrategy::Patch).unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn deep_layers_defaults() {
+        let defaults = valueof!({ "db": { "host": "localhost", "port": 5432_i32 }, "tags": ["a"] });
+        let user = valueof!({ "db": { "port": 6543_i32 }, "tags": ["b"], "debug": null });
+
+        let merged = defaults
+            .merge(&user, MergeStrategy::Deep(ArrayMerge::Replace))
+            .unwrap();
+        assert_eq!(
+            merged,
+            valueof!({ "db": { "host": "localhost", "port": 6543_i32 }, "tags": ["b"], "debug": null })
+        );
+
+        let merged = defaults
+            .merge(&user, MergeStrategy::Deep(ArrayMerge::Append))
+            .unwrap();
+        assert_eq!(merged.get(&path("tags")), Some(valueof!(["a", "b"])));
+    }
+
+    #[test]
+    fn deep_by_key() {
+        let a = valueof!({ "steps": [{ "id": 1_i32, "ok": false }, { "id": 2_i32, "ok": false }] });
+        let b = valueof!({ "steps": [{ "id": 2_i32, "ok": true }, { "id": 3_i32 }, "raw"] });
+
+        let merged = a
+            .merge(&b, MergeStrategy::Deep(ArrayMerge::ByKey(Ident::key("id"))))
+            .unwrap();
+        assert_eq!(
+            merged,
+            valueof!({ "steps": [
+                { "id": 1_i32, "ok": false },
+                { "id": 2_i32, "ok": true },
+                { "id": 3_i32 },
+                "raw",
+            ] })
+        );
+    }
+
+    #[test]
+    fn strict_reports_every_conflict() {
+        let a = valueof!({ "a": 1_i32, "b": { "c": "x", "d": true }, "e": [{ "id": 1_i32, "v": 1_i32 }] });
+        let b = valueof!({ "a": 1_u8, "b": { "c": "y", "f": 1_i32 }, "e": [{ "id": 1_i32, "v": 2_i32 }] });
+
+        let err = a
+            .merge(
+                &b,
+                MergeStrategy::Strict(ArrayMerge::ByKey(Ident::key("id"))),
+            )
+            .unwrap_err();
+        assert_eq!(err, ValueError::conflict(vec![path("b/c"), path("e/0/v")]));
+
+        let merged = a
+            .merge(
+                &valueof!({ "g": 1_i32 }),
+                MergeStrategy::Strict(ArrayMerge::Replace),
+            )
+            .unwrap();
+        assert_eq!(merged.get(&path("g")), Some(valueof!(1_i32)));
+    }
+
+    #[test]
+    fn keeps_field_order() {
+        let merged = valueof!({ "b": 1_i32, "a": 2_i32 })
+            .merge(&valueof!({ "c": 3_i32, "a": 4_i32 }), MergeStrategy::Patch)
+            .unwrap();
+        let fields: Vec<_> = merged
+            .as_struct()
+            .items()
+            .map(|(k, _)| k.to_string())
+            .collect();
+        assert_eq!(fields, ["b", "a", "c"]);
+    }
+}
@@ -0,0 +1,402 @@
+use xpath::Path;
+
+use crate::{Ident, Object, Value, ValueError};
+
+impl Value {
+    /// Mutable reference to the value at `path`. Shared backings along
+    /// the way are copied on write, so other clones are left untouched.
+    pub fn get_mut(&mut self, path: &Path) -> Result<&mut Self, ValueError> {
+        let mut value = self;
+
+        for (i, ident) in path.iter().enumerate() {
+            value = match value.child_mut(ident)? {
+                Some(v) => v,
+                None => return Err(ValueError::not_found(prefix(path, i + 1))),
+            };
+        }
+
+        Ok(value)
+    }
+
+    /// Write `value` at `path`, returning the value it replaced. Struct
+    /// fields are inserted when missing and arrays grow by one when the
+    /// index equals their length; every parent must already exist.
+    pub fn set(&mut self, path: &Path, value: Self) -> Result<Option<Self>, ValueError> {
+        let Some(ident) = path.last() else {
+            return Ok(Some(std::mem::replace(self, value)));
+        };
+
+        self.get_mut(&prefix(path, path.len() - 1))?
+            .set_child(ident, value)
+    }
+
+    /// Remove and return the value at `path`, or `None` when nothing is
+    /// there. Removing the root leaves [`Value::Null`] behind.
+    pub fn remove(&mut self, path: &Path) -> Result<Option<Self>, ValueError> {
+        let Some(ident) = path.last() else {
+            return Ok(Some(std::mem::take(self)));
+        };
+
+        self.get_mut(&prefix(path, path.len() - 1))?
+            .remove_child(ident)
+    }
+
+    /// The [`Entry`] at `path` for in-place get-or-insert.
+    pub fn entry(&mut self, path: &Path) -> Result<Entry<'_>, ValueError> {
+        let Some(ident) = path.last() else {
+            return Ok(Entry::Occupied(self));
+        };
+
+        let parent = self.get_mut(&prefix(path, path.len() - 1))?;
+
+        if parent.child_mut(ident)?.is_none() {
+            return Ok(Entry::Vacant(VacantEntry {
+                parent,
+                ident: ident.clone(),
+            }));
+        }
+
+        Ok(Entry::Occupied(parent.child_mut(ident)?.unwrap()))
+    }
+
+    /// Append `value` to an array.
+    pub fn push(&mut self, value: Self) -> Result<(), ValueError> {
+        self.object_mut("array")?.array_mut()?.push(value);
+        Ok(())
+    }
+
+    /// Insert `value` into an array at `index`, shifting later items right.
+    pub fn insert(&mut self, index: usize, value: Self) -> Result<(), ValueError> {
+        let len = self.try_as_array()?.len();
+
+        if index > len {
+            return Err(ValueError::out_of_bounds(index, len));
+        }
+
+        self.object_mut("array")?.array_mut()?.insert(index, value);
+        Ok(())
+    }
+
+    fn object_mut(&mut self, expected: &'static str) -> Result<&mut Object, ValueError> {
+        match self {
+            Self::Object(v) => Ok(v),
+            v => Err(ValueError::type_mismatch(expected, v.type_name())),
+        }
+    }
+
+    fn child_mut(&mut self, ident: &Ident) -> Result<Option<&mut Self>, ValueError> {
+        match ident {
+            Ident::Key(_) => Ok(self
+                .object_mut("struct")?
+                .struct_mut()?
+                .field_mut(ident.clone())),
+            Ident::Index(i) => {
+                let object = self.object_mut("array")?;
+
+                if object.is_tuple() {
+                    return Ok(object.tuple_mut()?.index_mut(*i));
+                }
+
+                Ok(object.array_mut()?.index_mut(*i))
+            }
+        }
+    }
+
+    fn set_child(&mut self, ident: &Ident, value: Self) -> Result<Option<Self>, ValueError> {
+        match ident {
+            Ident::Key(_) => Ok(self
+                .object_mut("struct")?
+                .struct_mut()?
+                .insert(ident.clone(), value)),
+            Ident::Index(i) => {
+                let object = self.object_mut("array")?;
+                let len = object.len();
+
+                if object.is_tuple() {
+                    let item = object.tuple_mut()?.index_mut(*i);
+                    let item = item.ok_or_else(|| ValueError::out_of_bounds(*i, len))?;
+                    return Ok(Some(std::mem::replace(item, value)));
+                }
+
+                let array = object.array_mut()?;
+
+                match array.index_mut(*i) {
+                    Some(item) => Ok(Some(std::mem::replace(item, value))),
+                    None if *i == len => {
+                        array.push(value);
+                        Ok(None)
+                    }
+                    None => Err(ValueError::out_of_bounds(*i, len)),
+                }
+            }
+        }
+    }
+
+    fn remove_child(&mut self, ident: &Ident) -> Result<Option<Self>, ValueError> {
+        match ident {
+            Ident::Key(_) => Ok(self
+                .object_mut("struct")?
+                .struct_mut()?
+                .remove(ident.clone())),
+            Ident::Index(i) => {
+                let object = self.object_mut("array")?;
+
+                if object.len() <= *i {
+                    return Ok(None);
+                }
+
+                Ok(Some(object.array_mut()?.remove(*i)))
+            }
+        }
+    }
+}
+
+/// A view into a single location of a [`Value`], which may or may not
+/// hold a value yet.
+pub enum Entry<'a> {
+    Occupied(&'a mut Value),
+    Vacant(VacantEntry<'a>),
+}
+
+impl<'a> Entry<'a> {
+    pub fn is_occupied(&self) -> bool {
+        matches!(self, Self::Occupied(_))
+    }
+
+    pub fn is_vacant(&self) -> bool {
+        matches!(self, Self::Vacant(_))
+    }
+
+    pub fn or_insert(self, default: Value) -> Result<&'a mut Value, ValueError> {
+        match self {
+            Self::Occupied(v) => Ok(v),
+            Self::Vacant(v) => v.insert(default),
+        }
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> Value>(
+        self,
+        default: F,
+    ) -> Result<&'a mut Value, ValueError> {
+        match self {
+            Self::Occupied(v) => Ok(v),
+            Self::Vacant(v) => v.insert(default()),
+        }
+    }
+
+    pub fn and_modify<F: FnOnce(&mut Value)>(self, f: F) -> Self {
+        match self {
+            Self::Occupied(v) => {
+                f(v);
+                Self::Occupied(v)
+            }
+            v => v,
+        }
+    }
+}
+
+/// A location in a struct or array that holds no value yet.
+pub struct VacantEntry<'a> {
+    parent: &'a mut Value,
+    ident: Ident,
+}
+
+impl<'a> VacantEntry<'a> {
+    pub fn ident(&self) -> &Ident {
+        &self.ident
+    }
+
+    pub fn insert(self, value: Value) -> Result<&'a mut Value, ValueError> {
+        self.parent.set_child(&self.ident, value)?;
+        Ok(self
+            .parent
+            .child_mut(&self.ident)?
+            .expect("entry exists after insert"))
+    }
+}
+
+fn prefix(path: &Path, len: usize) -> Path {
+    let mut prefix = Path::default();
+
+    for ident in path.iter().take(len) {
+        prefix.push(ident.clone());
+    }
+
+    prefix
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn path(src: &str) -> xpath::Path {
+        xpath::Path::parse(src).unwrap()
+    }
+
+    #[test]
+    fn set_struct_field() {
+        let mut v = valueof!({ "a": { "b": 1_i32 } });
+        assert_eq!(
+            v.set(&path("a/b"), valueof!(2_i32)),
+            Ok(Some(valueof!(1_i32)))
+        );
+        assert_eq!(v.set(&path("a/c"), valueof!(3_i32)), Ok(None));
+        assert_eq!(v.get(&path("a/b")).unwrap().to_i32(), 2);
+        assert_eq!(v.get(&path("a/c")).unwrap().to_i32(), 3);
+    }
+
+    #[test]
+    fn set_array_item() {
+        let mut v = valueof!({ "list": [1_i32, 2_i32] });
+        assert_eq!(
+            v.set(&path("list/0"), valueof!(5_i32)),
+            Ok(Some(valueof!(1_i32)))
+        );
+        assert_eq!(v.set(&path("list/2"), valueof!(6_i32)), Ok(None));
+        assert_eq!(
+            v.set(&path("list/9"), valueof!(7_i32)),
+            Err(ValueError::out_of_bounds(9, 3))
+        );
+        assert_eq!(v.get(&path("list")).unwrap().as_array().len(), 3);
+        assert_eq!(v.get(&path("list/0")).unwrap().to_i32(), 5);
+    }
+
+    #[test]
+    fn set_tuple_item() {
+        let mut v = valueof!((1_i32, "a"));
+        assert_eq!(v.set(&path("1"), valueof!("b")), Ok(Some(valueof!("a"))));
+        assert_eq!(
+            v.set(&path("2"), valueof!("c")),
+            Err(ValueError::out_of_bounds(2, 2))
+        );
+        assert_eq!(v.as_tuple().index(1).unwrap().to_value().as_str(), "b");
+    }
+
+    #[test]
+    fn set_root() {
+        let mut v = valueof!(1_i32);
+        assert_eq!(v.set(&path(""), valueof!(true)), Ok(Some(valueof!(1_i32))));
+        assert!(v.is_bool());
+    }
+
+    #[test]
+    fn set_missing_parent() {
+        let mut v = valueof!({ "a": {} });
+        assert_eq!(
+            v.set(&path("a/b/c"), valueof!(1_i32)),
+            Err(ValueError::not_found(path("a/b")))
+        );
+    }
+
+    #[test]
+    fn set_type_mismatch() {
+        let mut v = valueof!({ "a": 1_i32 });
+        assert_eq!(
+            v.set(&path("a/b"), valueof!(1_i32)),
+            Err(ValueError::type_mismatch("struct", "i32"))
+        );
+    }
+
+    #[test]
+    fn copy_on_write() {
+        let original = valueof!({ "a": [1_i32] });
+        let mut copy = original.clone();
+        copy.set(&path("a/0"), valueof!(2_i32)).unwrap();
+        copy.get_mut(&path("a"))
+            .unwrap()
+            .push(valueof!(3_i32))
+            .unwrap();
+
+        assert_eq!(original, valueof!({ "a": [1_i32] }));
+        assert_eq!(copy, valueof!({ "a": [2_i32, 3_i32] }));
+    }
+
+    #[test]
+    fn remove() {
+        let mut v = valueof!({ "a": 1_i32, "b": [1_i32, 2_i32] });
+        assert_eq!(v.remove(&path("a")), Ok(Some(valueof!(1_i32))));
+        assert_eq!(v.remove(&path("a")), Ok(None));
+        assert_eq!(v.remove(&path("b/0")), Ok(Some(valueof!(1_i32))));
+        assert_eq!(v.remove(&path("b/5")), Ok(None));
+        assert_eq!(v, valueof!({ "b": [2_i32] }));
+    }
+
+    #[test]
+    fn remove_root() {
+        let mut v = valueof!(1_i32);
+        assert_eq!(v.remove(&path("")), Ok(Some(valueof!(1_i32))));
+        assert!(v.is_null());
+    }
+
+    #[test]
+    fn entry() {
+        let mut v = valueof!({ "a": 1_i32 });
+
+        assert!(v.entry(&path("a")).unwrap().is_occupied());
+        assert!(v.entry(&path("b")).unwrap().is_vacant());
+
+        *v.entry(&path("b"))
+            .unwrap()
+            .or_insert(valueof!(2_i32))
+            .unwrap() = valueof!(3_i32);
+        v.entry(&path("a"))
+            .unwrap()
+            .and_modify(|v| *v = valueof!(10_i32))
+            .or_insert(valueof!(0_i32))
+            .unwrap();
+
+        assert_eq!(v, valueof!({ "a": 10_i32, "b": 3_i32 }));
+    }
+
+    #[test]
+    fn push_and_insert() {
+        let mut v = valueof!([1_i32]);
+        v.push(valueof!(3_i32)).unwrap();
+        v.insert(1, valueof!(2_i32)).unwrap();
+        assert_eq!(v, valueof!([1_i32, 2_i32, 3_i32]));
+        assert_eq!(
+            v.insert(5, valueof!(0_i32)),
+            Err(ValueError::out_of_bounds(5, 3))
+        );
+        assert_eq!(
+            valueof!(1_i32).push(valueof!(0_i32)),
+            Err(ValueError::type_mismatch("array", "i32"))
+        );
+    }
+
+    #[test]
+    fn immutable_backing() {
+        struct User;
+
+        impl Struct for User {
+            fn name(&self) -> &str {
+                "User"
+            }
+
+            fn type_id(&self) -> std::any::TypeId {
+                std::any::TypeId::of::<Self>()
+            }
+
+            fn len(&self) -> usize {
+                0
+            }
+
+            fn items(&self) -> StructIter<'_> {
+                StructIter::new(std::iter::empty())
+            }
+
+            fn field(&self, _ident: Ident) -> Option<&dyn ToValue> {
+                None
+            }
+        }
+
+        let mut v = Value::from_struct(User);
+        assert_eq!(
+            v.set(&path("a"), valueof!(1_i32)),
+            Err(ValueError::immutable("User"))
+        );
+
+        let _shared = v.clone();
+        assert_eq!(v.remove(&path("a")), Err(ValueError::immutable("User")));
+    }
+}
@@ -0,0 +1,835 @@
+use std::cmp::Ordering;
+
+use crate::{
+    ToValue, Value, ValueError, ValueRef,
+    num::{I128_BOUND, Number, checked_int, cmp_f64},
+};
+
+/// An exact base-10 number, `coefficient / 10^scale`.
+///
+/// The scale is kept as written, so `12.30` and `12.3` are equal but print
+/// differently. The coefficient is an [`i128`], which holds any 38 digit
+/// number, and the scale is at most [`Decimal::MAX_SCALE`].
+#[derive(Copy, Clone)]
+pub struct Decimal {
+    coefficient: i128,
+    scale: u32,
+}
+
+impl Decimal {
+    /// The most digits a decimal keeps after the point.
+    pub const MAX_SCALE: u32 = 38;
+
+    pub const ZERO: Self = Self {
+        coefficient: 0,
+        scale: 0,
+    };
+
+    /// `coefficient / 10^scale`, failing when `scale` is above
+    /// [`Decimal::MAX_SCALE`].
+    pub fn new(coefficient: i128, scale: u32) -> Result<Self, ValueError> {
+        if scale > Self::MAX_SCALE {
+            return Err(ValueError::overflow(
+                format!("{}e-{}", coefficient, scale),
+                "decimal",
+            ));
+        }
+
+        Ok(Self { coefficient, scale })
+    }
+
+    pub fn coefficient(&self) -> i128 {
+        self.coefficient
+    }
+
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefficient == 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.coefficient < 0
+    }
+
+    /// Whether the digits after the point are all zero.
+    pub fn is_integer(&self) -> bool {
+        self.coefficient % pow10(self.scale) == 0
+    }
+
+    /// The integer part, rounded toward zero.
+    pub fn trunc(&self) -> i128 {
+        self.coefficient / pow10(self.scale)
+    }
+
+    /// The same value with trailing zeros after the point removed.
+    pub fn normalize(self) -> Self {
+        let mut value = self;
+
+        while value.scale > 0 && value.coefficient % 10 == 0 {
+            value.coefficient /= 10;
+            value.scale -= 1;
+        }
+
+        value
+    }
+
+    /// The same value written with `scale` digits after the point, failing
+    /// when that would drop non-zero digits or overflow.
+    pub fn rescale(self, scale: u32) -> Result<Self, ValueError> {
+        if scale >= self.scale {
+            return self.widen(scale).ok_or_else(|| {
+                ValueError::overflow(format!("{} with scale {}", self, scale), "decimal")
+            });
+        }
+
+        let value = self.round(scale);
+
+        if value != self {
+            return Err(ValueError::precision_loss(self, "decimal"));
+        }
+
+        Ok(value)
+    }
+
+    /// Rounds to `scale` digits after the point, ties to even. Values that
+    /// already have no more digits than that are returned as-is.
+    pub fn round(self, scale: u32) -> Self {
+        if scale >= self.scale {
+            return self;
+        }
+
+        let divisor = pow10(self.scale - scale);
+        let quotient = self.coefficient / divisor;
+        let remainder = (self.coefficient % divisor).unsigned_abs();
+        let half = divisor.unsigned_abs() / 2;
+        let away = remainder > half || (remainder == half && quotient % 2 != 0);
+
+        Self {
+            coefficient: match (away, self.coefficient < 0) {
+                (false, _) => quotient,
+                (true, false) => quotient + 1,
+                (true, true) => quotient - 1,
+            },
+            scale,
+        }
+    }
+
+    /// Equal in coefficient and scale, unlike `==` which compares value only.
+    pub fn strict_eq(&self, other: &Self) -> bool {
+        self.coefficient == other.coefficient && self.scale == other.scale
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        "decimal"
+    }
+
+    pub fn type_id(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<Decimal>()
+    }
+
+    /// The coefficient at a `scale` no smaller than the current one, or
+    /// `None` when it overflows.
+    pub(crate) fn widen(self, scale: u32) -> Option<Self> {
+        if scale > Self::MAX_SCALE {
+            return None;
+        }
+
+        Some(Self {
+            coefficient: self
+                .coefficient
+                .checked_mul(10_i128.checked_pow(scale - self.scale)?)?,
+            scale,
+        })
+    }
+
+    /// The digits after the point as a signed integer of `scale` digits,
+    /// zero for integers.
+    fn fract_at(&self, scale: u32) -> i128 {
+        (self.coefficient % pow10(self.scale)) * pow10(scale - self.scale)
+    }
+}
+
+impl Decimal {
+    pub fn to_f32(&self) -> f32 {
+        self.to_string()
+            .parse()
+            .expect("decimal text is a valid float")
+    }
+
+    /// The nearest [`f64`]. Coefficients below 2^53 with a small scale are
+    /// divided directly, which is exact before the one rounding step;
+    /// anything else is parsed from its text.
+    pub fn to_f64(&self) -> f64 {
+        if self.coefficient.unsigned_abs() < 1 << 53 && self.scale <= 22 {
+            return self.coefficient as f64 / 10_f64.powi(self.scale as i32);
+        }
+
+        self.to_string()
+            .parse()
+            .expect("decimal text is a valid float")
+    }
+
+    pub fn to_i8(&self) -> i8 {
+        self.trunc() as i8
+    }
+
+    pub fn to_i16(&self) -> i16 {
+        self.trunc() as i16
+    }
+
+    pub fn to_i32(&self) -> i32 {
+        self.trunc() as i32
+    }
+
+    pub fn to_i64(&self) -> i64 {
+        self.trunc() as i64
+    }
+
+    pub fn to_i128(&self) -> i128 {
+        self.trunc()
+    }
+
+    pub fn to_isize(&self) -> isize {
+        self.trunc() as isize
+    }
+
+    pub fn to_u8(&self) -> u8 {
+        self.trunc() as u8
+    }
+
+    pub fn to_u16(&self) -> u16 {
+        self.trunc() as u16
+    }
+
+    pub fn to_u32(&self) -> u32 {
+        self.trunc() as u32
+    }
+
+    pub fn to_u64(&self) -> u64 {
+        self.trunc() as u64
+    }
+
+    pub fn to_u128(&self) -> u128 {
+        self.trunc() as u128
+    }
+
+    pub fn to_usize(&self) -> usize {
+        self.trunc() as usize
+    }
+}
+
+impl Decimal {
+    fn checked_integer<T: TryFrom<i128>>(&self, target: &'static str) -> Result<T, ValueError> {
+        if !self.is_integer() {
+            return Err(ValueError::precision_loss(self, target));
+        }
+
+        checked_int(self.trunc(), target)
+    }
+
+    pub fn checked_to_i8(&self) -> Result<i8, ValueError> {
+        self.checked_integer("i8")
+    }
+
+    pub fn checked_to_i16(&self) -> Result<i16, ValueError> {
+        self.checked_integer("i16")
+    }
+
+    pub fn checked_to_i32(&self) -> Result<i32, ValueError> {
+        self.checked_integer("i32")
+    }
+
+    pub fn checked_to_i64(&self) -> Result<i64, ValueError> {
+        self.checked_integer("i64")
+    }
+
+    pub fn checked_to_i128(&self) -> Result<i128, ValueError> {
+        self.checked_integer("i128")
+    }
+
+    pub fn checked_to_isize(&self) -> Result<isize, ValueError> {
+        self.checked_integer("isize")
+    }
+
+    pub fn checked_to_u8(&self) -> Result<u8, ValueError> {
+        self.checked_integer("u8")
+    }
+
+    pub fn checked_to_u16(&self) -> Result<u16, ValueError> {
+        self.checked_integer("u16")
+    }
+
+    pub fn checked_to_u32(&self) -> Result<u32, ValueError> {
+        self.checked_integer("u32")
+    }
+
+    pub fn checked_to_u64(&self) -> Result<u64, ValueError> {
+        self.checked_integer("u64")
+    }
+
+    pub fn checked_to_u128(&self) -> Result<u128, ValueError> {
+        self.checked_integer("u128")
+    }
+
+    pub fn checked_to_usize(&self) -> Result<usize, ValueError> {
+        self.checked_integer("usize")
+    }
+
+    /// Fails when the nearest [`f32`] is not exactly this value, as for
+    /// `0.1`.
+    pub fn checked_to_f32(&self) -> Result<f32, ValueError> {
+        let rounded = self.to_f32();
+
+        if cmp_decimal_f64(self, rounded as f64) != Ordering::Equal {
+            return Err(ValueError::precision_loss(self, "f32"));
+        }
+
+        Ok(rounded)
+    }
+
+    pub fn checked_to_f64(&self) -> Result<f64, ValueError> {
+        let rounded = self.to_f64();
+
+        if cmp_decimal_f64(self, rounded) != Ordering::Equal {
+            return Err(ValueError::precision_loss(self, "f64"));
+        }
+
+        Ok(rounded)
+    }
+}
+
+impl Number {
+    pub fn is_decimal(&self) -> bool {
+        matches!(self, Self::Decimal(_))
+    }
+
+    pub fn as_decimal(&self) -> &Decimal {
+        match self {
+            Self::Decimal(v) => v,
+            v => panic!(
+                "expected Decimal, received {}",
+                std::any::type_name_of_val(v)
+            ),
+        }
+    }
+
+    pub fn try_as_decimal(&self) -> Result<&Decimal, ValueError> {
+        match self {
+            Self::Decimal(v) => Ok(v),
+            v => Err(ValueError::type_mismatch("decimal", v.type_name())),
+        }
+    }
+
+    /// Integers convert exactly, failing only above [`i128::MAX`]. Floats
+    /// convert through their shortest round-trip text, so `0.1_f64` becomes
+    /// `0.1`, and fail when they are not finite or need more digits than a
+    /// decimal holds.
+    pub fn checked_to_decimal(&self) -> Result<Decimal, ValueError> {
+        match self {
+            Self::Int(v) => Ok(Decimal::from(v.to_i128())),
+            Self::UInt(v) => match i128::try_from(v.to_u128()) {
+                Ok(v) => Ok(Decimal::from(v)),
+                Err(_) => Err(ValueError::overflow(v, "decimal")),
+            },
+            Self::Float(v) if v.to_f64().is_nan() => Err(ValueError::nan("decimal")),
+            Self::Float(v) if v.to_f64().is_infinite() => Err(ValueError::overflow(v, "decimal")),
+            Self::Float(v) => v.to_string().parse(),
+            Self::Decimal(v) => Ok(*v),
+        }
+    }
+
+    pub fn from_decimal(value: Decimal) -> Self {
+        Self::Decimal(value)
+    }
+}
+
+impl Value {
+    pub fn from_decimal(value: Decimal) -> Self {
+        Self::Number(Number::from_decimal(value))
+    }
+}
+
+/// Parses `-12.30`, `1e-3` and `+4`: an optional sign, digits with an
+/// optional fraction, and an optional exponent. The scale is the number of
+/// digits after the point less the exponent, so `1.50e1` is `15.0`.
+/// Trailing zeros are dropped only when the text has more digits than a
+/// decimal holds.
+impl std::str::FromStr for Decimal {
+    type Err = ValueError;
+
+    fn from_str(text: &str) -> Result<Self, ValueError> {
+        let invalid = || ValueError::parse("decimal", text);
+        let (neg, rest) = match text.as_bytes().first() {
+            Some(b'-') => (true, &text[1..]),
+            Some(b'+') => (false, &text[1..]),
+            _ => (false, text),
+        };
+
+        let (mantissa, exp) = match rest.find(['e', 'E']) {
+            Some(i) => (
+                &rest[..i],
+                rest[i + 1..].parse::<i64>().map_err(|_| {
+                    match rest[i + 1..].trim_start_matches(['+', '-']) {
+                        v if !v.is_empty() && v.bytes().all(|b| b.is_ascii_digit()) => {
+                            ValueError::overflow(text, "decimal")
+                        }
+                        _ => invalid(),
+                    }
+                })?,
+            ),
+            None => (rest, 0),
+        };
+
+        let (int, fract) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let is_digits = |v: &str| v.bytes().all(|b| b.is_ascii_digit());
+
+        if int.is_empty() || !is_digits(int) || !is_digits(fract) || mantissa.ends_with('.') {
+            return Err(invalid());
+        }
+
+        let mut digits = format!("{}{}", int, fract);
+        let mut scale = (fract.len() as i64).saturating_sub(exp);
+        let start = digits.len() - digits.trim_start_matches('0').len();
+        digits.drain(..start);
+
+        if digits.is_empty() {
+            return Ok(Self {
+                coefficient: 0,
+                scale: scale.clamp(0, Self::MAX_SCALE as i64) as u32,
+            });
+        }
+
+        let fits = |digits: &str| {
+            let max = i128::MAX.unsigned_abs() + neg as u128;
+            digits.parse::<u128>().is_ok_and(|v| v <= max)
+        };
+
+        while (!fits(&digits) || scale > Self::MAX_SCALE as i64) && digits.ends_with('0') {
+            digits.pop();
+            scale -= 1;
+        }
+
+        if scale > Self::MAX_SCALE as i64 {
+            return Err(ValueError::precision_loss(text, "decimal"));
+        }
+
+        let overflow = || ValueError::overflow(text, "decimal");
+        let magnitude = digits.parse::<u128>().map_err(|_| overflow())?;
+        let mut coefficient = match neg {
+            true => 0_i128.checked_sub_unsigned(magnitude),
+            false => i128::try_from(magnitude).ok(),
+        }
+        .ok_or_else(overflow)?;
+
+        if scale < 0 {
+            coefficient = u32::try_from(-scale)
+                .ok()
+                .and_then(|exp| 10_i128.checked_pow(exp))
+                .and_then(|factor| coefficient.checked_mul(factor))
+                .ok_or_else(overflow)?;
+            scale = 0;
+        }
+
+        Ok(Self {
+            coefficient,
+            scale: scale as u32,
+        })
+    }
+}
+
+/// Decimals compare by value, so `12.30 == 12.3`.
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl std::hash::Hash for Decimal {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let value = self.normalize();
+        (value.coefficient, value.scale).hash(state);
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let scale = self.scale.max(other.scale);
+
+        self.trunc()
+            .cmp(&other.trunc())
+            .then_with(|| self.fract_at(scale).cmp(&other.fract_at(scale)))
+    }
+}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compare exactly with an integer.
+pub(crate) fn cmp_decimal_i128(a: &Decimal, b: i128) -> Ordering {
+    a.trunc().cmp(&b).then_with(|| a.fract_at(a.scale).cmp(&0))
+}
+
+pub(crate) fn cmp_decimal_u128(a: &Decimal, b: u128) -> Ordering {
+    match i128::try_from(b) {
+        Ok(b) => cmp_decimal_i128(a, b),
+        Err(_) => Ordering::Less,
+    }
+}
+
+/// Compare exactly with a float. Rounding is monotonic, so when `a` rounds
+/// to a different float than `b` that decides the order; otherwise the
+/// digits of both are compared, which only ever needs as many places as
+/// the float has fractional bits. NaN sorts above every decimal, as in
+/// [`cmp_f64`].
+pub(crate) fn cmp_decimal_f64(a: &Decimal, b: f64) -> Ordering {
+    if b.is_nan() {
+        return Ordering::Less;
+    }
+
+    let rounded = a.to_f64();
+
+    if rounded != b {
+        return cmp_f64(rounded, b);
+    }
+
+    // `a` is within the range of an i128, so `b` is finite and at least
+    // -2^127; only 2^127 itself, above every decimal, has no i128 integer part
+    let whole = b.trunc();
+
+    if whole >= I128_BOUND {
+        return Ordering::Less;
+    }
+
+    let ord = a.trunc().cmp(&(whole as i128));
+
+    if ord != Ordering::Equal {
+        return ord;
+    }
+
+    let (a_fract, b_fract) = (a.fract_at(a.scale), b - whole);
+    let b_sign = match b_fract {
+        v if v > 0.0 => 1,
+        v if v < 0.0 => -1,
+        _ => 0,
+    };
+    let sign = a_fract.signum().cmp(&b_sign);
+
+    if sign != Ordering::Equal || a_fract == 0 {
+        return sign;
+    }
+
+    let places = (fract_bits(b_fract) as usize).max(a.scale as usize);
+    let b_digits = format!("{:.*}", places, b_fract.abs());
+    let a_digits = format!(
+        "{:0>width$}",
+        a_fract.unsigned_abs(),
+        width = a.scale as usize
+    );
+    let ord = format!("{:0<places$}", a_digits)
+        .as_str()
+        .cmp(&b_digits[2..]);
+
+    match a_fract < 0 {
+        true => ord.reverse(),
+        false => ord,
+    }
+}
+
+/// The number of binary digits after the point in `value`, which is also
+/// how many decimal places it takes to write it exactly.
+fn fract_bits(value: f64) -> u32 {
+    let bits = value.to_bits();
+    let exp = ((bits >> 52) & 0x7ff) as i32;
+    let (mantissa, exp) = match exp {
+        0 => (bits & ((1 << 52) - 1), -1074),
+        e => ((bits & ((1 << 52) - 1)) | 1 << 52, e - 1075),
+    };
+
+    (-(exp + mantissa.trailing_zeros() as i32)).max(0) as u32
+}
+
+fn pow10(exp: u32) -> i128 {
+    10_i128.pow(exp)
+}
+
+macro_rules! impl_from_int {
+    ($($T:ty),+) => {
+        $(
+            impl From<$T> for Decimal {
+                fn from(value: $T) -> Self {
+                    Self {
+                        coefficient: value as i128,
+                        scale: 0,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl From<Decimal> for Number {
+    fn from(value: Decimal) -> Self {
+        Self::Decimal(value)
+    }
+}
+
+impl From<Decimal> for Value {
+    fn from(value: Decimal) -> Self {
+        Number::from(value).into()
+    }
+}
+
+impl std::fmt::Debug for Decimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+/// Plain digits with exactly `scale` of them after the point, never an
+/// exponent, so the text is also a valid JSON number.
+impl std::fmt::Display for Decimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let digits = self.coefficient.unsigned_abs().to_string();
+        let scale = self.scale as usize;
+        let digits = format!("{:0>width$}", digits, width = scale + 1);
+        let (int, fract) = digits.split_at(digits.len() - scale);
+
+        if self.coefficient < 0 {
+            f.write_str("-")?;
+        }
+
+        match fract.is_empty() {
+            true => f.write_str(int),
+            false => write!(f, "{}.{}", int, fract),
+        }
+    }
+}
+
+impl ToValue for Decimal {
+    fn to_value(&self) -> Value {
+        Value::Number(Number::Decimal(*self))
+    }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Number(Number::Decimal(*self))
+    }
+}
+
+/// Written as a string, so the scale and every digit survive formats that
+/// only have binary floats.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Decimal {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Read from a string, an integer, or a float through its shortest
+/// round-trip text.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Decimal {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(DecimalVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct DecimalVisitor;
+
+#[cfg(feature = "serde")]
+impl serde::de::Visitor<'_> for DecimalVisitor {
+    type Value = Decimal;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("a decimal number or numeric string")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Decimal, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Decimal, E> {
+        Ok(Decimal::from(v))
+    }
+
+    fn visit_i128<E: serde::de::Error>(self, v: i128) -> Result<Decimal, E> {
+        Ok(Decimal::from(v))
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Decimal, E> {
+        Ok(Decimal::from(v))
+    }
+
+    fn visit_u128<E: serde::de::Error>(self, v: u128) -> Result<Decimal, E> {
+        Number::from_u128(v).checked_to_decimal().map_err(E::custom)
+    }
+
+    fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<Decimal, E> {
+        Number::from_f64(v).checked_to_decimal().map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(text: &str) -> Decimal {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        for (input, output, scale) in [
+            ("12.30", "12.30", 2),
+            ("-0.05", "-0.05", 2),
+            ("+4", "4", 0),
+            ("1.50e1", "15.0", 1),
+            ("1e3", "1000", 0),
+            ("25E-4", "0.0025", 4),
+            ("-0", "0", 0),
+            ("0.000", "0.000", 3),
+            (
+                "170141183460469231731687303715884105727",
+                "170141183460469231731687303715884105727",
+                0,
+            ),
+            (
+                "0.12345678901234567890123456789012345678",
+                "0.12345678901234567890123456789012345678",
+                38,
+            ),
+        ] {
+            let v = dec(input);
+            assert_eq!(v.to_string(), output, "{input}");
+            assert_eq!(v.scale(), scale, "{input}");
+        }
+
+        assert_eq!(
+            dec("1.000000000000000000000000000000000000000000").scale(),
+            38
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        for input in ["", "-", "1.", ".5", "1e", "1x", "1.2.3", "e5", "--1"] {
+            assert!(
+                matches!(input.parse::<Decimal>(), Err(ValueError::Parse { .. })),
+                "{input}"
+            );
+        }
+
+        assert!(matches!(
+            "170141183460469231731687303715884105728".parse::<Decimal>(),
+            Err(ValueError::Overflow { .. })
+        ));
+        assert!(matches!(
+            "1e39".parse::<Decimal>(),
+            Err(ValueError::Overflow { .. })
+        ));
+        assert!(matches!(
+            "1e-39".parse::<Decimal>(),
+            Err(ValueError::PrecisionLoss { .. })
+        ));
+    }
+
+    #[test]
+    fn eq_and_ord() {
+        assert_eq!(dec("12.30"), dec("12.3"));
+        assert!(!dec("12.30").strict_eq(&dec("12.3")));
+        assert!(dec("-1.5") < dec("-1.2"));
+        assert!(dec("-0.5") < dec("0.2"));
+        assert!(dec("0.10000000000000000000000000000000000001") > dec("0.1"));
+    }
+
+    #[test]
+    fn eq_across_kinds() {
+        assert_eq!(Number::from(dec("2.00")), Number::from_i32(2));
+        assert_eq!(Number::from(dec("0.5")), Number::from_f64(0.5));
+        assert_ne!(Number::from(dec("0.1")), Number::from_f64(0.1));
+        assert!(Number::from(dec("0.1")) < Number::from_f64(0.1));
+        assert!(Number::from(dec("-0.1")) > Number::from_f64(-0.1));
+        assert!(Number::from(dec("-1.1")) > Number::from_f64(-1.1));
+        assert!(Number::from(dec("1e30")) < Number::from_f64(1e30));
+        assert_eq!(Number::from(dec("-2.75")), Number::from_f32(-2.75));
+        assert!(Number::from(dec("1.5")) > Number::from_u128(1));
+        assert!(Number::from(dec("1.5")) < Number::from_u128(u128::MAX));
+        assert!(Number::from(dec("-1.5")) < Number::from_i8(-1));
+        assert!(Number::from(dec("1e30")) < Number::from_f64(f64::NAN));
+    }
+
+    #[test]
+    fn hash_agrees_with_eq() {
+        use std::hash::{BuildHasher, RandomState};
+
+        let state = RandomState::new();
+        let hash = |n: Number| state.hash_one(n);
+
+        assert_eq!(hash(dec("2.00").into()), hash(Number::from_u8(2)));
+        assert_eq!(hash(dec("0.250").into()), hash(Number::from_f32(0.25)));
+        assert_eq!(hash(dec("0.10").into()), hash(dec("0.1").into()));
+    }
+
+    #[test]
+    fn round_and_rescale() {
+        assert_eq!(dec("2.345").round(2).to_string(), "2.34");
+        assert_eq!(dec("2.355").round(2).to_string(), "2.36");
+        assert_eq!(dec("-2.3451").round(2).to_string(), "-2.35");
+        assert_eq!(dec("1.5").rescale(3).unwrap().to_string(), "1.500");
+        assert_eq!(dec("1.500").rescale(1).unwrap().to_string(), "1.5");
+        assert!(matches!(
+            dec("1.25").rescale(1),
+            Err(ValueError::PrecisionLoss { .. })
+        ));
+        assert_eq!(dec("12.3400").normalize().to_string(), "12.34");
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(dec("0.1").to_f64(), 0.1);
+        assert_eq!(dec("3.999").to_i32(), 3);
+        assert_eq!(dec("12.00").checked_to_u8(), Ok(12));
+        assert_eq!(
+            dec("1.5").checked_to_i64(),
+            Err(ValueError::precision_loss("1.5", "i64"))
+        );
+        assert_eq!(dec("0.5").checked_to_f32(), Ok(0.5));
+        assert_eq!(
+            dec("0.1").checked_to_f64(),
+            Err(ValueError::precision_loss("0.1", "f64"))
+        );
+        assert_eq!(
+            Number::from_f64(0.1)
+                .checked_to_decimal()
+                .unwrap()
+                .to_string(),
+            "0.1"
+        );
+        assert!(Number::from_u128(u128::MAX).checked_to_decimal().is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        assert_eq!(serde_json::to_string(&dec("12.30")).unwrap(), r#""12.30""#);
+        assert!(
+            serde_json::from_str::<Decimal>(r#""12.30""#)
+                .unwrap()
+                .strict_eq(&dec("12.30"))
+        );
+        assert_eq!(serde_json::from_str::<Decimal>("7").unwrap(), dec("7"));
+
+        let n: Number = serde_json::from_str(r#""0.10""#).unwrap();
+        assert!(n.strict_eq(&dec("0.10").into()));
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{ToValue, Value, num::Number};
+use crate::{
+    ToValue, Value, ValueError, ValueRef,
+    num::{Number, checked_float_to_int, checked_float_to_uint, cmp_f64},
+};
 
 /// A floating-point value that can hold an [`f32`] or [`f64`].
 #[derive(Copy, Clone)]
@@ -127,6 +130,22 @@ impl Float {
         }
     }
 
+    /// Equal in width and bit pattern, unlike `==` which compares value only.
+    pub fn strict_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::F32(a), Self::F32(b)) => a.to_bits() == b.to_bits(),
+            (Self::F64(a), Self::F64(b)) => a.to_bits() == b.to_bits(),
+            _ => false,
+        }
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::F64(_) => "f64",
+            Self::F32(_) => "f32",
+        }
+    }
+
     pub fn type_id(&self) -> std::any::TypeId {
         match self {
             Self::F64(_) => std::any::TypeId::of::<f64>(),
@@ -135,6 +154,85 @@ impl Float {
     }
 }
 
+impl Float {
+    pub fn checked_to_i8(&self) -> Result<i8, ValueError> {
+        checked_float_to_int(self.to_f64(), "i8")
+    }
+
+    pub fn checked_to_i16(&self) -> Result<i16, ValueError> {
+        checked_float_to_int(self.to_f64(), "i16")
+    }
+
+    pub fn checked_to_i32(&self) -> Result<i32, ValueError> {
+        checked_float_to_int(self.to_f64(), "i32")
+    }
+
+    pub fn checked_to_i64(&self) -> Result<i64, ValueError> {
+        checked_float_to_int(self.to_f64(), "i64")
+    }
+
+    pub fn checked_to_i128(&self) -> Result<i128, ValueError> {
+        checked_float_to_int(self.to_f64(), "i128")
+    }
+
+    pub fn checked_to_isize(&self) -> Result<isize, ValueError> {
+        checked_float_to_int(self.to_f64(), "isize")
+    }
+
+    pub fn checked_to_u8(&self) -> Result<u8, ValueError> {
+        checked_float_to_uint(self.to_f64(), "u8")
+    }
+
+    pub fn checked_to_u16(&self) -> Result<u16, ValueError> {
+        checked_float_to_uint(self.to_f64(), "u16")
+    }
+
+    pub fn checked_to_u32(&self) -> Result<u32, ValueError> {
+        checked_float_to_uint(self.to_f64(), "u32")
+    }
+
+    pub fn checked_to_u64(&self) -> Result<u64, ValueError> {
+        checked_float_to_uint(self.to_f64(), "u64")
+    }
+
+    pub fn checked_to_u128(&self) -> Result<u128, ValueError> {
+        checked_float_to_uint(self.to_f64(), "u128")
+    }
+
+    pub fn checked_to_usize(&self) -> Result<usize, ValueError> {
+        checked_float_to_uint(self.to_f64(), "usize")
+    }
+
+    /// Narrowing an [`f64`] fails when the value is out of [`f32`] range or
+    /// would be rounded. NaN and infinities convert as-is.
+    pub fn checked_to_f32(&self) -> Result<f32, ValueError> {
+        match self {
+            Self::F32(v) => Ok(*v),
+            Self::F64(v) if !v.is_finite() => Ok(*v as f32),
+            Self::F64(v) => {
+                let rounded = *v as f32;
+
+                if rounded.is_infinite() {
+                    return Err(ValueError::overflow(v, "f32"));
+                }
+
+                if rounded as f64 != *v {
+                    return Err(ValueError::precision_loss(v, "f32"));
+                }
+
+                Ok(rounded)
+            }
+        }
+    }
+
+    pub fn checked_to_f64(&self) -> Result<f64, ValueError> {
+        Ok(self.to_f64())
+    }
+}
+
+/// Floats compare by value across widths, with `-0.0 == 0.0`. Unlike IEEE
+/// comparison every NaN is equal to every other NaN and greater than
+/// every number, which keeps `Eq`, `Ord` and `Hash` total and in agreement.
 impl PartialEq for Float {
     fn eq(&self, other: &Self) -> bool {
         self.cmp(other) == std::cmp::Ordering::Equal
@@ -143,13 +241,23 @@ impl PartialEq for Float {
 
 impl Eq for Float {}
 
+impl std::hash::Hash for Float {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let value = self.to_f64();
+
+        if value.is_nan() {
+            f64::NAN.to_bits().hash(state);
+        } else if value == 0.0 {
+            0.0_f64.to_bits().hash(state);
+        } else {
+            value.to_bits().hash(state);
+        }
+    }
+}
+
 impl Ord for Float {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        match (self, other) {
-            (Self::F32(a), Self::F32(b)) => a.total_cmp(b),
-            (Self::F64(a), Self::F64(b)) => a.total_cmp(b),
-            _ => self.to_f64().total_cmp(&other.to_f64()),
-        }
+        cmp_f64(self.to_f64(), other.to_f64())
     }
 }
 
@@ -161,25 +269,25 @@ impl PartialOrd for Float {
 
 impl PartialEq<f32> for Float {
     fn eq(&self, other: &f32) -> bool {
-        self.to_f64().total_cmp(&(*other as f64)) == std::cmp::Ordering::Equal
+        *self == Float::from_f32(*other)
     }
 }
 
 impl PartialEq<f64> for Float {
     fn eq(&self, other: &f64) -> bool {
-        self.to_f64().total_cmp(other) == std::cmp::Ordering::Equal
+        *self == Float::from_f64(*other)
     }
 }
 
 impl PartialEq<f32> for Number {
     fn eq(&self, other: &f32) -> bool {
-        matches!(self, Self::Float(v) if v == other)
+        *self == Number::from_f32(*other)
     }
 }
 
 impl PartialEq<f64> for Number {
     fn eq(&self, other: &f64) -> bool {
-        matches!(self, Self::Float(v) if v == other)
+        *self == Number::from_f64(*other)
     }
 }
 
@@ -285,18 +393,30 @@ impl ToValue for Float {
     fn to_value(&self) -> Value {
         Value::Number(Number::Float(*self))
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Number(Number::Float(*self))
+    }
 }
 
 impl ToValue for f32 {
     fn to_value(&self) -> Value {
         Value::from_f32(*self)
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Number(Number::from_f32(*self))
+    }
 }
 
 impl ToValue for f64 {
     fn to_value(&self) -> Value {
         Value::from_f64(*self)
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Number(Number::from_f64(*self))
+    }
 }
 
 #[cfg(test)]
@@ -333,6 +453,47 @@ mod tests {
         assert_eq!(Float::from_f64(1.5).to_f32(), 1.5);
     }
 
+    #[test]
+    fn checked_to_int() {
+        assert_eq!(Float::from_f64(42.0).checked_to_i32(), Ok(42));
+        assert_eq!(Float::from_f64(-0.0).checked_to_u8(), Ok(0));
+        assert_eq!(
+            Float::from_f64(1.5).checked_to_i32(),
+            Err(ValueError::precision_loss(1.5, "i32"))
+        );
+        assert_eq!(
+            Float::from_f64(-1.0).checked_to_u32(),
+            Err(ValueError::overflow(-1, "u32"))
+        );
+        assert_eq!(
+            Float::from_f64(1e300).checked_to_i128(),
+            Err(ValueError::overflow(1e300, "i128"))
+        );
+        assert_eq!(
+            Float::from_f64(f64::INFINITY).checked_to_u64(),
+            Err(ValueError::overflow(f64::INFINITY, "u64"))
+        );
+        assert_eq!(
+            Float::from_f64(f64::NAN).checked_to_i64(),
+            Err(ValueError::nan("i64"))
+        );
+    }
+
+    #[test]
+    fn checked_to_float() {
+        assert_eq!(Float::from_f64(1.5).checked_to_f32(), Ok(1.5));
+        assert_eq!(
+            Float::from_f64(0.1).checked_to_f32(),
+            Err(ValueError::precision_loss(0.1, "f32"))
+        );
+        assert_eq!(
+            Float::from_f64(1e300).checked_to_f32(),
+            Err(ValueError::overflow(1e300, "f32"))
+        );
+        assert!(Float::from_f64(f64::NAN).checked_to_f32().unwrap().is_nan());
+        assert_eq!(Float::from_f32(0.1).checked_to_f64(), Ok(0.1_f32 as f64));
+    }
+
     #[test]
     fn to_i64_from_float() {
         assert_eq!(Float::from_f64(3.14).to_i64(), 3);
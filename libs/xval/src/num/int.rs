@@ -1,7 +1,10 @@
-use crate::{ToValue, Value, num::Number};
+use crate::{
+    ToValue, Value, ValueError, ValueRef,
+    num::{Number, checked_int, is_exact_i128},
+};
 
 /// A signed integer value that can hold an [`i8`], [`i16`], [`i32`], [`i64`], or [`i128`].
-#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Deserialize, serde::Serialize),
@@ -208,6 +211,21 @@ impl Int {
         }
     }
 
+    /// Equal in value and width, unlike `==` which compares value only.
+    pub fn strict_eq(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other) && self == other
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::I8(_) => "i8",
+            Self::I16(_) => "i16",
+            Self::I32(_) => "i32",
+            Self::I64(_) => "i64",
+            Self::I128(_) => "i128",
+        }
+    }
+
     pub fn type_id(&self) -> std::any::TypeId {
         match self {
             Self::I8(_) => std::any::TypeId::of::<i8>(),
@@ -219,6 +237,94 @@ impl Int {
     }
 }
 
+impl Int {
+    pub fn checked_to_i8(&self) -> Result<i8, ValueError> {
+        checked_int(self.to_i128(), "i8")
+    }
+
+    pub fn checked_to_i16(&self) -> Result<i16, ValueError> {
+        checked_int(self.to_i128(), "i16")
+    }
+
+    pub fn checked_to_i32(&self) -> Result<i32, ValueError> {
+        checked_int(self.to_i128(), "i32")
+    }
+
+    pub fn checked_to_i64(&self) -> Result<i64, ValueError> {
+        checked_int(self.to_i128(), "i64")
+    }
+
+    pub fn checked_to_i128(&self) -> Result<i128, ValueError> {
+        checked_int(self.to_i128(), "i128")
+    }
+
+    pub fn checked_to_isize(&self) -> Result<isize, ValueError> {
+        checked_int(self.to_i128(), "isize")
+    }
+
+    pub fn checked_to_u8(&self) -> Result<u8, ValueError> {
+        checked_int(self.to_i128(), "u8")
+    }
+
+    pub fn checked_to_u16(&self) -> Result<u16, ValueError> {
+        checked_int(self.to_i128(), "u16")
+    }
+
+    pub fn checked_to_u32(&self) -> Result<u32, ValueError> {
+        checked_int(self.to_i128(), "u32")
+    }
+
+    pub fn checked_to_u64(&self) -> Result<u64, ValueError> {
+        checked_int(self.to_i128(), "u64")
+    }
+
+    pub fn checked_to_u128(&self) -> Result<u128, ValueError> {
+        checked_int(self.to_i128(), "u128")
+    }
+
+    pub fn checked_to_usize(&self) -> Result<usize, ValueError> {
+        checked_int(self.to_i128(), "usize")
+    }
+
+    pub fn checked_to_f32(&self) -> Result<f32, ValueError> {
+        let value = self.to_i128();
+        let rounded = value as f32;
+
+        if !is_exact_i128(value, rounded as f64) {
+            return Err(ValueError::precision_loss(self, "f32"));
+        }
+
+        Ok(rounded)
+    }
+
+    pub fn checked_to_f64(&self) -> Result<f64, ValueError> {
+        let value = self.to_i128();
+        let rounded = value as f64;
+
+        if !is_exact_i128(value, rounded) {
+            return Err(ValueError::precision_loss(self, "f64"));
+        }
+
+        Ok(rounded)
+    }
+}
+
+/// Integers compare by value, so `Int::from_i8(1) == Int::from_i32(1)`.
+/// Use [`Int::strict_eq`] to also require the same width.
+impl PartialEq for Int {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_i128() == other.to_i128()
+    }
+}
+
+impl Eq for Int {}
+
+impl std::hash::Hash for Int {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.to_i128().hash(state);
+    }
+}
+
 impl PartialOrd for Int {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
@@ -233,61 +339,61 @@ impl Ord for Int {
 
 impl PartialEq<i8> for Int {
     fn eq(&self, other: &i8) -> bool {
-        matches!(self, Self::I8(v) if v == other)
+        self.to_i128() == *other as i128
     }
 }
 
 impl PartialEq<i16> for Int {
     fn eq(&self, other: &i16) -> bool {
-        matches!(self, Self::I16(v) if v == other)
+        self.to_i128() == *other as i128
     }
 }
 
 impl PartialEq<i32> for Int {
     fn eq(&self, other: &i32) -> bool {
-        matches!(self, Self::I32(v) if v == other)
+        self.to_i128() == *other as i128
     }
 }
 
 impl PartialEq<i64> for Int {
     fn eq(&self, other: &i64) -> bool {
-        matches!(self, Self::I64(v) if v == other)
+        self.to_i128() == *other as i128
     }
 }
 
 impl PartialEq<i128> for Int {
     fn eq(&self, other: &i128) -> bool {
-        matches!(self, Self::I128(v) if v == other)
+        self.to_i128() == *other
     }
 }
 
 impl PartialEq<i8> for Number {
     fn eq(&self, other: &i8) -> bool {
-        matches!(self, Self::Int(v) if v == other)
+        *self == Number::from_i8(*other)
     }
 }
 
 impl PartialEq<i16> for Number {
     fn eq(&self, other: &i16) -> bool {
-        matches!(self, Self::Int(v) if v == other)
+        *self == Number::from_i16(*other)
     }
 }
 
 impl PartialEq<i32> for Number {
     fn eq(&self, other: &i32) -> bool {
-        matches!(self, Self::Int(v) if v == other)
+        *self == Number::from_i32(*other)
     }
 }
 
 impl PartialEq<i64> for Number {
     fn eq(&self, other: &i64) -> bool {
-        matches!(self, Self::Int(v) if v == other)
+        *self == Number::from_i64(*other)
     }
 }
 
 impl PartialEq<i128> for Number {
     fn eq(&self, other: &i128) -> bool {
-        matches!(self, Self::Int(v) if v == other)
+        *self == Number::from_i128(*other)
     }
 }
 
@@ -521,42 +627,70 @@ impl ToValue for Int {
     fn to_value(&self) -> Value {
         Value::Number(Number::Int(*self))
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Number(Number::Int(*self))
+    }
 }
 
 impl ToValue for i8 {
     fn to_value(&self) -> Value {
         Value::from_i8(*self)
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Number(Number::from_i8(*self))
+    }
 }
 
 impl ToValue for i16 {
     fn to_value(&self) -> Value {
         Value::from_i16(*self)
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Number(Number::from_i16(*self))
+    }
 }
 
 impl ToValue for i32 {
     fn to_value(&self) -> Value {
         Value::from_i32(*self)
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Number(Number::from_i32(*self))
+    }
 }
 
 impl ToValue for i64 {
     fn to_value(&self) -> Value {
         Value::from_i64(*self)
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Number(Number::from_i64(*self))
+    }
 }
 
 impl ToValue for i128 {
     fn to_value(&self) -> Value {
         Value::from_i128(*self)
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Number(Number::from_i128(*self))
+    }
 }
 
 impl ToValue for isize {
     fn to_value(&self) -> Value {
         Value::from_isize(*self)
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Number(Number::from_isize(*self))
+    }
 }
 
 #[cfg(test)]
@@ -678,6 +812,36 @@ mod tests {
         assert_eq!(Int::from_i128(1).type_id(), std::any::TypeId::of::<i128>());
     }
 
+    #[test]
+    fn checked_to_int() {
+        assert_eq!(Int::from_i64(-5).checked_to_i8(), Ok(-5));
+        assert_eq!(
+            Int::from_i32(300).checked_to_i8(),
+            Err(ValueError::overflow(300, "i8"))
+        );
+        assert_eq!(
+            Int::from_i8(-1).checked_to_u32(),
+            Err(ValueError::overflow(-1, "u32"))
+        );
+        assert_eq!(
+            Int::from_i128(i64::MAX as i128).checked_to_u64(),
+            Ok(i64::MAX as u64)
+        );
+    }
+
+    #[test]
+    fn checked_to_float() {
+        assert_eq!(Int::from_i32(1 << 24).checked_to_f32(), Ok(16_777_216.0));
+        assert_eq!(
+            Int::from_i32((1 << 24) + 1).checked_to_f32(),
+            Err(ValueError::precision_loss(16_777_217, "f32"))
+        );
+        assert_eq!(
+            Int::from_i64(i64::MAX).checked_to_f64(),
+            Err(ValueError::precision_loss(i64::MAX, "f64"))
+        );
+    }
+
     #[cfg(feature = "serde")]
     mod serde {
         use super::*;
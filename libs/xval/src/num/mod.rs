@@ -1,15 +1,19 @@
+mod decimal;
 mod float;
 mod int;
+mod ops;
 mod uint;
 
+pub use decimal::*;
 pub use float::*;
 pub use int::*;
 pub use uint::*;
 
-use crate::{ToValue, Value};
+use crate::{ToValue, Value, ValueError, ValueRef};
 
-/// A numeric value that can hold a float, signed integer, or unsigned integer.
-#[derive(Copy, Clone, PartialEq, Eq)]
+/// A numeric value that can hold a float, signed integer, unsigned integer
+/// or exact [`Decimal`].
+#[derive(Copy, Clone)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Deserialize, serde::Serialize),
@@ -19,6 +23,7 @@ pub enum Number {
     Int(Int),
     UInt(UInt),
     Float(Float),
+    Decimal(Decimal),
 }
 
 impl Number {
@@ -55,11 +60,54 @@ impl Number {
         }
     }
 
+    pub fn try_as_int(&self) -> Result<&Int, ValueError> {
+        match self {
+            Self::Int(v) => Ok(v),
+            v => Err(ValueError::type_mismatch("int", v.type_name())),
+        }
+    }
+
+    pub fn try_as_uint(&self) -> Result<&UInt, ValueError> {
+        match self {
+            Self::UInt(v) => Ok(v),
+            v => Err(ValueError::type_mismatch("uint", v.type_name())),
+        }
+    }
+
+    pub fn try_as_float(&self) -> Result<&Float, ValueError> {
+        match self {
+            Self::Float(v) => Ok(v),
+            v => Err(ValueError::type_mismatch("float", v.type_name())),
+        }
+    }
+
+    /// Equal in kind, width and value, unlike `==` which compares the
+    /// mathematical value only.
+    pub fn strict_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Int(a), Self::Int(b)) => a.strict_eq(b),
+            (Self::UInt(a), Self::UInt(b)) => a.strict_eq(b),
+            (Self::Float(a), Self::Float(b)) => a.strict_eq(b),
+            (Self::Decimal(a), Self::Decimal(b)) => a.strict_eq(b),
+            _ => false,
+        }
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Int(v) => v.type_name(),
+            Self::UInt(v) => v.type_name(),
+            Self::Float(v) => v.type_name(),
+            Self::Decimal(v) => v.type_name(),
+        }
+    }
+
     pub fn type_id(&self) -> std::any::TypeId {
         match self {
             Self::Int(v) => v.type_id(),
             Self::UInt(v) => v.type_id(),
             Self::Float(v) => v.type_id(),
+            Self::Decimal(v) => v.type_id(),
         }
     }
 }
@@ -78,6 +126,7 @@ impl Number {
             Self::Int(v) => v.to_f32(),
             Self::UInt(v) => v.to_f32(),
             Self::Float(v) => v.to_f32(),
+            Self::Decimal(v) => v.to_f32(),
         }
     }
 
@@ -86,6 +135,7 @@ impl Number {
             Self::Int(v) => v.to_f64(),
             Self::UInt(v) => v.to_f64(),
             Self::Float(v) => v.to_f64(),
+            Self::Decimal(v) => v.to_f64(),
         }
     }
 }
@@ -116,6 +166,7 @@ impl Number {
             Self::Int(v) => v.to_i8(),
             Self::UInt(v) => v.to_i8(),
             Self::Float(v) => v.to_i8(),
+            Self::Decimal(v) => v.to_i8(),
         }
     }
 
@@ -124,6 +175,7 @@ impl Number {
             Self::Int(v) => v.to_i16(),
             Self::UInt(v) => v.to_i16(),
             Self::Float(v) => v.to_i16(),
+            Self::Decimal(v) => v.to_i16(),
         }
     }
 
@@ -132,6 +184,7 @@ impl Number {
             Self::Int(v) => v.to_i32(),
             Self::UInt(v) => v.to_i32(),
             Self::Float(v) => v.to_i32(),
+            Self::Decimal(v) => v.to_i32(),
         }
     }
 
@@ -140,6 +193,7 @@ impl Number {
             Self::Int(v) => v.to_i64(),
             Self::UInt(v) => v.to_i64(),
             Self::Float(v) => v.to_i64(),
+            Self::Decimal(v) => v.to_i64(),
         }
     }
 
@@ -148,6 +202,7 @@ impl Number {
             Self::Int(v) => v.to_i128(),
             Self::UInt(v) => v.to_i128(),
             Self::Float(v) => v.to_i128(),
+            Self::Decimal(v) => v.to_i128(),
         }
     }
 
@@ -156,6 +211,7 @@ impl Number {
             Self::Int(v) => v.to_isize(),
             Self::UInt(v) => v.to_isize(),
             Self::Float(v) => v.to_isize(),
+            Self::Decimal(v) => v.to_isize(),
         }
     }
 }
@@ -186,6 +242,7 @@ impl Number {
             Self::Int(v) => v.to_u8(),
             Self::UInt(v) => v.to_u8(),
             Self::Float(v) => v.to_u8(),
+            Self::Decimal(v) => v.to_u8(),
         }
     }
 
@@ -194,6 +251,7 @@ impl Number {
             Self::Int(v) => v.to_u16(),
             Self::UInt(v) => v.to_u16(),
             Self::Float(v) => v.to_u16(),
+            Self::Decimal(v) => v.to_u16(),
         }
     }
 
@@ -202,6 +260,7 @@ impl Number {
             Self::Int(v) => v.to_u32(),
             Self::UInt(v) => v.to_u32(),
             Self::Float(v) => v.to_u32(),
+            Self::Decimal(v) => v.to_u32(),
         }
     }
 
@@ -210,6 +269,7 @@ impl Number {
             Self::Int(v) => v.to_u64(),
             Self::UInt(v) => v.to_u64(),
             Self::Float(v) => v.to_u64(),
+            Self::Decimal(v) => v.to_u64(),
         }
     }
 
@@ -218,6 +278,7 @@ impl Number {
             Self::Int(v) => v.to_u128(),
             Self::UInt(v) => v.to_u128(),
             Self::Float(v) => v.to_u128(),
+            Self::Decimal(v) => v.to_u128(),
         }
     }
 
@@ -226,6 +287,208 @@ impl Number {
             Self::Int(v) => v.to_usize(),
             Self::UInt(v) => v.to_usize(),
             Self::Float(v) => v.to_usize(),
+            Self::Decimal(v) => v.to_usize(),
+        }
+    }
+}
+
+impl Number {
+    pub fn checked_to_i8(&self) -> Result<i8, ValueError> {
+        match self {
+            Self::Int(v) => v.checked_to_i8(),
+            Self::UInt(v) => v.checked_to_i8(),
+            Self::Float(v) => v.checked_to_i8(),
+            Self::Decimal(v) => v.checked_to_i8(),
+        }
+    }
+
+    pub fn checked_to_i16(&self) -> Result<i16, ValueError> {
+        match self {
+            Self::Int(v) => v.checked_to_i16(),
+            Self::UInt(v) => v.checked_to_i16(),
+            Self::Float(v) => v.checked_to_i16(),
+            Self::Decimal(v) => v.checked_to_i16(),
+        }
+    }
+
+    pub fn checked_to_i32(&self) -> Result<i32, ValueError> {
+        match self {
+            Self::Int(v) => v.checked_to_i32(),
+            Self::UInt(v) => v.checked_to_i32(),
+            Self::Float(v) => v.checked_to_i32(),
+            Self::Decimal(v) => v.checked_to_i32(),
+        }
+    }
+
+    pub fn checked_to_i64(&self) -> Result<i64, ValueError> {
+        match self {
+            Self::Int(v) => v.checked_to_i64(),
+            Self::UInt(v) => v.checked_to_i64(),
+            Self::Float(v) => v.checked_to_i64(),
+            Self::Decimal(v) => v.checked_to_i64(),
+        }
+    }
+
+    pub fn checked_to_i128(&self) -> Result<i128, ValueError> {
+        match self {
+            Self::Int(v) => v.checked_to_i128(),
+            Self::UInt(v) => v.checked_to_i128(),
+            Self::Float(v) => v.checked_to_i128(),
+            Self::Decimal(v) => v.checked_to_i128(),
+        }
+    }
+
+    pub fn checked_to_isize(&self) -> Result<isize, ValueError> {
+        match self {
+            Self::Int(v) => v.checked_to_isize(),
+            Self::UInt(v) => v.checked_to_isize(),
+            Self::Float(v) => v.checked_to_isize(),
+            Self::Decimal(v) => v.checked_to_isize(),
+        }
+    }
+
+    pub fn checked_to_u8(&self) -> Result<u8, ValueError> {
+        match self {
+            Self::Int(v) => v.checked_to_u8(),
+            Self::UInt(v) => v.checked_to_u8(),
+            Self::Float(v) => v.checked_to_u8(),
+            Self::Decimal(v) => v.checked_to_u8(),
+        }
+    }
+
+    pub fn checked_to_u16(&self) -> Result<u16, ValueError> {
+        match self {
+            Self::Int(v) => v.checked_to_u16(),
+            Self::UInt(v) => v.checked_to_u16(),
+            Self::Float(v) => v.checked_to_u16(),
+            Self::Decimal(v) => v.checked_to_u16(),
+        }
+    }
+
+    pub fn checked_to_u32(&self) -> Result<u32, ValueError> {
+        match self {
+            Self::Int(v) => v.checked_to_u32(),
+            Self::UInt(v) => v.checked_to_u32(),
+            Self::Float(v) => v.checked_to_u32(),
+            Self::Decimal(v) => v.checked_to_u32(),
+        }
+    }
+
+    pub fn checked_to_u64(&self) -> Result<u64, ValueError> {
+        match self {
+            Self::Int(v) => v.checked_to_u64(),
+            Self::UInt(v) => v.checked_to_u64(),
+            Self::Float(v) => v.checked_to_u64(),
+            Self::Decimal(v) => v.checked_to_u64(),
+        }
+    }
+
+    pub fn checked_to_u128(&self) -> Result<u128, ValueError> {
+        match self {
+            Self::Int(v) => v.checked_to_u128(),
+            Self::UInt(v) => v.checked_to_u128(),
+            Self::Float(v) => v.checked_to_u128(),
+            Self::Decimal(v) => v.checked_to_u128(),
+        }
+    }
+
+    pub fn checked_to_usize(&self) -> Result<usize, ValueError> {
+        match self {
+            Self::Int(v) => v.checked_to_usize(),
+            Self::UInt(v) => v.checked_to_usize(),
+            Self::Float(v) => v.checked_to_usize(),
+            Self::Decimal(v) => v.checked_to_usize(),
+        }
+    }
+
+    pub fn checked_to_f32(&self) -> Result<f32, ValueError> {
+        match self {
+            Self::Int(v) => v.checked_to_f32(),
+            Self::UInt(v) => v.checked_to_f32(),
+            Self::Float(v) => v.checked_to_f32(),
+            Self::Decimal(v) => v.checked_to_f32(),
+        }
+    }
+
+    pub fn checked_to_f64(&self) -> Result<f64, ValueError> {
+        match self {
+            Self::Int(v) => v.checked_to_f64(),
+            Self::UInt(v) => v.checked_to_f64(),
+            Self::Float(v) => v.checked_to_f64(),
+            Self::Decimal(v) => v.checked_to_f64(),
+        }
+    }
+}
+
+/// Numbers are equal when their mathematical values are, whatever their
+/// kind or width: `1_u8`, `1_i64`, `1.0_f32` and the decimal `1.00` are all
+/// equal. Integers and decimals are compared exactly, never by rounding
+/// through `f64`, so the decimal `0.1` is less than `0.1_f64`.
+impl PartialEq for Number {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for Number {}
+
+/// Agrees with `Eq`: a number with an exact integer value hashes as that
+/// integer, whether it is stored as an `Int`, `UInt` or `Float`.
+impl std::hash::Hash for Number {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        match self.canonical() {
+            CanonicalNumber::Int(v) => (0_u8, v).hash(state),
+            CanonicalNumber::UInt(v) => (1_u8, v).hash(state),
+            CanonicalNumber::Float(v) => (2_u8, Float::F64(v)).hash(state),
+            CanonicalNumber::Decimal(v) => (3_u8, v).hash(state),
+        }
+    }
+}
+
+/// The one form every equal [`Number`] shares, used for hashing,
+/// fingerprints and canonical JSON.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum CanonicalNumber {
+    /// Integers and integral floats that fit an `i128`.
+    Int(i128),
+    /// Integers and integral floats that only fit a `u128`.
+    UInt(u128),
+    /// Floats, and decimals with an exact `f64` value.
+    Float(f64),
+    /// Every other decimal, normalized.
+    Decimal(Decimal),
+}
+
+impl Number {
+    pub(crate) fn canonical(&self) -> CanonicalNumber {
+        let uint = |v: u128| match i128::try_from(v) {
+            Ok(v) => CanonicalNumber::Int(v),
+            Err(_) => CanonicalNumber::UInt(v),
+        };
+
+        match self {
+            Self::Int(v) => CanonicalNumber::Int(v.to_i128()),
+            Self::UInt(v) => uint(v.to_u128()),
+            Self::Float(v) => {
+                let value = v.to_f64();
+
+                if value.fract() == 0.0 && (-I128_BOUND..I128_BOUND).contains(&value) {
+                    CanonicalNumber::Int(value as i128)
+                } else if value.fract() == 0.0 && (0.0..U128_BOUND).contains(&value) {
+                    uint(value as u128)
+                } else {
+                    CanonicalNumber::Float(value)
+                }
+            }
+            Self::Decimal(v) if v.is_integer() => CanonicalNumber::Int(v.trunc()),
+            Self::Decimal(v) => {
+                let value = v.to_f64();
+
+                match cmp_decimal_f64(v, value) {
+                    std::cmp::Ordering::Equal => CanonicalNumber::Float(value),
+                    _ => CanonicalNumber::Decimal(v.normalize()),
+                }
+            }
         }
     }
 }
@@ -236,7 +499,19 @@ impl Ord for Number {
             (Self::Int(a), Self::Int(b)) => a.cmp(b),
             (Self::UInt(a), Self::UInt(b)) => a.cmp(b),
             (Self::Float(a), Self::Float(b)) => a.cmp(b),
-            _ => self.to_f64().total_cmp(&other.to_f64()),
+            (Self::Int(a), Self::UInt(b)) => cmp_i128_u128(a.to_i128(), b.to_u128()),
+            (Self::UInt(a), Self::Int(b)) => cmp_i128_u128(b.to_i128(), a.to_u128()).reverse(),
+            (Self::Int(a), Self::Float(b)) => cmp_i128_f64(a.to_i128(), b.to_f64()),
+            (Self::Float(a), Self::Int(b)) => cmp_i128_f64(b.to_i128(), a.to_f64()).reverse(),
+            (Self::UInt(a), Self::Float(b)) => cmp_u128_f64(a.to_u128(), b.to_f64()),
+            (Self::Float(a), Self::UInt(b)) => cmp_u128_f64(b.to_u128(), a.to_f64()).reverse(),
+            (Self::Decimal(a), Self::Decimal(b)) => a.cmp(b),
+            (Self::Decimal(a), Self::Int(b)) => cmp_decimal_i128(a, b.to_i128()),
+            (Self::Int(a), Self::Decimal(b)) => cmp_decimal_i128(b, a.to_i128()).reverse(),
+            (Self::Decimal(a), Self::UInt(b)) => cmp_decimal_u128(a, b.to_u128()),
+            (Self::UInt(a), Self::Decimal(b)) => cmp_decimal_u128(b, a.to_u128()).reverse(),
+            (Self::Decimal(a), Self::Float(b)) => cmp_decimal_f64(a, b.to_f64()),
+            (Self::Float(a), Self::Decimal(b)) => cmp_decimal_f64(b, a.to_f64()).reverse(),
         }
     }
 }
@@ -259,6 +534,7 @@ impl std::fmt::Debug for Number {
             Self::Int(v) => write!(f, "{:#?}", v),
             Self::UInt(v) => write!(f, "{:#?}", v),
             Self::Float(v) => write!(f, "{:#?}", v),
+            Self::Decimal(v) => write!(f, "{:#?}", v),
         }
     }
 }
@@ -269,6 +545,7 @@ impl std::fmt::Display for Number {
             Self::Int(v) => write!(f, "{}", v),
             Self::UInt(v) => write!(f, "{}", v),
             Self::Float(v) => write!(f, "{}", v),
+            Self::Decimal(v) => write!(f, "{}", v),
         }
     }
 }
@@ -277,6 +554,138 @@ impl ToValue for Number {
     fn to_value(&self) -> Value {
         Value::Number(*self)
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Number(*self)
+    }
+}
+
+/// 2^127, the first [`f64`] above [`i128::MAX`].
+const I128_BOUND: f64 = 170141183460469231731687303715884105728.0;
+
+/// 2^128, the first [`f64`] above [`u128::MAX`].
+const U128_BOUND: f64 = 340282366920938463463374607431768211456.0;
+
+pub(crate) fn checked_int<T: TryFrom<i128>>(
+    value: i128,
+    target: &'static str,
+) -> Result<T, ValueError> {
+    T::try_from(value).map_err(|_| ValueError::overflow(value, target))
+}
+
+pub(crate) fn checked_uint<T: TryFrom<u128>>(
+    value: u128,
+    target: &'static str,
+) -> Result<T, ValueError> {
+    T::try_from(value).map_err(|_| ValueError::overflow(value, target))
+}
+
+pub(crate) fn checked_float_to_int<T: TryFrom<i128>>(
+    value: f64,
+    target: &'static str,
+) -> Result<T, ValueError> {
+    if value.is_nan() {
+        return Err(ValueError::nan(target));
+    }
+
+    if !(-I128_BOUND..I128_BOUND).contains(&value) {
+        return Err(ValueError::overflow(value, target));
+    }
+
+    if value.fract() != 0.0 {
+        return Err(ValueError::precision_loss(value, target));
+    }
+
+    T::try_from(value as i128).map_err(|_| ValueError::overflow(value, target))
+}
+
+pub(crate) fn checked_float_to_uint<T: TryFrom<u128>>(
+    value: f64,
+    target: &'static str,
+) -> Result<T, ValueError> {
+    if value.is_nan() {
+        return Err(ValueError::nan(target));
+    }
+
+    if !(0.0..U128_BOUND).contains(&value) {
+        return Err(ValueError::overflow(value, target));
+    }
+
+    if value.fract() != 0.0 {
+        return Err(ValueError::precision_loss(value, target));
+    }
+
+    T::try_from(value as u128).map_err(|_| ValueError::overflow(value, target))
+}
+
+/// Total order over floats by value: `-0.0 == 0.0`, and NaN equals NaN and
+/// sorts above every other number.
+pub(crate) fn cmp_f64(a: f64, b: f64) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(&b).expect("neither side is NaN"),
+    }
+}
+
+fn cmp_i128_u128(a: i128, b: u128) -> std::cmp::Ordering {
+    match u128::try_from(a) {
+        Ok(a) => a.cmp(&b),
+        Err(_) => std::cmp::Ordering::Less,
+    }
+}
+
+/// Compare without rounding `a` to a float. NaN sorts above every integer,
+/// as in [`cmp_f64`].
+fn cmp_i128_f64(a: i128, b: f64) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    if b.is_nan() {
+        return Ordering::Less;
+    }
+
+    if b >= I128_BOUND {
+        return Ordering::Less;
+    }
+
+    if b < -I128_BOUND {
+        return Ordering::Greater;
+    }
+
+    let whole = b.trunc();
+    a.cmp(&(whole as i128)).then(whole.total_cmp(&b))
+}
+
+fn cmp_u128_f64(a: u128, b: f64) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    if b.is_nan() {
+        return Ordering::Less;
+    }
+
+    if b >= U128_BOUND {
+        return Ordering::Less;
+    }
+
+    if b < 0.0 {
+        return Ordering::Greater;
+    }
+
+    let whole = b.trunc();
+    a.cmp(&(whole as u128)).then(whole.total_cmp(&b))
+}
+
+/// Whether `rounded` is exactly `value`, guarding against the saturating
+/// float-to-int cast that would map 2^127 back onto [`i128::MAX`].
+pub(crate) fn is_exact_i128(value: i128, rounded: f64) -> bool {
+    (-I128_BOUND..I128_BOUND).contains(&rounded) && rounded as i128 == value
+}
+
+pub(crate) fn is_exact_u128(value: u128, rounded: f64) -> bool {
+    rounded < U128_BOUND && rounded as u128 == value
 }
 
 #[cfg(test)]
@@ -369,6 +778,86 @@ mod tests {
         assert_eq!(Number::from_u32(1).type_id(), std::any::TypeId::of::<u32>());
     }
 
+    #[test]
+    fn eq_across_kinds() {
+        assert_eq!(Number::from_i32(1), Number::from_i64(1));
+        assert_eq!(Number::from_u8(1), Number::from_i32(1));
+        assert_eq!(Number::from_f32(1.0), Number::from_i128(1));
+        assert_eq!(Number::from_f64(-0.0), Number::from_u64(0));
+        assert_ne!(
+            Number::from_u64(u64::MAX),
+            Number::from_f64(u64::MAX as f64)
+        );
+        assert_ne!(Number::from_i32(-1), Number::from_u32(u32::MAX));
+        assert_ne!(Number::from_i32(1), Number::from_f64(1.5));
+        assert_eq!(Number::from_i32(1), 1_u8);
+        assert_eq!(Number::from_u16(2), 2.0_f64);
+    }
+
+    #[test]
+    fn nan() {
+        let nan = Number::from_f64(f64::NAN);
+        assert_eq!(nan, Number::from_f32(-f32::NAN));
+        assert!(nan > Number::from_f64(f64::INFINITY));
+        assert!(nan > Number::from_u128(u128::MAX));
+        assert!(nan > Number::from_i8(-1));
+    }
+
+    #[test]
+    fn hash_agrees_with_eq() {
+        use std::hash::{BuildHasher, RandomState};
+
+        let state = RandomState::new();
+        let hash = |n: Number| state.hash_one(n);
+
+        assert_eq!(hash(Number::from_i8(7)), hash(Number::from_u64(7)));
+        assert_eq!(hash(Number::from_i8(7)), hash(Number::from_f32(7.0)));
+        assert_eq!(hash(Number::from_f64(0.0)), hash(Number::from_f64(-0.0)));
+        assert_eq!(
+            hash(Number::from_u128(1 << 127)),
+            hash(Number::from_f64(2.0_f64.powi(127)))
+        );
+        assert_eq!(
+            hash(Number::from_f64(f64::NAN)),
+            hash(Number::from_f32(f32::NAN))
+        );
+    }
+
+    #[test]
+    fn strict_eq() {
+        assert!(Number::from_i32(1).strict_eq(&Number::from_i32(1)));
+        assert!(!Number::from_i32(1).strict_eq(&Number::from_i64(1)));
+        assert!(!Number::from_u8(1).strict_eq(&Number::from_i8(1)));
+        assert!(!Number::from_f32(1.0).strict_eq(&Number::from_f64(1.0)));
+        assert!(!Number::from_f64(0.0).strict_eq(&Number::from_f64(-0.0)));
+    }
+
+    #[test]
+    fn try_as() {
+        assert_eq!(Number::from_i32(1).try_as_int().unwrap().to_i32(), 1);
+        assert_eq!(Number::from_u8(1).try_as_uint().unwrap().to_u8(), 1);
+        assert_eq!(Number::from_f64(1.0).try_as_float().unwrap().to_f64(), 1.0);
+        assert_eq!(
+            Number::from_i32(1).try_as_float().unwrap_err(),
+            ValueError::type_mismatch("float", "i32")
+        );
+    }
+
+    #[test]
+    fn checked_to() {
+        assert_eq!(Number::from_u64(10).checked_to_i8(), Ok(10));
+        assert_eq!(Number::from_f64(10.0).checked_to_u16(), Ok(10));
+        assert_eq!(Number::from_i32(-3).checked_to_f32(), Ok(-3.0));
+        assert_eq!(
+            Number::from_i64(-1).checked_to_usize(),
+            Err(ValueError::overflow(-1, "usize"))
+        );
+        assert_eq!(
+            Number::from_f32(2.5).checked_to_u8(),
+            Err(ValueError::precision_loss(2.5, "u8"))
+        );
+    }
+
     #[cfg(feature = "serde")]
     mod serde {
         use super::*;
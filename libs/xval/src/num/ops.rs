@@ -0,0 +1,970 @@
+use crate::{
+    ValueError,
+    num::{Decimal, Float, Int, Number, UInt},
+};
+
+/// A binary arithmetic operator.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl Op {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Self::Add => "+",
+            Self::Sub => "-",
+            Self::Mul => "*",
+            Self::Div => "/",
+            Self::Rem => "%",
+        }
+    }
+}
+
+/// An integer in sign-magnitude form, wide enough to hold every [`i128`]
+/// and [`u128`] so mixed signed/unsigned arithmetic is exact.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Wide {
+    neg: bool,
+    mag: u128,
+}
+
+impl Wide {
+    fn new(neg: bool, mag: u128) -> Self {
+        Self {
+            neg: neg && mag != 0,
+            mag,
+        }
+    }
+
+    fn from_i128(value: i128) -> Self {
+        Self::new(value < 0, value.unsigned_abs())
+    }
+
+    fn from_u128(value: u128) -> Self {
+        Self::new(false, value)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.mag == 0
+    }
+
+    fn neg(self) -> Self {
+        Self::new(!self.neg, self.mag)
+    }
+
+    fn add(self, rhs: Self) -> Option<Self> {
+        if self.neg == rhs.neg {
+            return Some(Self::new(self.neg, self.mag.checked_add(rhs.mag)?));
+        }
+
+        if self.mag >= rhs.mag {
+            Some(Self::new(self.neg, self.mag - rhs.mag))
+        } else {
+            Some(Self::new(rhs.neg, rhs.mag - self.mag))
+        }
+    }
+
+    fn mul(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.neg != rhs.neg,
+            self.mag.checked_mul(rhs.mag)?,
+        ))
+    }
+
+    fn pow(self, exp: u32) -> Option<Self> {
+        Some(Self::new(
+            self.neg && exp % 2 == 1,
+            self.mag.checked_pow(exp)?,
+        ))
+    }
+
+    /// Applies `op`, returning the sign the result would have had on overflow.
+    fn apply(self, op: Op, rhs: Self) -> Result<Self, bool> {
+        match op {
+            Op::Add => self.add(rhs).ok_or(self.neg),
+            Op::Sub => self.add(rhs.neg()).ok_or(self.neg),
+            Op::Mul => self.mul(rhs).ok_or(self.neg != rhs.neg),
+            // truncates toward zero, like Rust's integer division
+            Op::Div => Ok(Self::new(self.neg != rhs.neg, self.mag / rhs.mag)),
+            // takes the sign of the dividend, like Rust's `%`
+            Op::Rem => Ok(Self::new(self.neg, self.mag % rhs.mag)),
+        }
+    }
+
+    fn to_i128(self) -> Option<i128> {
+        if self.neg {
+            0_i128.checked_sub_unsigned(self.mag)
+        } else {
+            i128::try_from(self.mag).ok()
+        }
+    }
+
+    fn to_u128(self) -> Option<u128> {
+        if self.neg { None } else { Some(self.mag) }
+    }
+
+    /// Picks the representation of a result: the promoted kind and width if
+    /// it fits, else the 128-bit type of the same kind, else the 128-bit type
+    /// of the other kind.
+    fn narrow(self, signed: bool, bits: u32) -> Option<Number> {
+        let fit = |signed: bool, bits: u32| {
+            if signed {
+                self.to_i128()
+                    .and_then(|v| int_of(v, bits))
+                    .map(Number::Int)
+            } else {
+                self.to_u128()
+                    .and_then(|v| uint_of(v, bits))
+                    .map(Number::UInt)
+            }
+        };
+
+        fit(signed, bits)
+            .or_else(|| fit(signed, 128))
+            .or_else(|| fit(!signed, 128))
+    }
+}
+
+fn int_bits(value: &Int) -> u32 {
+    match value {
+        Int::I8(_) => 8,
+        Int::I16(_) => 16,
+        Int::I32(_) => 32,
+        Int::I64(_) => 64,
+        Int::I128(_) => 128,
+    }
+}
+
+fn uint_bits(value: &UInt) -> u32 {
+    match value {
+        UInt::U8(_) => 8,
+        UInt::U16(_) => 16,
+        UInt::U32(_) => 32,
+        UInt::U64(_) => 64,
+        UInt::U128(_) => 128,
+    }
+}
+
+fn int_of(value: i128, bits: u32) -> Option<Int> {
+    match bits {
+        8 => i8::try_from(value).ok().map(Int::I8),
+        16 => i16::try_from(value).ok().map(Int::I16),
+        32 => i32::try_from(value).ok().map(Int::I32),
+        64 => i64::try_from(value).ok().map(Int::I64),
+        _ => Some(Int::I128(value)),
+    }
+}
+
+fn uint_of(value: u128, bits: u32) -> Option<UInt> {
+    match bits {
+        8 => u8::try_from(value).ok().map(UInt::U8),
+        16 => u16::try_from(value).ok().map(UInt::U16),
+        32 => u32::try_from(value).ok().map(UInt::U32),
+        64 => u64::try_from(value).ok().map(UInt::U64),
+        _ => Some(UInt::U128(value)),
+    }
+}
+
+fn overflow_target(neg: bool) -> &'static str {
+    if neg { "i128" } else { "u128" }
+}
+
+impl Number {
+    /// Integers as an exact [`Wide`] with their signedness and width, or
+    /// `None` for floats and decimals.
+    fn wide(&self) -> Option<(Wide, bool, u32)> {
+        match self {
+            Self::Int(v) => Some((Wide::from_i128(v.to_i128()), true, int_bits(v))),
+            Self::UInt(v) => Some((Wide::from_u128(v.to_u128()), false, uint_bits(v))),
+            Self::Float(_) | Self::Decimal(_) => None,
+        }
+    }
+
+    fn to_float(self) -> Float {
+        match self {
+            Self::Float(v) => v,
+            v => Float::F64(v.to_f64()),
+        }
+    }
+
+    fn checked_op(self, op: Op, rhs: Self) -> Result<Self, ValueError> {
+        let ((a, a_signed, a_bits), (b, b_signed, b_bits)) = match (self.wide(), rhs.wide()) {
+            (Some(a), Some(b)) => (a, b),
+            _ if self.is_float() || rhs.is_float() => {
+                return Ok(Self::Float(self.to_float().checked_op(op, rhs.to_float())?));
+            }
+            _ => {
+                let (a, b) = (self.checked_to_decimal()?, rhs.checked_to_decimal()?);
+                return Ok(Self::Decimal(a.checked_op(op, b)?));
+            }
+        };
+
+        if matches!(op, Op::Div | Op::Rem) && b.is_zero() {
+            return Err(ValueError::division_by_zero());
+        }
+
+        let expr = || format!("{} {} {}", self, op.symbol(), rhs);
+        let result = a
+            .apply(op, b)
+            .map_err(|neg| ValueError::overflow(expr(), overflow_target(neg)))?;
+
+        result
+            .narrow(a_signed || b_signed, a_bits.max(b_bits))
+            .ok_or_else(|| ValueError::overflow(expr(), overflow_target(result.neg)))
+    }
+
+    fn op(self, op: Op, rhs: Self) -> Self {
+        match (self, rhs) {
+            (Self::Float(_), _) | (_, Self::Float(_)) => {
+                Self::Float(self.to_float().op(op, rhs.to_float()))
+            }
+            _ => self
+                .checked_op(op, rhs)
+                .unwrap_or_else(|err| panic!("{err}")),
+        }
+    }
+
+    /// Adds two numbers exactly.
+    ///
+    /// Integers promote to the wider of the two widths, signed if either
+    /// side is signed, and widen to [`i128`] or [`u128`] when the result
+    /// does not fit. Any float operand makes the result a float, which is
+    /// only [`f32`] when both sides are; otherwise any [`Decimal`] operand
+    /// makes it a decimal. Errors when the exact result is out of range of
+    /// every integer type, or a float overflows to infinity.
+    pub fn checked_add(self, rhs: Self) -> Result<Self, ValueError> {
+        self.checked_op(Op::Add, rhs)
+    }
+
+    /// Subtracts `rhs`, promoting like [`Number::checked_add`].
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, ValueError> {
+        self.checked_op(Op::Sub, rhs)
+    }
+
+    /// Multiplies by `rhs`, promoting like [`Number::checked_add`].
+    pub fn checked_mul(self, rhs: Self) -> Result<Self, ValueError> {
+        self.checked_op(Op::Mul, rhs)
+    }
+
+    /// Divides by `rhs`, promoting like [`Number::checked_add`]. Integer
+    /// division truncates toward zero, and a zero divisor is an error for
+    /// floats too.
+    pub fn checked_div(self, rhs: Self) -> Result<Self, ValueError> {
+        self.checked_op(Op::Div, rhs)
+    }
+
+    /// The remainder of dividing by `rhs`, with the sign of `self`.
+    pub fn checked_rem(self, rhs: Self) -> Result<Self, ValueError> {
+        self.checked_op(Op::Rem, rhs)
+    }
+
+    /// Raises `self` to `exp`. An integer or decimal base with a
+    /// non-negative integer exponent stays exact, integers widening on
+    /// overflow; anything else is computed as a float.
+    pub fn checked_pow(self, exp: Self) -> Result<Self, ValueError> {
+        match (self, exp) {
+            (Self::Int(base), Self::Int(_) | Self::UInt(_)) if exp.to_i128() >= 0 => {
+                base.checked_pow(exp_u32(exp))
+            }
+            (Self::UInt(base), Self::Int(_) | Self::UInt(_)) if exp.to_i128() >= 0 => {
+                base.checked_pow(exp_u32(exp))
+            }
+            (Self::Decimal(base), Self::Int(_) | Self::UInt(_)) if exp.to_i128() >= 0 => {
+                Ok(Self::Decimal(base.checked_pow(exp_u32(exp))?))
+            }
+            (Self::Float(base), Self::Float(exp)) => Ok(Self::Float(base.checked_pow(exp)?)),
+            (Self::Float(base @ Float::F32(_)), _) => {
+                Ok(Self::Float(base.checked_pow(Float::F32(exp.to_f32()))?))
+            }
+            _ => Ok(Self::Float(
+                self.to_float().checked_pow(Float::F64(exp.to_f64()))?,
+            )),
+        }
+    }
+
+    /// Negates the number. Unsigned integers become signed.
+    pub fn checked_neg(self) -> Result<Self, ValueError> {
+        match self {
+            Self::Int(v) => v.checked_neg(),
+            Self::UInt(v) => v.checked_neg(),
+            Self::Float(v) => Ok(Self::Float(-v)),
+            Self::Decimal(v) => Ok(Self::Decimal(v.checked_neg()?)),
+        }
+    }
+}
+
+/// A non-negative integer exponent as a `u32`. Larger exponents saturate
+/// to the largest `u32` of the same parity, so a base of `-1` keeps the
+/// right sign.
+fn exp_u32(exp: Number) -> u32 {
+    exp.checked_to_u32().unwrap_or(match exp.to_u128() % 2 {
+        0 => u32::MAX - 1,
+        _ => u32::MAX,
+    })
+}
+
+impl Int {
+    /// Adds two integers, see [`Number::checked_add`] for the promotion
+    /// rules. The result is a [`Number`] because it may widen to [`u128`].
+    pub fn checked_add(self, rhs: Self) -> Result<Number, ValueError> {
+        Number::Int(self).checked_add(Number::Int(rhs))
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Result<Number, ValueError> {
+        Number::Int(self).checked_sub(Number::Int(rhs))
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Result<Number, ValueError> {
+        Number::Int(self).checked_mul(Number::Int(rhs))
+    }
+
+    pub fn checked_div(self, rhs: Self) -> Result<Number, ValueError> {
+        Number::Int(self).checked_div(Number::Int(rhs))
+    }
+
+    pub fn checked_rem(self, rhs: Self) -> Result<Number, ValueError> {
+        Number::Int(self).checked_rem(Number::Int(rhs))
+    }
+
+    pub fn checked_pow(self, exp: u32) -> Result<Number, ValueError> {
+        let base = Wide::from_i128(self.to_i128());
+        let result = base.pow(exp).ok_or_else(|| {
+            ValueError::overflow(format!("{} ** {}", self, exp), overflow_target(base.neg))
+        })?;
+
+        result
+            .narrow(true, int_bits(&self))
+            .ok_or_else(|| ValueError::overflow(format!("{} ** {}", self, exp), "i128"))
+    }
+
+    pub fn checked_neg(self) -> Result<Number, ValueError> {
+        Ok(Wide::from_i128(self.to_i128())
+            .neg()
+            .narrow(true, int_bits(&self))
+            .expect("the negation of an i128 fits in a u128"))
+    }
+}
+
+impl UInt {
+    /// Adds two unsigned integers, see [`Number::checked_add`] for the
+    /// promotion rules. The result is a [`Number`] because subtraction may
+    /// go negative.
+    pub fn checked_add(self, rhs: Self) -> Result<Number, ValueError> {
+        Number::UInt(self).checked_add(Number::UInt(rhs))
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Result<Number, ValueError> {
+        Number::UInt(self).checked_sub(Number::UInt(rhs))
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Result<Number, ValueError> {
+        Number::UInt(self).checked_mul(Number::UInt(rhs))
+    }
+
+    pub fn checked_div(self, rhs: Self) -> Result<Number, ValueError> {
+        Number::UInt(self).checked_div(Number::UInt(rhs))
+    }
+
+    pub fn checked_rem(self, rhs: Self) -> Result<Number, ValueError> {
+        Number::UInt(self).checked_rem(Number::UInt(rhs))
+    }
+
+    pub fn checked_pow(self, exp: u32) -> Result<Number, ValueError> {
+        Wide::from_u128(self.to_u128())
+            .pow(exp)
+            .and_then(|v| v.narrow(false, uint_bits(&self)))
+            .ok_or_else(|| ValueError::overflow(format!("{} ** {}", self, exp), "u128"))
+    }
+
+    /// Negates the integer as a signed one of the same width, widening to
+    /// [`i128`] when needed.
+    pub fn checked_neg(self) -> Result<Number, ValueError> {
+        let value = Wide::from_u128(self.to_u128()).neg();
+        value
+            .narrow(true, uint_bits(&self))
+            .ok_or_else(|| ValueError::overflow(format!("-{}", self), "i128"))
+    }
+}
+
+impl Float {
+    fn op(self, op: Op, rhs: Self) -> Self {
+        match (self, rhs) {
+            (Self::F32(a), Self::F32(b)) => Self::F32(match op {
+                Op::Add => a + b,
+                Op::Sub => a - b,
+                Op::Mul => a * b,
+                Op::Div => a / b,
+                Op::Rem => a % b,
+            }),
+            (a, b) => {
+                let (a, b) = (a.to_f64(), b.to_f64());
+                Self::F64(match op {
+                    Op::Add => a + b,
+                    Op::Sub => a - b,
+                    Op::Mul => a * b,
+                    Op::Div => a / b,
+                    Op::Rem => a % b,
+                })
+            }
+        }
+    }
+
+    fn is_finite(&self) -> bool {
+        match self {
+            Self::F64(v) => v.is_finite(),
+            Self::F32(v) => v.is_finite(),
+        }
+    }
+
+    /// Errors when `result` is infinite although its operands are finite.
+    fn check_finite(
+        result: Self,
+        operands: &[Self],
+        expr: impl FnOnce() -> String,
+    ) -> Result<Self, ValueError> {
+        if !result.is_finite() && operands.iter().all(Self::is_finite) {
+            return Err(ValueError::overflow(expr(), result.type_name()));
+        }
+
+        Ok(result)
+    }
+
+    fn checked_op(self, op: Op, rhs: Self) -> Result<Self, ValueError> {
+        if matches!(op, Op::Div | Op::Rem) && rhs.to_f64() == 0.0 {
+            return Err(ValueError::division_by_zero());
+        }
+
+        Self::check_finite(self.op(op, rhs), &[self, rhs], || {
+            format!("{} {} {}", self, op.symbol(), rhs)
+        })
+    }
+
+    /// Adds two floats. The result is [`f32`] only when both sides are, and
+    /// overflowing to infinity from finite operands is an error.
+    pub fn checked_add(self, rhs: Self) -> Result<Self, ValueError> {
+        self.checked_op(Op::Add, rhs)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, ValueError> {
+        self.checked_op(Op::Sub, rhs)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Result<Self, ValueError> {
+        self.checked_op(Op::Mul, rhs)
+    }
+
+    /// Divides by `rhs`, erroring on a zero divisor rather than producing
+    /// an infinity or NaN.
+    pub fn checked_div(self, rhs: Self) -> Result<Self, ValueError> {
+        self.checked_op(Op::Div, rhs)
+    }
+
+    pub fn checked_rem(self, rhs: Self) -> Result<Self, ValueError> {
+        self.checked_op(Op::Rem, rhs)
+    }
+
+    pub fn checked_pow(self, exp: Self) -> Result<Self, ValueError> {
+        let result = match (self, exp) {
+            (Self::F32(a), Self::F32(b)) => Self::F32(a.powf(b)),
+            (a, b) => Self::F64(a.to_f64().powf(b.to_f64())),
+        };
+
+        Self::check_finite(result, &[self, exp], || format!("{} ** {}", self, exp))
+    }
+
+    pub fn checked_neg(self) -> Result<Self, ValueError> {
+        Ok(-self)
+    }
+}
+
+impl Decimal {
+    fn checked_op(self, op: Op, rhs: Self) -> Result<Self, ValueError> {
+        let expr = || format!("{} {} {}", self, op.symbol(), rhs);
+        let overflow = || ValueError::overflow(expr(), "decimal");
+
+        if matches!(op, Op::Div | Op::Rem) && rhs.is_zero() {
+            return Err(ValueError::division_by_zero());
+        }
+
+        if op == Op::Div {
+            return match self.div(rhs) {
+                Some((quotient, true)) => Ok(quotient),
+                Some((_, false)) => Err(ValueError::precision_loss(expr(), "decimal")),
+                None => Err(overflow()),
+            };
+        }
+
+        if op == Op::Mul {
+            let coefficient = self
+                .coefficient()
+                .checked_mul(rhs.coefficient())
+                .ok_or_else(overflow)?;
+
+            // trailing zeros may be dropped to bring the scale back in range
+            let scale = self.scale() + rhs.scale();
+            let shift = scale.saturating_sub(Self::MAX_SCALE);
+
+            if coefficient % 10_i128.pow(shift) != 0 {
+                return Err(ValueError::precision_loss(expr(), "decimal"));
+            }
+
+            return Ok(Self::new(coefficient / 10_i128.pow(shift), scale - shift)
+                .expect("scale is in range"));
+        }
+
+        let scale = self.scale().max(rhs.scale());
+        let (a, b) = match (self.widen(scale), rhs.widen(scale)) {
+            (Some(a), Some(b)) => (a.coefficient(), b.coefficient()),
+            _ => return Err(overflow()),
+        };
+
+        let coefficient = match op {
+            Op::Add => a.checked_add(b),
+            Op::Sub => a.checked_sub(b),
+            _ => a.checked_rem(b),
+        };
+
+        Ok(Self::new(coefficient.ok_or_else(overflow)?, scale).expect("scale is in range"))
+    }
+
+    /// Long division that keeps going past the dividend's scale until the
+    /// remainder is zero or [`Decimal::MAX_SCALE`] digits are reached, then
+    /// truncates. The flag is whether nothing was truncated; `None` when the
+    /// quotient overflows.
+    fn div(self, rhs: Self) -> Option<(Self, bool)> {
+        let divisor = rhs.coefficient().unsigned_abs();
+        let dividend = self.coefficient().unsigned_abs();
+        let mut quotient = dividend / divisor;
+        let mut remainder = dividend % divisor;
+        let mut scale = self.scale() as i64 - rhs.scale() as i64;
+
+        while (remainder != 0 || scale < 0) && scale < Self::MAX_SCALE as i64 {
+            // the next digit of `remainder * 10 / divisor`, adding one
+            // remainder at a time so nothing overflows a u128
+            let mut digit = 0;
+            let mut next = 0_u128;
+
+            for _ in 0..10 {
+                next += remainder;
+
+                if next >= divisor {
+                    next -= divisor;
+                    digit += 1;
+                }
+            }
+
+            let shifted = quotient
+                .checked_mul(10)
+                .and_then(|v| v.checked_add(digit))
+                .filter(|v| *v <= i128::MAX.unsigned_abs());
+
+            let Some(shifted) = shifted else {
+                if scale < 0 {
+                    return None;
+                }
+
+                break;
+            };
+
+            quotient = shifted;
+            remainder = next;
+            scale += 1;
+        }
+
+        let coefficient = match self.is_negative() != rhs.is_negative() {
+            true => 0_i128.checked_sub_unsigned(quotient)?,
+            false => i128::try_from(quotient).ok()?,
+        };
+
+        Some((Self::new(coefficient, scale as u32).ok()?, remainder == 0))
+    }
+
+    /// Adds two decimals exactly, at the larger of the two scales.
+    pub fn checked_add(self, rhs: Self) -> Result<Self, ValueError> {
+        self.checked_op(Op::Add, rhs)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, ValueError> {
+        self.checked_op(Op::Sub, rhs)
+    }
+
+    /// Multiplies exactly, adding the scales. Errors rather than rounding
+    /// when the product needs more than [`Decimal::MAX_SCALE`] places.
+    pub fn checked_mul(self, rhs: Self) -> Result<Self, ValueError> {
+        self.checked_op(Op::Mul, rhs)
+    }
+
+    /// Divides exactly, keeping at least the scale of `self` less that of
+    /// `rhs`. Like [`Decimal::checked_mul`], errors rather than rounding
+    /// when the quotient needs more than [`Decimal::MAX_SCALE`] places, so
+    /// `1 / 4` is `0.25` but `1 / 3` is an error.
+    pub fn checked_div(self, rhs: Self) -> Result<Self, ValueError> {
+        self.checked_op(Op::Div, rhs)
+    }
+
+    /// The remainder of dividing by `rhs`, with the sign of `self`.
+    pub fn checked_rem(self, rhs: Self) -> Result<Self, ValueError> {
+        self.checked_op(Op::Rem, rhs)
+    }
+
+    pub fn checked_pow(self, exp: u32) -> Result<Self, ValueError> {
+        let mut result = Self::from(1);
+        let mut base = self;
+        let mut exp = exp;
+
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result = result.checked_mul(base)?;
+            }
+
+            exp /= 2;
+
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+
+        Ok(result)
+    }
+
+    pub fn checked_neg(self) -> Result<Self, ValueError> {
+        match self.coefficient().checked_neg() {
+            Some(v) => Ok(Self::new(v, self.scale()).expect("scale is in range")),
+            None => Err(ValueError::overflow(format!("-({})", self), "decimal")),
+        }
+    }
+}
+
+macro_rules! impl_ops {
+    ($T:ty => $Out:ty: $($Trait:ident $method:ident $op:ident),*) => {
+        $(
+            impl std::ops::$Trait for $T {
+                type Output = $Out;
+
+                fn $method(self, rhs: Self) -> $Out {
+                    <$Out>::from(self).op(Op::$op, <$Out>::from(rhs))
+                }
+            }
+        )*
+    };
+}
+
+impl_ops!(Number => Number: Add add Add, Sub sub Sub, Mul mul Mul, Div div Div, Rem rem Rem);
+impl_ops!(Int => Number: Add add Add, Sub sub Sub, Mul mul Mul, Div div Div, Rem rem Rem);
+impl_ops!(UInt => Number: Add add Add, Sub sub Sub, Mul mul Mul, Div div Div, Rem rem Rem);
+impl_ops!(Float => Float: Add add Add, Sub sub Sub, Mul mul Mul, Div div Div, Rem rem Rem);
+
+macro_rules! impl_decimal_ops {
+    ($($Trait:ident $method:ident $op:ident),*) => {
+        $(
+            impl std::ops::$Trait for Decimal {
+                type Output = Decimal;
+
+                fn $method(self, rhs: Self) -> Decimal {
+                    self.checked_op(Op::$op, rhs)
+                        .unwrap_or_else(|err| panic!("{err}"))
+                }
+            }
+        )*
+    };
+}
+
+impl_decimal_ops!(Add add Add, Sub sub Sub, Mul mul Mul, Div div Div, Rem rem Rem);
+
+impl std::ops::Neg for Number {
+    type Output = Number;
+
+    fn neg(self) -> Number {
+        self.checked_neg().unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl std::ops::Neg for Int {
+    type Output = Number;
+
+    fn neg(self) -> Number {
+        Number::Int(self).neg()
+    }
+}
+
+impl std::ops::Neg for UInt {
+    type Output = Number;
+
+    fn neg(self) -> Number {
+        Number::UInt(self).neg()
+    }
+}
+
+impl std::ops::Neg for Decimal {
+    type Output = Decimal;
+
+    fn neg(self) -> Decimal {
+        self.checked_neg().unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl std::ops::Neg for Float {
+    type Output = Float;
+
+    fn neg(self) -> Float {
+        match self {
+            Self::F64(v) => Self::F64(-v),
+            Self::F32(v) => Self::F32(-v),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_promotes_to_wider_width() {
+        let sum = Number::from_i8(1).checked_add(Number::from_i32(2)).unwrap();
+        assert!(sum.strict_eq(&Number::from_i32(3)));
+
+        let sum = Number::from_u8(1).checked_add(Number::from_u64(2)).unwrap();
+        assert!(sum.strict_eq(&Number::from_u64(3)));
+    }
+
+    #[test]
+    fn mixed_sign_promotes_to_signed() {
+        let sum = Number::from_i8(-1)
+            .checked_add(Number::from_u32(2))
+            .unwrap();
+        assert!(sum.strict_eq(&Number::from_i32(1)));
+    }
+
+    #[test]
+    fn overflow_widens() {
+        let sum = Number::from_i8(i8::MAX)
+            .checked_add(Number::from_i8(1))
+            .unwrap();
+        assert!(sum.strict_eq(&Number::from_i128(128)));
+
+        let diff = Number::from_u8(3).checked_sub(Number::from_u8(5)).unwrap();
+        assert!(diff.strict_eq(&Number::from_i128(-2)));
+
+        let sum = Number::from_i128(i128::MAX)
+            .checked_add(Number::from_i8(1))
+            .unwrap();
+        assert!(sum.strict_eq(&Number::from_u128(1 << 127)));
+    }
+
+    #[test]
+    fn overflow_errors_past_128_bits() {
+        let err = Number::from_u128(u128::MAX)
+            .checked_add(Number::from_u8(1))
+            .unwrap_err();
+        assert!(matches!(err, ValueError::Overflow { target: "u128", .. }));
+
+        let err = Number::from_i128(i128::MIN)
+            .checked_mul(Number::from_i8(2))
+            .unwrap_err();
+        assert!(matches!(err, ValueError::Overflow { target: "i128", .. }));
+    }
+
+    #[test]
+    fn exact_beyond_f64() {
+        let big = Number::from_i64(9_007_199_254_740_993);
+        let sum = big.checked_add(Number::from_i64(1)).unwrap();
+        assert_eq!(sum.to_i64(), 9_007_199_254_740_994);
+    }
+
+    #[test]
+    fn int_and_float_is_float() {
+        let sum = Number::from_i32(1)
+            .checked_add(Number::from_f32(0.5))
+            .unwrap();
+        assert!(sum.strict_eq(&Number::from_f64(1.5)));
+
+        let sum = Number::from_f32(1.0)
+            .checked_add(Number::from_f32(0.5))
+            .unwrap();
+        assert!(sum.strict_eq(&Number::from_f32(1.5)));
+    }
+
+    #[test]
+    fn div_and_rem() {
+        assert_eq!(
+            Number::from_i32(-7)
+                .checked_div(Number::from_i32(2))
+                .unwrap(),
+            -3_i32
+        );
+        assert_eq!(
+            Number::from_i32(-7)
+                .checked_rem(Number::from_i32(2))
+                .unwrap(),
+            -1_i32
+        );
+        assert_eq!(
+            Number::from_f64(7.0)
+                .checked_div(Number::from_i32(2))
+                .unwrap(),
+            3.5_f64
+        );
+    }
+
+    #[test]
+    fn division_by_zero() {
+        assert_eq!(
+            Number::from_i32(1).checked_div(Number::from_u8(0)),
+            Err(ValueError::DivisionByZero)
+        );
+        assert_eq!(
+            Number::from_f64(1.0).checked_rem(Number::from_f64(0.0)),
+            Err(ValueError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn float_overflow() {
+        let err = Number::from_f64(f64::MAX)
+            .checked_mul(Number::from_f64(2.0))
+            .unwrap_err();
+        assert!(matches!(err, ValueError::Overflow { target: "f64", .. }));
+    }
+
+    #[test]
+    fn pow() {
+        let v = Number::from_i32(2)
+            .checked_pow(Number::from_u8(10))
+            .unwrap();
+        assert!(v.strict_eq(&Number::from_i32(1024)));
+
+        let v = Number::from_i64(-3)
+            .checked_pow(Number::from_i64(3))
+            .unwrap();
+        assert_eq!(v, -27_i64);
+
+        let v = Number::from_i64(2)
+            .checked_pow(Number::from_i64(-1))
+            .unwrap();
+        assert!(v.strict_eq(&Number::from_f64(0.5)));
+
+        assert!(
+            Number::from_u8(2)
+                .checked_pow(Number::from_u32(200))
+                .is_err()
+        );
+
+        let v = Number::from_i32(-1)
+            .checked_pow(Number::from_u64(1 << 40))
+            .unwrap();
+        assert_eq!(v, 1_i32);
+
+        let v = Number::from_i32(-1)
+            .checked_pow(Number::from_u64((1 << 40) + 1))
+            .unwrap();
+        assert_eq!(v, -1_i32);
+    }
+
+    #[test]
+    fn neg() {
+        let v = Number::from_u8(200).checked_neg().unwrap();
+        assert!(v.strict_eq(&Number::from_i128(-200)));
+
+        let v = Number::from_i128(i128::MIN).checked_neg().unwrap();
+        assert!(v.strict_eq(&Number::from_u128(1 << 127)));
+
+        assert!((-Number::from_f64(1.5)).strict_eq(&Number::from_f64(-1.5)));
+    }
+
+    #[test]
+    fn std_ops() {
+        assert_eq!(Number::from_i32(2) + Number::from_u8(3), 5_i32);
+        assert_eq!(Int::I16(6) * Int::I16(7), 42_i16);
+        assert_eq!(UInt::U8(1) - UInt::U8(2), -1_i128);
+        assert_eq!(Float::F64(1.0) / Float::F64(0.0), f64::INFINITY);
+    }
+
+    fn dec(text: &str) -> Decimal {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn decimal_arithmetic() {
+        assert!((dec("0.1") + dec("0.2")).strict_eq(&dec("0.3")));
+        assert!((dec("1.10") - dec("2")).strict_eq(&dec("-0.90")));
+        assert!((dec("1.10") * dec("2")).strict_eq(&dec("2.20")));
+        assert!((dec("10.00") / dec("4")).strict_eq(&dec("2.50")));
+        assert!((dec("100") / dec("0.01")).strict_eq(&dec("10000")));
+        assert!((dec("7.5") % dec("2")).strict_eq(&dec("1.5")));
+        assert!((dec("-7.5") % dec("2")).strict_eq(&dec("-1.5")));
+        assert!((dec("-1") / dec("8")).strict_eq(&dec("-0.125")));
+        assert!(
+            (dec("1") / dec(&format!("1e{}", Decimal::MAX_SCALE)))
+                .strict_eq(&dec(&format!("1e-{}", Decimal::MAX_SCALE)))
+        );
+        assert!(dec("1.5").checked_pow(3).unwrap().strict_eq(&dec("3.375")));
+        assert!(-dec("1.5") == dec("-1.5"));
+    }
+
+    #[test]
+    fn decimal_promotion() {
+        let sum = Number::from(dec("0.5"))
+            .checked_add(Number::from_u8(1))
+            .unwrap();
+        assert!(sum.strict_eq(&dec("1.5").into()));
+
+        let sum = Number::from(dec("0.5"))
+            .checked_add(Number::from_f32(1.0))
+            .unwrap();
+        assert!(sum.strict_eq(&Number::from_f64(1.5)));
+
+        let pow = Number::from(dec("0.1"))
+            .checked_pow(Number::from_i32(2))
+            .unwrap();
+        assert!(pow.strict_eq(&dec("0.01").into()));
+    }
+
+    #[test]
+    fn decimal_errors() {
+        assert_eq!(
+            dec("1").checked_div(dec("0.00")),
+            Err(ValueError::division_by_zero())
+        );
+        assert!(matches!(
+            dec("1e37").checked_mul(dec("100")),
+            Err(ValueError::Overflow { .. })
+        ));
+        assert!(matches!(
+            dec("1e-20").checked_mul(dec("1e-20")),
+            Err(ValueError::PrecisionLoss { .. })
+        ));
+        assert!(matches!(
+            dec("1").checked_div(dec("3")),
+            Err(ValueError::PrecisionLoss { .. })
+        ));
+        assert!(matches!(
+            dec("-10").checked_div(dec("3")),
+            Err(ValueError::PrecisionLoss { .. })
+        ));
+        assert!(matches!(
+            dec("1e-30").checked_div(dec("1e10")),
+            Err(ValueError::PrecisionLoss { .. })
+        ));
+        assert!(
+            dec("1.0e-20")
+                .checked_mul(dec("1.00e-18"))
+                .unwrap()
+                .strict_eq(&"1e-38".parse().unwrap())
+        );
+        assert!(matches!(
+            Number::from(dec("1")).checked_add(Number::from_u128(u128::MAX)),
+            Err(ValueError::Overflow { .. })
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn std_ops_panic_on_int_division_by_zero() {
+        let _ = Number::from_i32(1) / Number::from_i32(0);
+    }
+}
@@ -1,7 +1,10 @@
-use crate::{ToValue, Value, num::Number};
+use crate::{
+    ToValue, Value, ValueError, ValueRef,
+    num::{Number, checked_uint, is_exact_u128},
+};
 
 /// An unsigned integer value that can hold a [`u8`], [`u16`], [`u32`], [`u64`], or [`u128`].
-#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Deserialize, serde::Serialize),
@@ -208,6 +211,21 @@ impl UInt {
         }
     }
 
+    /// Equal in value and width, unlike `==` which compares value only.
+    pub fn strict_eq(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other) && self == other
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::U8(_) => "u8",
+            Self::U16(_) => "u16",
+            Self::U32(_) => "u32",
+            Self::U64(_) => "u64",
+            Self::U128(_) => "u128",
+        }
+    }
+
     pub fn type_id(&self) -> std::any::TypeId {
         match self {
             Self::U8(_) => std::any::TypeId::of::<u8>(),
@@ -219,6 +237,94 @@ impl UInt {
     }
 }
 
+impl UInt {
+    pub fn checked_to_i8(&self) -> Result<i8, ValueError> {
+        checked_uint(self.to_u128(), "i8")
+    }
+
+    pub fn checked_to_i16(&self) -> Result<i16, ValueError> {
+        checked_uint(self.to_u128(), "i16")
+    }
+
+    pub fn checked_to_i32(&self) -> Result<i32, ValueError> {
+        checked_uint(self.to_u128(), "i32")
+    }
+
+    pub fn checked_to_i64(&self) -> Result<i64, ValueError> {
+        checked_uint(self.to_u128(), "i64")
+    }
+
+    pub fn checked_to_i128(&self) -> Result<i128, ValueError> {
+        checked_uint(self.to_u128(), "i128")
+    }
+
+    pub fn checked_to_isize(&self) -> Result<isize, ValueError> {
+        checked_uint(self.to_u128(), "isize")
+    }
+
+    pub fn checked_to_u8(&self) -> Result<u8, ValueError> {
+        checked_uint(self.to_u128(), "u8")
+    }
+
+    pub fn checked_to_u16(&self) -> Result<u16, ValueError> {
+        checked_uint(self.to_u128(), "u16")
+    }
+
+    pub fn checked_to_u32(&self) -> Result<u32, ValueError> {
+        checked_uint(self.to_u128(), "u32")
+    }
+
+    pub fn checked_to_u64(&self) -> Result<u64, ValueError> {
+        checked_uint(self.to_u128(), "u64")
+    }
+
+    pub fn checked_to_u128(&self) -> Result<u128, ValueError> {
+        checked_uint(self.to_u128(), "u128")
+    }
+
+    pub fn checked_to_usize(&self) -> Result<usize, ValueError> {
+        checked_uint(self.to_u128(), "usize")
+    }
+
+    pub fn checked_to_f32(&self) -> Result<f32, ValueError> {
+        let value = self.to_u128();
+        let rounded = value as f32;
+
+        if !is_exact_u128(value, rounded as f64) {
+            return Err(ValueError::precision_loss(self, "f32"));
+        }
+
+        Ok(rounded)
+    }
+
+    pub fn checked_to_f64(&self) -> Result<f64, ValueError> {
+        let value = self.to_u128();
+        let rounded = value as f64;
+
+        if !is_exact_u128(value, rounded) {
+            return Err(ValueError::precision_loss(self, "f64"));
+        }
+
+        Ok(rounded)
+    }
+}
+
+/// Integers compare by value, so `UInt::from_u8(1) == UInt::from_u32(1)`.
+/// Use [`UInt::strict_eq`] to also require the same width.
+impl PartialEq for UInt {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_u128() == other.to_u128()
+    }
+}
+
+impl Eq for UInt {}
+
+impl std::hash::Hash for UInt {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.to_u128().hash(state);
+    }
+}
+
 impl PartialOrd for UInt {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
@@ -233,61 +339,61 @@ impl Ord for UInt {
 
 impl PartialEq<u8> for UInt {
     fn eq(&self, other: &u8) -> bool {
-        matches!(self, Self::U8(v) if v == other)
+        self.to_u128() == *other as u128
     }
 }
 
 impl PartialEq<u16> for UInt {
     fn eq(&self, other: &u16) -> bool {
-        matches!(self, Self::U16(v) if v == other)
+        self.to_u128() == *other as u128
     }
 }
 
 impl PartialEq<u32> for UInt {
     fn eq(&self, other: &u32) -> bool {
-        matches!(self, Self::U32(v) if v == other)
+        self.to_u128() == *other as u128
     }
 }
 
 impl PartialEq<u64> for UInt {
     fn eq(&self, other: &u64) -> bool {
-        matches!(self, Self::U64(v) if v == other)
+        self.to_u128() == *other as u128
     }
 }
 
 impl PartialEq<u128> for UInt {
     fn eq(&self, other: &u128) -> bool {
-        matches!(self, Self::U128(v) if v == other)
+        self.to_u128() == *other
     }
 }
 
 impl PartialEq<u8> for Number {
     fn eq(&self, other: &u8) -> bool {
-        matches!(self, Self::UInt(v) if v == other)
+        *self == Number::from_u8(*other)
     }
 }
 
 impl PartialEq<u16> for Number {
     fn eq(&self, other: &u16) -> bool {
-        matches!(self, Self::UInt(v) if v == other)
+        *self == Number::from_u16(*other)
     }
 }
 
 impl PartialEq<u32> for Number {
     fn eq(&self, other: &u32) -> bool {
-        matches!(self, Self::UInt(v) if v == other)
+        *self == Number::from_u32(*other)
     }
 }
 
 impl PartialEq<u64> for Number {
     fn eq(&self, other: &u64) -> bool {
-        matches!(self, Self::UInt(v) if v == other)
+        *self == Number::from_u64(*other)
     }
 }
 
 impl PartialEq<u128> for Number {
     fn eq(&self, other: &u128) -> bool {
-        matches!(self, Self::UInt(v) if v == other)
+        *self == Number::from_u128(*other)
     }
 }
 
@@ -521,42 +627,70 @@ impl ToValue for UInt {
     fn to_value(&self) -> Value {
         Value::Number(Number::UInt(*self))
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Number(Number::UInt(*self))
+    }
 }
 
 impl ToValue for u8 {
     fn to_value(&self) -> Value {
         Value::from_u8(*self)
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Number(Number::from_u8(*self))
+    }
 }
 
 impl ToValue for u16 {
     fn to_value(&self) -> Value {
         Value::from_u16(*self)
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Number(Number::from_u16(*self))
+    }
 }
 
 impl ToValue for u32 {
     fn to_value(&self) -> Value {
         Value::from_u32(*self)
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Number(Number::from_u32(*self))
+    }
 }
 
 impl ToValue for u64 {
     fn to_value(&self) -> Value {
         Value::from_u64(*self)
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Number(Number::from_u64(*self))
+    }
 }
 
 impl ToValue for u128 {
     fn to_value(&self) -> Value {
         Value::from_u128(*self)
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Number(Number::from_u128(*self))
+    }
 }
 
 impl ToValue for usize {
     fn to_value(&self) -> Value {
         Value::from_usize(*self)
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Number(Number::from_usize(*self))
+    }
 }
 
 #[cfg(test)]
@@ -678,6 +812,32 @@ mod tests {
         assert_eq!(UInt::from_u128(1).type_id(), std::any::TypeId::of::<u128>());
     }
 
+    #[test]
+    fn checked_to_int() {
+        assert_eq!(UInt::from_u64(255).checked_to_u8(), Ok(255));
+        assert_eq!(
+            UInt::from_u16(256).checked_to_u8(),
+            Err(ValueError::overflow(256, "u8"))
+        );
+        assert_eq!(
+            UInt::from_u128(u128::MAX).checked_to_i128(),
+            Err(ValueError::overflow(u128::MAX, "i128"))
+        );
+        assert_eq!(UInt::from_u32(7).checked_to_isize(), Ok(7));
+    }
+
+    #[test]
+    fn checked_to_float() {
+        assert_eq!(
+            UInt::from_u64(1 << 53).checked_to_f64(),
+            Ok(9_007_199_254_740_992.0)
+        );
+        assert_eq!(
+            UInt::from_u64((1 << 53) + 1).checked_to_f64(),
+            Err(ValueError::precision_loss(9_007_199_254_740_993_u64, "f64"))
+        );
+    }
+
     #[cfg(feature = "serde")]
     mod serde {
         use super::*;
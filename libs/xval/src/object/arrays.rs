@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
-use crate::{Object, ToValue, Value};
+use crate::{Object, ToValue, Value, ValueRef};
 
-pub trait Array: Send + Sync {
+pub trait Array {
     fn name(&self) -> &str;
     fn type_id(&self) -> std::any::TypeId;
     fn len(&self) -> usize;
@@ -12,15 +12,37 @@ pub trait Array: Send + Sync {
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Mutable access to the items, `None` for read-only backings.
+    fn as_array_mut(&mut self) -> Option<&mut dyn ArrayMut> {
+        None
+    }
+
+    /// Copy the backing so a shared array can be cloned on write.
+    fn clone_array(&self) -> Option<Box<dyn Array + Send + Sync>> {
+        None
+    }
 }
 
-impl Array for Vec<Value> {
+/// In-place edits for [`Array`] backings that own their items as [`Value`]s.
+/// Indices are bounds checked by the caller.
+pub trait ArrayMut {
+    fn index_mut(&mut self, i: usize) -> Option<&mut Value>;
+    fn push(&mut self, value: Value);
+    fn insert(&mut self, i: usize, value: Value);
+    fn remove(&mut self, i: usize) -> Value;
+}
+
+/// Any `Vec` of convertible items is an array, so a `Vec<T>` field can be
+/// read through [`ToValue::as_value_ref`] without converting. Only
+/// `Vec<Value>` is mutable or can be copied into a [`Value`].
+impl<T: Clone + ToValue + 'static> Array for Vec<T> {
     fn name(&self) -> &str {
         "Vec"
     }
 
     fn type_id(&self) -> std::any::TypeId {
-        std::any::TypeId::of::<Vec<Value>>()
+        std::any::TypeId::of::<Self>()
     }
 
     fn len(&self) -> usize {
@@ -34,12 +56,46 @@ impl Array for Vec<Value> {
     fn index(&self, i: usize) -> Option<&dyn ToValue> {
         self.get(i).map(|v| v as &dyn ToValue)
     }
+
+    fn as_array_mut(&mut self) -> Option<&mut dyn ArrayMut> {
+        (self as &mut dyn std::any::Any)
+            .downcast_mut::<Vec<Value>>()
+            .map(|v| v as &mut dyn ArrayMut)
+    }
+
+    fn clone_array(&self) -> Option<Box<dyn Array + Send + Sync>> {
+        (self as &dyn std::any::Any)
+            .downcast_ref::<Vec<Value>>()
+            .map(|v| Box::new(v.clone()) as Box<dyn Array + Send + Sync>)
+    }
+}
+
+impl ArrayMut for Vec<Value> {
+    fn index_mut(&mut self, i: usize) -> Option<&mut Value> {
+        self.get_mut(i)
+    }
+
+    fn push(&mut self, value: Value) {
+        Vec::push(self, value)
+    }
+
+    fn insert(&mut self, i: usize, value: Value) {
+        Vec::insert(self, i, value)
+    }
+
+    fn remove(&mut self, i: usize) -> Value {
+        Vec::remove(self, i)
+    }
 }
 
 impl<T: Clone + ToValue + 'static> ToValue for Vec<T> {
     fn to_value(&self) -> Value {
         Value::from_array(self.iter().map(|v| v.to_value()).collect::<Vec<_>>())
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Array(self)
+    }
 }
 
 impl<T: Clone + ToValue + 'static> ToValue for [T] {
@@ -66,12 +122,12 @@ impl From<Vec<Value>> for Value {
     }
 }
 
-impl std::fmt::Debug for dyn Array {
+impl std::fmt::Debug for dyn Array + '_ {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut dbg = f.debug_list();
 
         for v in self.items() {
-            dbg.entry(&v.to_value());
+            dbg.entry(&v.as_value_ref());
         }
 
         dbg.finish()
@@ -79,7 +135,7 @@ impl std::fmt::Debug for dyn Array {
 }
 
 #[cfg(feature = "serde")]
-impl serde::Serialize for dyn Array {
+impl serde::Serialize for dyn Array + '_ {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -90,13 +146,30 @@ impl serde::Serialize for dyn Array {
         let mut seq = serializer.serialize_seq(Some(len))?;
 
         for item in self.items() {
-            seq.serialize_element(&item.to_value())?;
+            seq.serialize_element(&item.as_value_ref())?;
         }
 
         seq.end()
     }
 }
 
+/// The [`Object`] storage form, which only adds thread safety.
+impl std::fmt::Debug for dyn Array + Send + Sync + '_ {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self as &dyn Array, f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for dyn Array + Send + Sync + '_ {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        (self as &dyn Array).serialize(serializer)
+    }
+}
+
 pub struct ArrayIter<'a>(Box<dyn Iterator<Item = &'a dyn ToValue> + 'a>);
 
 impl<'a> ArrayIter<'a> {
@@ -225,4 +298,17 @@ mod tests {
         assert_eq!(inner.as_array().len(), 2);
         assert_eq!(inner.as_array().index(0).unwrap().to_value().to_i32(), 1);
     }
+
+    #[test]
+    fn vec_of_non_send_to_value() {
+        let items = vec![std::rc::Rc::new(1i32), std::rc::Rc::new(2)];
+        let v = items.to_value();
+        assert_eq!(v.as_array().index(1).unwrap().to_value().to_i32(), 2);
+
+        let ValueRef::Array(a) = items.as_value_ref() else {
+            panic!("expected a borrowed array");
+        };
+        assert_eq!(a.len(), 2);
+        assert!(ValueRef::Array(a).to_value().strict_eq(&v));
+    }
 }
@@ -1,32 +1,36 @@
 mod arrays;
+mod named;
+mod ordered_map;
 mod structs;
 mod tuples;
 
 pub use arrays::*;
+pub use named::*;
+pub use ordered_map::*;
 pub use structs::*;
 pub use tuples::*;
 
 use std::sync::Arc;
 
-use crate::{ToValue, Value};
+use crate::{ToValue, Value, ValueError, ValueRef};
 
 #[derive(Clone)]
 pub enum Object {
-    Struct(Arc<dyn Struct>),
-    Array(Arc<dyn Array>),
-    Tuple(Arc<dyn Tuple>),
+    Struct(Arc<dyn Struct + Send + Sync>),
+    Array(Arc<dyn Array + Send + Sync>),
+    Tuple(Arc<dyn Tuple + Send + Sync>),
 }
 
 impl Object {
-    pub fn from_struct<T: Struct + 'static>(value: T) -> Self {
+    pub fn from_struct<T: Struct + Send + Sync + 'static>(value: T) -> Self {
         Self::Struct(Arc::new(value))
     }
 
-    pub fn from_array<T: Array + 'static>(value: T) -> Self {
+    pub fn from_array<T: Array + Send + Sync + 'static>(value: T) -> Self {
         Self::Array(Arc::new(value))
     }
 
-    pub fn from_tuple<T: Tuple + 'static>(value: T) -> Self {
+    pub fn from_tuple<T: Tuple + Send + Sync + 'static>(value: T) -> Self {
         Self::Tuple(Arc::new(value))
     }
 
@@ -70,61 +74,144 @@ impl Object {
         self.len() == 0
     }
 
-    pub fn as_struct(&self) -> &Arc<dyn Struct> {
+    pub fn as_struct(&self) -> &Arc<dyn Struct + Send + Sync> {
         match self {
             Self::Struct(v) => v,
             v => panic!("expected Struct, received {}", v.name()),
         }
     }
 
-    pub fn as_array(&self) -> &Arc<dyn Array> {
+    pub fn as_array(&self) -> &Arc<dyn Array + Send + Sync> {
         match self {
             Self::Array(v) => v,
             v => panic!("expected Array, received {}", v.name()),
         }
     }
 
-    pub fn as_tuple(&self) -> &Arc<dyn Tuple> {
+    pub fn as_tuple(&self) -> &Arc<dyn Tuple + Send + Sync> {
         match self {
             Self::Tuple(v) => v,
             v => panic!("expected Tuple, received {}", v.name()),
         }
     }
+
+    pub fn try_as_struct(&self) -> Result<&Arc<dyn Struct + Send + Sync>, ValueError> {
+        match self {
+            Self::Struct(v) => Ok(v),
+            v => Err(ValueError::type_mismatch("struct", v.name())),
+        }
+    }
+
+    pub fn try_as_array(&self) -> Result<&Arc<dyn Array + Send + Sync>, ValueError> {
+        match self {
+            Self::Array(v) => Ok(v),
+            v => Err(ValueError::type_mismatch("array", v.name())),
+        }
+    }
+
+    pub fn try_as_tuple(&self) -> Result<&Arc<dyn Tuple + Send + Sync>, ValueError> {
+        match self {
+            Self::Tuple(v) => Ok(v),
+            v => Err(ValueError::type_mismatch("tuple", v.name())),
+        }
+    }
+
+    /// Structural equality using [`Value::strict_eq`] for every item.
+    pub fn strict_eq(&self, other: &Self) -> bool {
+        self.as_value_ref().strict_eq(&other.as_value_ref())
+    }
+
+    /// Mutable access to a struct backing. A backing shared with other
+    /// values is copied first, so edits never leak into them.
+    pub fn struct_mut(&mut self) -> Result<&mut dyn StructMut, ValueError> {
+        let v = match self {
+            Self::Struct(v) => v,
+            v => return Err(ValueError::type_mismatch("struct", v.name())),
+        };
+
+        if Arc::get_mut(v).is_none() {
+            let copy = v.clone_struct();
+            *v = Arc::from(copy.ok_or_else(|| ValueError::immutable(v.name()))?);
+        }
+
+        let inner = Arc::get_mut(v).expect("struct is unique after copy on write");
+
+        if inner.as_struct_mut().is_none() {
+            return Err(ValueError::immutable(inner.name()));
+        }
+
+        Ok(inner.as_struct_mut().unwrap())
+    }
+
+    /// Mutable access to an array backing, copied first when shared.
+    pub fn array_mut(&mut self) -> Result<&mut dyn ArrayMut, ValueError> {
+        let v = match self {
+            Self::Array(v) => v,
+            v => return Err(ValueError::type_mismatch("array", v.name())),
+        };
+
+        if Arc::get_mut(v).is_none() {
+            let copy = v.clone_array();
+            *v = Arc::from(copy.ok_or_else(|| ValueError::immutable(v.name()))?);
+        }
+
+        let inner = Arc::get_mut(v).expect("array is unique after copy on write");
+
+        if inner.as_array_mut().is_none() {
+            return Err(ValueError::immutable(inner.name()));
+        }
+
+        Ok(inner.as_array_mut().unwrap())
+    }
+
+    /// Mutable access to a tuple backing, copied first when shared.
+    pub fn tuple_mut(&mut self) -> Result<&mut dyn TupleMut, ValueError> {
+        let v = match self {
+            Self::Tuple(v) => v,
+            v => return Err(ValueError::type_mismatch("tuple", v.name())),
+        };
+
+        if Arc::get_mut(v).is_none() {
+            let copy = v.clone_tuple();
+            *v = Arc::from(copy.ok_or_else(|| ValueError::immutable(v.name()))?);
+        }
+
+        let inner = Arc::get_mut(v).expect("tuple is unique after copy on write");
+
+        if inner.as_tuple_mut().is_none() {
+            return Err(ValueError::immutable(inner.name()));
+        }
+
+        Ok(inner.as_tuple_mut().unwrap())
+    }
 }
 
 impl PartialEq for Object {
     fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (Self::Struct(a), Self::Struct(b)) => {
-                if a.len() != b.len() {
-                    return false;
-                }
-
-                a.items().all(|(k, v)| {
-                    b.field(k.clone())
-                        .is_some_and(|bv| v.to_value() == bv.to_value())
-                })
-            }
-            (Self::Array(a), Self::Array(b)) => {
-                if a.len() != b.len() {
-                    return false;
-                }
-
-                a.items()
-                    .zip(b.items())
-                    .all(|(av, bv)| av.to_value() == bv.to_value())
-            }
-            (Self::Tuple(a), Self::Tuple(b)) => {
-                if a.len() != b.len() {
-                    return false;
-                }
-
-                a.items()
-                    .zip(b.items())
-                    .all(|(av, bv)| av.to_value() == bv.to_value())
-            }
-            _ => false,
-        }
+        self.as_value_ref() == other.as_value_ref()
+    }
+}
+
+impl Eq for Object {}
+
+/// Objects order by kind (`Struct < Array < Tuple`), then item by item.
+/// Struct fields are compared as `(key, value)` pairs sorted by key, so
+/// the backing map type and its iteration order never matter.
+impl Ord for Object {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_value_ref().cmp(&other.as_value_ref())
+    }
+}
+
+impl PartialOrd for Object {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::hash::Hash for Object {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_value_ref().hash(state);
     }
 }
 
@@ -145,22 +232,24 @@ impl From<Object> for Value {
 }
 
 impl Value {
-    pub fn from_struct<T: Struct + 'static>(value: T) -> Self {
+    pub fn from_struct<T: Struct + Send + Sync + 'static>(value: T) -> Self {
         Self::Object(Object::from_struct(value))
     }
 
-    pub fn from_array<T: Array + 'static>(value: T) -> Self {
+    pub fn from_array<T: Array + Send + Sync + 'static>(value: T) -> Self {
         Self::Object(Object::from_array(value))
     }
 
-    pub fn from_tuple<T: Tuple + 'static>(value: T) -> Self {
+    pub fn from_tuple<T: Tuple + Send + Sync + 'static>(value: T) -> Self {
         Self::Object(Object::from_tuple(value))
     }
 }
 
+/// JSON-like text, see [`ValueDisplay`](crate::ValueDisplay). Use [`Object::name`] for the type
+/// name of the backing.
 impl std::fmt::Display for Object {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.name())
+        std::fmt::Display::fmt(&self.display(), f)
     }
 }
 
@@ -168,6 +257,14 @@ impl ToValue for Object {
     fn to_value(&self) -> Value {
         Value::Object(self.clone())
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        match self {
+            Self::Struct(v) => ValueRef::Struct(v.as_ref()),
+            Self::Array(v) => ValueRef::Array(v.as_ref()),
+            Self::Tuple(v) => ValueRef::Tuple(v.as_ref()),
+        }
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -195,7 +292,7 @@ impl<'de> serde::Deserialize<'de> for Object {
 }
 
 #[cfg(feature = "serde")]
-struct ObjectVisitor;
+pub(crate) struct ObjectVisitor;
 
 #[cfg(feature = "serde")]
 impl<'de> serde::de::Visitor<'de> for ObjectVisitor {
@@ -206,14 +303,10 @@ impl<'de> serde::de::Visitor<'de> for ObjectVisitor {
     }
 
     fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
-        use std::collections::HashMap;
-
-        let mut result = HashMap::new();
+        let mut result = OrderedMap::with_capacity(map.size_hint().unwrap_or(0));
 
         while let Some((key, value)) = map.next_entry::<String, Value>()? {
-            use crate::Ident;
-
-            result.insert(Ident::key(&key), value);
+            result.insert(crate::Ident::key(&key), value);
         }
 
         Ok(Object::Struct(Arc::new(result)))
@@ -292,6 +385,23 @@ mod tests {
             assert_eq!(t.name(), "Tuple3");
         }
 
+        #[test]
+        fn try_as() {
+            let s = Object::from_struct(sample_struct());
+            assert!(s.try_as_struct().is_ok());
+            assert_eq!(
+                s.try_as_array().unwrap_err(),
+                ValueError::type_mismatch("array", "HashMap")
+            );
+
+            let t = Object::from_tuple(sample_tuple());
+            assert!(t.try_as_tuple().is_ok());
+            assert_eq!(
+                t.try_as_struct().unwrap_err(),
+                ValueError::type_mismatch("struct", "Tuple3")
+            );
+        }
+
         #[test]
         fn type_id() {
             let s = Object::Struct(Arc::new(sample_struct()));
@@ -583,11 +693,14 @@ mod tests {
 
         #[test]
         fn display() {
-            let s = Object::from(sample_struct());
-            assert_eq!(s.to_string(), "HashMap");
+            let s = Object::from(HashMap::from([(Ident::key("a"), valueof!(1_i32))]));
+            assert_eq!(s.to_string(), r#"{"a":1}"#);
+            assert_eq!(s.name(), "HashMap");
 
             let a = Object::from(sample_array());
-            assert_eq!(a.to_string(), "Vec");
+            assert_eq!(a.to_string(), r#"[1,true,"hello"]"#);
+            assert_eq!(format!("{a:#}"), "[\n  1,\n  true,\n  \"hello\"\n]");
+            assert_eq!(a.name(), "Vec");
         }
 
         #[test]
@@ -0,0 +1,124 @@
+use std::borrow::Cow;
+
+use crate::{Ident, ToValue};
+
+use super::{Struct, StructIter, StructMut, Tuple, TupleIter, TupleMut};
+
+/// A [`Struct`] or [`Tuple`] backing reported under another name, such as
+/// the enum variant a derived value was built from. Everything else,
+/// mutability included, is delegated to the inner backing.
+#[derive(Debug, Clone)]
+pub struct Named<T> {
+    name: Cow<'static, str>,
+    inner: T,
+}
+
+impl<T> Named<T> {
+    pub fn new(name: impl Into<Cow<'static, str>>, inner: T) -> Self {
+        Self {
+            name: name.into(),
+            inner,
+        }
+    }
+
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: Struct + Clone + Send + Sync + 'static> Struct for Named<T> {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn type_id(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<Self>()
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn items(&self) -> StructIter<'_> {
+        self.inner.items()
+    }
+
+    fn field(&self, ident: Ident) -> Option<&dyn ToValue> {
+        self.inner.field(ident)
+    }
+
+    fn as_struct_mut(&mut self) -> Option<&mut dyn StructMut> {
+        self.inner.as_struct_mut()
+    }
+
+    fn clone_struct(&self) -> Option<Box<dyn Struct + Send + Sync>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+impl<T: Tuple + Clone + Send + Sync + 'static> Tuple for Named<T> {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn type_id(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<Self>()
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn items(&self) -> TupleIter<'_> {
+        self.inner.items()
+    }
+
+    fn index(&self, i: usize) -> Option<&dyn ToValue> {
+        self.inner.index(i)
+    }
+
+    fn as_tuple_mut(&mut self) -> Option<&mut dyn TupleMut> {
+        self.inner.as_tuple_mut()
+    }
+
+    fn clone_tuple(&self) -> Option<Box<dyn Tuple + Send + Sync>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::*;
+
+    #[test]
+    fn name_and_delegation() {
+        let map = BTreeMap::from([(Ident::key("a"), valueof!(1_i32))]);
+        let v = Value::from_struct(Named::new("Chat", map.clone()));
+
+        assert_eq!(v.as_object().name(), "Chat");
+        assert_eq!(v, Value::from_struct(map));
+
+        let t = Value::from_tuple(Named::new("Pair", (valueof!(1_i32), valueof!(true))));
+        assert_eq!(t.as_object().name(), "Pair");
+        assert_eq!(t.as_tuple().len(), 2);
+    }
+
+    #[test]
+    fn mutable_when_inner_is() {
+        let mut v = Value::from_struct(Named::new(
+            "Chat",
+            BTreeMap::from([(Ident::key("a"), valueof!(1_i32))]),
+        ));
+
+        let path = xpath::Path::parse("a").unwrap();
+        v.set(&path, valueof!(2_i32)).unwrap();
+        assert_eq!(v.get(&path).unwrap(), valueof!(2_i32));
+        assert_eq!(v.as_object().name(), "Chat");
+    }
+}
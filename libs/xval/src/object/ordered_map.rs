@@ -0,0 +1,261 @@
+use std::{collections::HashMap, sync::Arc};
+
+use crate::{Ident, Object, Struct, StructIter, StructMut, ToValue, Value, ValueRef};
+
+/// A [`Struct`] backing that keeps fields in insertion order, with O(1)
+/// lookup through a key index. Used for deserialized objects and
+/// [`valueof!`](crate::valueof) so their output is deterministic.
+#[derive(Debug, Default, Clone)]
+pub struct OrderedMap {
+    entries: Vec<(Ident, Value)>,
+    index: HashMap<Ident, usize>,
+}
+
+impl OrderedMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: Vec::with_capacity(capacity),
+            index: HashMap::with_capacity(capacity),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn contains_key(&self, ident: &Ident) -> bool {
+        self.index.contains_key(ident)
+    }
+
+    pub fn get(&self, ident: &Ident) -> Option<&Value> {
+        self.index.get(ident).map(|&i| &self.entries[i].1)
+    }
+
+    pub fn get_mut(&mut self, ident: &Ident) -> Option<&mut Value> {
+        self.index.get(ident).map(|&i| &mut self.entries[i].1)
+    }
+
+    /// Inserts a field, keeping the original position when the key already
+    /// exists and appending it otherwise.
+    pub fn insert(&mut self, ident: Ident, value: Value) -> Option<Value> {
+        match self.index.get(&ident) {
+            Some(&i) => Some(std::mem::replace(&mut self.entries[i].1, value)),
+            None => {
+                self.index.insert(ident.clone(), self.entries.len());
+                self.entries.push((ident, value));
+                None
+            }
+        }
+    }
+
+    /// Removes a field, shifting the later fields down so the order of the
+    /// rest is kept. This is O(n), unlike lookups.
+    pub fn remove(&mut self, ident: &Ident) -> Option<Value> {
+        let i = self.index.remove(ident)?;
+        let (_, value) = self.entries.remove(i);
+
+        for (ident, _) in &self.entries[i..] {
+            *self.index.get_mut(ident).expect("indexed key") -= 1;
+        }
+
+        Some(value)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &Ident> {
+        self.entries.iter().map(|(k, _)| k)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &Value> {
+        self.entries.iter().map(|(_, v)| v)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Ident, &Value)> {
+        self.entries.iter().map(|(k, v)| (k, v))
+    }
+}
+
+impl PartialEq for OrderedMap {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+impl FromIterator<(Ident, Value)> for OrderedMap {
+    fn from_iter<T: IntoIterator<Item = (Ident, Value)>>(iter: T) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl Extend<(Ident, Value)> for OrderedMap {
+    fn extend<T: IntoIterator<Item = (Ident, Value)>>(&mut self, iter: T) {
+        for (ident, value) in iter {
+            self.insert(ident, value);
+        }
+    }
+}
+
+impl IntoIterator for OrderedMap {
+    type Item = (Ident, Value);
+    type IntoIter = std::vec::IntoIter<(Ident, Value)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl Struct for OrderedMap {
+    fn name(&self) -> &str {
+        "OrderedMap"
+    }
+
+    fn type_id(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<Self>()
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn items(&self) -> StructIter<'_> {
+        StructIter::new(
+            self.entries
+                .iter()
+                .map(|(k, v)| (k.clone(), v as &dyn ToValue)),
+        )
+    }
+
+    fn field(&self, ident: Ident) -> Option<&dyn ToValue> {
+        self.get(&ident).map(|v| v as &dyn ToValue)
+    }
+
+    fn as_struct_mut(&mut self) -> Option<&mut dyn StructMut> {
+        Some(self)
+    }
+
+    fn clone_struct(&self) -> Option<Box<dyn Struct + Send + Sync>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+impl StructMut for OrderedMap {
+    fn field_mut(&mut self, ident: Ident) -> Option<&mut Value> {
+        self.get_mut(&ident)
+    }
+
+    fn insert(&mut self, ident: Ident, value: Value) -> Option<Value> {
+        OrderedMap::insert(self, ident, value)
+    }
+
+    fn remove(&mut self, ident: Ident) -> Option<Value> {
+        OrderedMap::remove(self, &ident)
+    }
+}
+
+impl From<OrderedMap> for Object {
+    fn from(value: OrderedMap) -> Self {
+        Self::Struct(Arc::new(value))
+    }
+}
+
+impl From<OrderedMap> for Value {
+    fn from(value: OrderedMap) -> Self {
+        Self::Object(Object::from(value))
+    }
+}
+
+impl ToValue for OrderedMap {
+    fn to_value(&self) -> Value {
+        Value::from_struct(self.clone())
+    }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Struct(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn sample() -> OrderedMap {
+        [
+            (Ident::key("z"), valueof!(1_i32)),
+            (Ident::key("a"), valueof!(2_i32)),
+            (Ident::key("m"), valueof!(3_i32)),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    fn keys(map: &OrderedMap) -> Vec<String> {
+        map.keys().map(|k| k.to_string()).collect()
+    }
+
+    #[test]
+    fn keeps_insertion_order() {
+        let map = sample();
+        assert_eq!(keys(&map), ["z", "a", "m"]);
+
+        let v = map.to_value();
+        let fields: Vec<_> = v.as_struct().items().map(|(k, _)| k.to_string()).collect();
+        assert_eq!(fields, ["z", "a", "m"]);
+    }
+
+    #[test]
+    fn insert_existing_keeps_position() {
+        let mut map = sample();
+        assert_eq!(
+            map.insert(Ident::key("z"), valueof!(9_i32)),
+            Some(valueof!(1_i32))
+        );
+        assert_eq!(keys(&map), ["z", "a", "m"]);
+        assert_eq!(map.get(&Ident::key("z")), Some(&valueof!(9_i32)));
+    }
+
+    #[test]
+    fn remove_reindexes() {
+        let mut map = sample();
+        assert_eq!(map.remove(&Ident::key("z")), Some(valueof!(1_i32)));
+        assert_eq!(keys(&map), ["a", "m"]);
+        assert_eq!(map.get(&Ident::key("m")), Some(&valueof!(3_i32)));
+
+        map.insert(Ident::key("z"), valueof!(4_i32));
+        assert_eq!(keys(&map), ["a", "m", "z"]);
+        assert_eq!(map.get(&Ident::key("z")), Some(&valueof!(4_i32)));
+        assert!(map.remove(&Ident::key("missing")).is_none());
+    }
+
+    #[test]
+    fn field() {
+        let map = sample();
+        assert_eq!(map.name(), "OrderedMap");
+        assert_eq!(Struct::field(&map, "a".into()).unwrap().to_value(), 2_i32);
+        assert!(Struct::field(&map, "missing".into()).is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_keeps_order() {
+        let json = r#"{"zeta":1,"alpha":{"y":2,"x":3},"mid":null}"#;
+        let v: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(v.as_struct().name(), "OrderedMap");
+        assert_eq!(serde_json::to_string(&v).unwrap(), json);
+    }
+
+    #[test]
+    fn valueof_keeps_order() {
+        let v = valueof!({ "b": 1_i32, "a": 2_i32 });
+        let fields: Vec<_> = v.as_struct().items().map(|(k, _)| k.to_string()).collect();
+        assert_eq!(fields, ["b", "a"]);
+    }
+}
@@ -3,9 +3,9 @@ use std::{
     sync::Arc,
 };
 
-use crate::{Ident, Object, ToValue, Value};
+use crate::{Ident, Object, ToValue, Value, ValueRef};
 
-pub trait Struct: Send + Sync {
+pub trait Struct {
     fn name(&self) -> &str;
     fn type_id(&self) -> std::any::TypeId;
     fn len(&self) -> usize;
@@ -15,9 +15,28 @@ pub trait Struct: Send + Sync {
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Mutable access to the fields, `None` for read-only backings
+    /// such as derived structs.
+    fn as_struct_mut(&mut self) -> Option<&mut dyn StructMut> {
+        None
+    }
+
+    /// Copy the backing so a shared struct can be cloned on write.
+    fn clone_struct(&self) -> Option<Box<dyn Struct + Send + Sync>> {
+        None
+    }
+}
+
+/// In-place edits for [`Struct`] backings that own their fields as [`Value`]s.
+pub trait StructMut {
+    fn field_mut(&mut self, ident: Ident) -> Option<&mut Value>;
+    fn insert(&mut self, ident: Ident, value: Value) -> Option<Value>;
+    fn remove(&mut self, ident: Ident) -> Option<Value>;
 }
 
-impl Struct for HashMap<Ident, Value> {
+/// Only a map of [`Value`]s is mutable or can be copied into a [`Value`].
+impl<T: Clone + ToValue + 'static> Struct for HashMap<Ident, T> {
     fn name(&self) -> &str {
         "HashMap"
     }
@@ -37,6 +56,32 @@ impl Struct for HashMap<Ident, Value> {
     fn field(&self, ident: Ident) -> Option<&dyn ToValue> {
         self.get(&ident).map(|v| v as &dyn ToValue)
     }
+
+    fn as_struct_mut(&mut self) -> Option<&mut dyn StructMut> {
+        (self as &mut dyn std::any::Any)
+            .downcast_mut::<HashMap<Ident, Value>>()
+            .map(|v| v as &mut dyn StructMut)
+    }
+
+    fn clone_struct(&self) -> Option<Box<dyn Struct + Send + Sync>> {
+        (self as &dyn std::any::Any)
+            .downcast_ref::<HashMap<Ident, Value>>()
+            .map(|v| Box::new(v.clone()) as Box<dyn Struct + Send + Sync>)
+    }
+}
+
+impl StructMut for HashMap<Ident, Value> {
+    fn field_mut(&mut self, ident: Ident) -> Option<&mut Value> {
+        self.get_mut(&ident)
+    }
+
+    fn insert(&mut self, ident: Ident, value: Value) -> Option<Value> {
+        HashMap::insert(self, ident, value)
+    }
+
+    fn remove(&mut self, ident: Ident) -> Option<Value> {
+        HashMap::remove(self, &ident)
+    }
 }
 
 impl From<HashMap<Ident, Value>> for Object {
@@ -59,9 +104,14 @@ impl<T: Clone + ToValue + 'static> ToValue for HashMap<Ident, T> {
                 .collect::<HashMap<Ident, Value>>(),
         )
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Struct(self)
+    }
 }
 
-impl Struct for BTreeMap<Ident, Value> {
+/// Only a map of [`Value`]s is mutable or can be copied into a [`Value`].
+impl<T: Clone + ToValue + 'static> Struct for BTreeMap<Ident, T> {
     fn name(&self) -> &str {
         "BTreeMap"
     }
@@ -81,6 +131,32 @@ impl Struct for BTreeMap<Ident, Value> {
     fn field(&self, ident: Ident) -> Option<&dyn ToValue> {
         self.get(&ident).map(|v| v as &dyn ToValue)
     }
+
+    fn as_struct_mut(&mut self) -> Option<&mut dyn StructMut> {
+        (self as &mut dyn std::any::Any)
+            .downcast_mut::<BTreeMap<Ident, Value>>()
+            .map(|v| v as &mut dyn StructMut)
+    }
+
+    fn clone_struct(&self) -> Option<Box<dyn Struct + Send + Sync>> {
+        (self as &dyn std::any::Any)
+            .downcast_ref::<BTreeMap<Ident, Value>>()
+            .map(|v| Box::new(v.clone()) as Box<dyn Struct + Send + Sync>)
+    }
+}
+
+impl StructMut for BTreeMap<Ident, Value> {
+    fn field_mut(&mut self, ident: Ident) -> Option<&mut Value> {
+        self.get_mut(&ident)
+    }
+
+    fn insert(&mut self, ident: Ident, value: Value) -> Option<Value> {
+        BTreeMap::insert(self, ident, value)
+    }
+
+    fn remove(&mut self, ident: Ident) -> Option<Value> {
+        BTreeMap::remove(self, &ident)
+    }
 }
 
 impl From<BTreeMap<Ident, Value>> for Object {
@@ -103,14 +179,18 @@ impl<T: Clone + ToValue + 'static> ToValue for BTreeMap<Ident, T> {
                 .collect::<BTreeMap<Ident, Value>>(),
         )
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Struct(self)
+    }
 }
 
-impl std::fmt::Debug for dyn Struct {
+impl std::fmt::Debug for dyn Struct + '_ {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut dbg = f.debug_struct(self.name());
 
         for (k, v) in self.items() {
-            dbg.field(&k.to_string(), &v.to_value());
+            dbg.field(&k.to_string(), &v.as_value_ref());
         }
 
         dbg.finish()
@@ -118,7 +198,7 @@ impl std::fmt::Debug for dyn Struct {
 }
 
 #[cfg(feature = "serde")]
-impl serde::Serialize for dyn Struct {
+impl serde::Serialize for dyn Struct + '_ {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -129,13 +209,30 @@ impl serde::Serialize for dyn Struct {
         let mut map = serializer.serialize_map(None)?;
 
         for (ident, value) in iter {
-            map.serialize_entry(&ident.to_string(), &value.to_value())?;
+            map.serialize_entry(&ident.to_string(), &value.as_value_ref())?;
         }
 
         map.end()
     }
 }
 
+/// The [`Object`] storage form, which only adds thread safety.
+impl std::fmt::Debug for dyn Struct + Send + Sync + '_ {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self as &dyn Struct, f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for dyn Struct + Send + Sync + '_ {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        (self as &dyn Struct).serialize(serializer)
+    }
+}
+
 pub struct StructIter<'a>(Box<dyn Iterator<Item = (Ident, &'a dyn ToValue)> + 'a>);
 
 impl<'a> StructIter<'a> {
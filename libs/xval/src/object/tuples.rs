@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
-use crate::{Object, ToValue, Value};
+use crate::{Object, ToValue, Value, ValueRef};
 
-pub trait Tuple: Send + Sync {
+pub trait Tuple {
     fn name(&self) -> &str;
     fn type_id(&self) -> std::any::TypeId;
     fn len(&self) -> usize;
@@ -12,14 +12,30 @@ pub trait Tuple: Send + Sync {
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Mutable access to the items, `None` for read-only backings.
+    fn as_tuple_mut(&mut self) -> Option<&mut dyn TupleMut> {
+        None
+    }
+
+    /// Copy the backing so a shared tuple can be cloned on write.
+    fn clone_tuple(&self) -> Option<Box<dyn Tuple + Send + Sync>> {
+        None
+    }
+}
+
+/// In-place edits for [`Tuple`] backings that own their items as [`Value`]s.
+/// Tuples have a fixed length, so only existing items can be replaced.
+pub trait TupleMut {
+    fn index_mut(&mut self, i: usize) -> Option<&mut Value>;
 }
 
-impl std::fmt::Debug for dyn Tuple {
+impl std::fmt::Debug for dyn Tuple + '_ {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut dbg = f.debug_tuple(self.name());
 
         for v in self.items() {
-            dbg.field(&v.to_value());
+            dbg.field(&v.as_value_ref());
         }
 
         dbg.finish()
@@ -27,7 +43,7 @@ impl std::fmt::Debug for dyn Tuple {
 }
 
 #[cfg(feature = "serde")]
-impl serde::Serialize for dyn Tuple {
+impl serde::Serialize for dyn Tuple + '_ {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -38,13 +54,30 @@ impl serde::Serialize for dyn Tuple {
         let mut tup = serializer.serialize_tuple(len)?;
 
         for item in self.items() {
-            tup.serialize_element(&item.to_value())?;
+            tup.serialize_element(&item.as_value_ref())?;
         }
 
         tup.end()
     }
 }
 
+/// The [`Object`] storage form, which only adds thread safety.
+impl std::fmt::Debug for dyn Tuple + Send + Sync + '_ {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self as &dyn Tuple, f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for dyn Tuple + Send + Sync + '_ {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        (self as &dyn Tuple).serialize(serializer)
+    }
+}
+
 pub struct TupleIter<'a>(Box<dyn Iterator<Item = &'a dyn ToValue> + 'a>);
 
 impl<'a> TupleIter<'a> {
@@ -61,9 +94,73 @@ impl<'a> Iterator for TupleIter<'a> {
     }
 }
 
+/// A [`Tuple`] backing of any length, for tuples decoded from data whose
+/// arity is only known at runtime.
+#[derive(Debug, Default, Clone)]
+pub struct TupleVec(Vec<Value>);
+
+impl TupleVec {
+    pub fn new(items: Vec<Value>) -> Self {
+        Self(items)
+    }
+
+    pub fn into_inner(self) -> Vec<Value> {
+        self.0
+    }
+}
+
+impl Tuple for TupleVec {
+    fn name(&self) -> &str {
+        "TupleVec"
+    }
+
+    fn type_id(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<Self>()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn items(&self) -> TupleIter<'_> {
+        TupleIter::new(self.0.iter().map(|v| v as &dyn ToValue))
+    }
+
+    fn index(&self, i: usize) -> Option<&dyn ToValue> {
+        self.0.get(i).map(|v| v as &dyn ToValue)
+    }
+
+    fn as_tuple_mut(&mut self) -> Option<&mut dyn TupleMut> {
+        Some(self)
+    }
+
+    fn clone_tuple(&self) -> Option<Box<dyn Tuple + Send + Sync>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+impl TupleMut for TupleVec {
+    fn index_mut(&mut self, i: usize) -> Option<&mut Value> {
+        self.0.get_mut(i)
+    }
+}
+
+impl From<Vec<Value>> for TupleVec {
+    fn from(value: Vec<Value>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<TupleVec> for Value {
+    fn from(value: TupleVec) -> Self {
+        Self::Object(Object::Tuple(Arc::new(value)))
+    }
+}
+
 macro_rules! impl_tuple {
-    ($name:expr, $($idx:tt),+) => {
-        impl Tuple for ( $(impl_tuple!(@replace $idx Value),)+ ) {
+    ($name:expr, $($idx:tt $T:ident),+) => {
+        /// Only a tuple of [`Value`]s is mutable or can be copied into a [`Value`].
+        impl<$($T: Clone + ToValue + 'static),+> Tuple for ($($T,)+) {
             fn name(&self) -> &str {
                 $name
             }
@@ -88,6 +185,37 @@ macro_rules! impl_tuple {
                     _ => None,
                 }
             }
+
+            fn as_tuple_mut(&mut self) -> Option<&mut dyn TupleMut> {
+                (self as &mut dyn std::any::Any)
+                    .downcast_mut::<( $(impl_tuple!(@replace $idx Value),)+ )>()
+                    .map(|v| v as &mut dyn TupleMut)
+            }
+
+            fn clone_tuple(&self) -> Option<Box<dyn Tuple + Send + Sync>> {
+                (self as &dyn std::any::Any)
+                    .downcast_ref::<( $(impl_tuple!(@replace $idx Value),)+ )>()
+                    .map(|v| Box::new(v.clone()) as Box<dyn Tuple + Send + Sync>)
+            }
+        }
+
+        impl TupleMut for ( $(impl_tuple!(@replace $idx Value),)+ ) {
+            fn index_mut(&mut self, i: usize) -> Option<&mut Value> {
+                match i {
+                    $( $idx => Some(&mut self.$idx), )+
+                    _ => None,
+                }
+            }
+        }
+
+        impl<$($T: Clone + ToValue + 'static),+> ToValue for ($($T,)+) {
+            fn to_value(&self) -> Value {
+                Value::from_tuple(($(self.$idx.to_value(),)+))
+            }
+
+            fn as_value_ref(&self) -> ValueRef<'_> {
+                ValueRef::Tuple(self)
+            }
         }
 
         impl From<( $(impl_tuple!(@replace $idx Value),)+ )> for Object {
@@ -106,41 +234,18 @@ macro_rules! impl_tuple {
     (@count $($t:tt),+) => { 0usize $(+ impl_tuple!(@replace $t 1usize))+ };
 }
 
-impl_tuple!("Tuple1", 0);
-impl_tuple!("Tuple2", 0, 1);
-impl_tuple!("Tuple3", 0, 1, 2);
-impl_tuple!("Tuple4", 0, 1, 2, 3);
-impl_tuple!("Tuple5", 0, 1, 2, 3, 4);
-impl_tuple!("Tuple6", 0, 1, 2, 3, 4, 5);
-impl_tuple!("Tuple7", 0, 1, 2, 3, 4, 5, 6);
-impl_tuple!("Tuple8", 0, 1, 2, 3, 4, 5, 6, 7);
-impl_tuple!("Tuple9", 0, 1, 2, 3, 4, 5, 6, 7, 8);
-impl_tuple!("Tuple10", 0, 1, 2, 3, 4, 5, 6, 7, 8, 9);
-impl_tuple!("Tuple11", 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
-impl_tuple!("Tuple12", 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
-
-macro_rules! impl_tuple_to_value {
-    ($($idx:tt $T:ident),+) => {
-        impl<$($T: Clone + ToValue + 'static),+> ToValue for ($($T,)+) {
-            fn to_value(&self) -> Value {
-                Value::from_tuple(($(self.$idx.to_value(),)+))
-            }
-        }
-    };
-}
-
-impl_tuple_to_value!(0 A);
-impl_tuple_to_value!(0 A, 1 B);
-impl_tuple_to_value!(0 A, 1 B, 2 C);
-impl_tuple_to_value!(0 A, 1 B, 2 C, 3 D);
-impl_tuple_to_value!(0 A, 1 B, 2 C, 3 D, 4 E);
-impl_tuple_to_value!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
-impl_tuple_to_value!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
-impl_tuple_to_value!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
-impl_tuple_to_value!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I);
-impl_tuple_to_value!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J);
-impl_tuple_to_value!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K);
-impl_tuple_to_value!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L);
+impl_tuple!("Tuple1", 0 A);
+impl_tuple!("Tuple2", 0 A, 1 B);
+impl_tuple!("Tuple3", 0 A, 1 B, 2 C);
+impl_tuple!("Tuple4", 0 A, 1 B, 2 C, 3 D);
+impl_tuple!("Tuple5", 0 A, 1 B, 2 C, 3 D, 4 E);
+impl_tuple!("Tuple6", 0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+impl_tuple!("Tuple7", 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+impl_tuple!("Tuple8", 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+impl_tuple!("Tuple9", 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I);
+impl_tuple!("Tuple10", 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J);
+impl_tuple!("Tuple11", 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K);
+impl_tuple!("Tuple12", 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L);
 
 #[cfg(test)]
 mod tests {
@@ -236,4 +341,17 @@ mod tests {
         assert_eq!(inner.as_array().len(), 2);
         assert_eq!(t.index(1).unwrap().to_value().to_bool(), true);
     }
+
+    #[test]
+    fn tuple_of_non_send_to_value() {
+        let pair = (std::rc::Rc::new(1i32), 2i32);
+        let v = pair.to_value();
+        assert_eq!(v.as_tuple().index(0).unwrap().to_value().to_i32(), 1);
+
+        let ValueRef::Tuple(t) = pair.as_value_ref() else {
+            panic!("expected a borrowed tuple");
+        };
+        assert_eq!(t.len(), 2);
+        assert!(ValueRef::Tuple(t).to_value().strict_eq(&v));
+    }
 }
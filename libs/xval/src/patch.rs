@@ -0,0 +1,520 @@
+use xpath::Path;
+
+use crate::{Ident, Value, ValueError};
+
+/// A single JSON Patch (RFC 6902) operation, addressed by [`Path`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "op", rename_all = "lowercase")
+)]
+pub enum Operation {
+    /// Insert `value`, shifting array items right; a final `-` segment
+    /// appends to an array.
+    Add {
+        #[cfg_attr(feature = "serde", serde(with = "pointer"))]
+        path: Path,
+        value: Value,
+    },
+    Remove {
+        #[cfg_attr(feature = "serde", serde(with = "pointer"))]
+        path: Path,
+    },
+    Replace {
+        #[cfg_attr(feature = "serde", serde(with = "pointer"))]
+        path: Path,
+        value: Value,
+    },
+    Move {
+        #[cfg_attr(feature = "serde", serde(with = "pointer"))]
+        from: Path,
+        #[cfg_attr(feature = "serde", serde(with = "pointer"))]
+        path: Path,
+    },
+    Copy {
+        #[cfg_attr(feature = "serde", serde(with = "pointer"))]
+        from: Path,
+        #[cfg_attr(feature = "serde", serde(with = "pointer"))]
+        path: Path,
+    },
+    /// Fails the patch unless the value at `path` equals `value`.
+    Test {
+        #[cfg_attr(feature = "serde", serde(with = "pointer"))]
+        path: Path,
+        value: Value,
+    },
+}
+
+impl Operation {
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Add { path, .. }
+            | Self::Remove { path }
+            | Self::Replace { path, .. }
+            | Self::Move { path, .. }
+            | Self::Copy { path, .. }
+            | Self::Test { path, .. } => path,
+        }
+    }
+}
+
+/// An ordered list of [`Operation`]s, applied all-or-nothing by
+/// [`Value::apply`]. With `serde` it reads and writes standard JSON Patch
+/// documents, with paths as JSON Pointers.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Patch(Vec<Operation>);
+
+impl Patch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn push(&mut self, op: Operation) -> &mut Self {
+        self.0.push(op);
+        self
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Operation> {
+        self.0.iter()
+    }
+}
+
+impl From<Vec<Operation>> for Patch {
+    fn from(value: Vec<Operation>) -> Self {
+        Self(value)
+    }
+}
+
+impl FromIterator<Operation> for Patch {
+    fn from_iter<T: IntoIterator<Item = Operation>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for Patch {
+    type Item = Operation;
+    type IntoIter = std::vec::IntoIter<Operation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Patch {
+    type Item = &'a Operation;
+    type IntoIter = std::slice::Iter<'a, Operation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// The operations that turn `from` into `to`, such that
+/// `from.apply(&diff(from, to))` is [`Value::strict_eq`] to `to`. Struct
+/// fields are matched by key and a removed field whose value reappears
+/// under a new key becomes a `move`; arrays are compared index by index.
+pub fn diff(from: &Value, to: &Value) -> Patch {
+    let mut patch = Patch::new();
+    diff_at(&mut Path::default(), from, to, &mut patch);
+    patch
+}
+
+fn diff_at(path: &mut Path, from: &Value, to: &Value, patch: &mut Patch) {
+    if from.strict_eq(to) {
+        return;
+    }
+
+    if from.is_struct() && to.is_struct() {
+        let (a, b) = (from.as_struct(), to.as_struct());
+        let mut added: Vec<_> = b
+            .items()
+            .filter(|(k, _)| a.field(k.clone()).is_none())
+            .map(|(k, v)| (k, v.to_value()))
+            .collect();
+
+        for (ident, value) in a.items() {
+            let value = value.to_value();
+
+            match b.field(ident.clone()) {
+                Some(other) => {
+                    path.push(ident);
+                    diff_at(path, &value, &other.to_value(), patch);
+                    path.pop();
+                }
+                None => match added.iter().position(|(_, v)| v.strict_eq(&value)) {
+                    Some(i) => {
+                        let (to, _) = added.remove(i);
+                        patch.push(Operation::Move {
+                            from: path.child(ident),
+                            path: path.child(to),
+                        });
+                    }
+                    None => {
+                        patch.push(Operation::Remove {
+                            path: path.child(ident),
+                        });
+                    }
+                },
+            }
+        }
+
+        for (ident, value) in added {
+            patch.push(Operation::Add {
+                path: path.child(ident),
+                value,
+            });
+        }
+
+        return;
+    }
+
+    if from.is_array() && to.is_array() {
+        let (a, b) = (from.as_array(), to.as_array());
+
+        for (i, (av, bv)) in a.items().zip(b.items()).enumerate() {
+            path.push(i);
+            diff_at(path, &av.to_value(), &bv.to_value(), patch);
+            path.pop();
+        }
+
+        for (i, value) in b.items().enumerate().skip(a.len()) {
+            patch.push(Operation::Add {
+                path: path.child(i),
+                value: value.to_value(),
+            });
+        }
+
+        for i in (b.len()..a.len()).rev() {
+            patch.push(Operation::Remove {
+                path: path.child(i),
+            });
+        }
+
+        return;
+    }
+
+    if from.is_tuple() && to.is_tuple() && from.len() == to.len() {
+        let (a, b) = (from.as_tuple(), to.as_tuple());
+
+        for (i, (av, bv)) in a.items().zip(b.items()).enumerate() {
+            path.push(i);
+            diff_at(path, &av.to_value(), &bv.to_value(), patch);
+            path.pop();
+        }
+
+        return;
+    }
+
+    patch.push(Operation::Replace {
+        path: path.clone(),
+        value: to.clone(),
+    });
+}
+
+impl Value {
+    /// Apply `patch` with RFC 6902 semantics. Operations run in order and
+    /// the patch is atomic: on error `self` is left unchanged.
+    pub fn apply(&mut self, patch: &Patch) -> Result<(), ValueError> {
+        let mut value = self.clone();
+
+        for op in patch {
+            value.apply_op(op)?;
+        }
+
+        *self = value;
+        Ok(())
+    }
+
+    fn apply_op(&mut self, op: &Operation) -> Result<(), ValueError> {
+        match op {
+            Operation::Add { path, value } => self.patch_add(path, value.clone()),
+            Operation::Remove { path } => self.patch_remove(path).map(|_| ()),
+            Operation::Replace { path, value } => {
+                self.get(path)
+                    .ok_or_else(|| ValueError::not_found(path.clone()))?;
+                self.set(path, value.clone()).map(|_| ())
+            }
+            Operation::Move { from, path } => {
+                if from == path {
+                    return self
+                        .get(from)
+                        .map(|_| ())
+                        .ok_or_else(|| ValueError::not_found(from.clone()));
+                }
+
+                if is_prefix(from, path) {
+                    return Err(ValueError::invalid_move(from.clone(), path.clone()));
+                }
+
+                let value = self.patch_remove(from)?;
+                self.patch_add(path, value)
+            }
+            Operation::Copy { from, path } => {
+                let value = self
+                    .get(from)
+                    .ok_or_else(|| ValueError::not_found(from.clone()))?;
+                self.patch_add(path, value)
+            }
+            Operation::Test { path, value } => match self.get(path) {
+                Some(v) if v == *value => Ok(()),
+                _ => Err(ValueError::test_failed(path.clone())),
+            },
+        }
+    }
+
+    fn patch_add(&mut self, path: &Path, value: Self) -> Result<(), ValueError> {
+        let Some(ident) = path.last() else {
+            *self = value;
+            return Ok(());
+        };
+
+        let mut parent = path.clone();
+        parent.pop();
+        let parent = self.get_mut(&parent)?;
+
+        match ident {
+            Ident::Key(key) if &**key == "-" && parent.is_array() => parent.push(value),
+            Ident::Index(i) if parent.is_array() => parent.insert(*i, value),
+            _ => parent
+                .set(&Path::default().child(ident.clone()), value)
+                .map(|_| ()),
+        }
+    }
+
+    fn patch_remove(&mut self, path: &Path) -> Result<Self, ValueError> {
+        self.remove(path)?
+            .ok_or_else(|| ValueError::not_found(path.clone()))
+    }
+}
+
+/// Whether `path` lies strictly inside `parent`.
+fn is_prefix(parent: &Path, path: &Path) -> bool {
+    parent.len() < path.len() && parent.iter().zip(path.iter()).all(|(a, b)| a == b)
+}
+
+/// JSON Pointer (RFC 6901) strings for the `path` and `from` members.
+#[cfg(feature = "serde")]
+mod pointer {
+    use serde::Deserialize;
+    use xpath::Path;
+
+    pub fn serialize<S: serde::Serializer>(path: &Path, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&path.to_json_pointer())
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Path, D::Error> {
+        let value = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        Path::from_json_pointer(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn path(src: &str) -> xpath::Path {
+        xpath::Path::parse(src).unwrap()
+    }
+
+    fn round_trip(from: Value, to: Value) -> Patch {
+        let patch = diff(&from, &to);
+        let mut value = from;
+        value.apply(&patch).unwrap();
+        assert!(value.strict_eq(&to), "{:?} != {:?}", value, to);
+        patch
+    }
+
+    #[test]
+    fn diff_equal_is_empty() {
+        let v = valueof!({ "a": [1_i32, { "b": true }] });
+        assert!(diff(&v, &v.clone()).is_empty());
+    }
+
+    #[test]
+    fn diff_struct() {
+        let patch = round_trip(
+            valueof!({ "a": 1_i32, "b": 2_i32, "c": { "d": 1_i32 } }),
+            valueof!({ "a": 1_i32, "c": { "d": 2_i32 }, "e": 3_i32 }),
+        );
+
+        assert_eq!(
+            patch,
+            Patch::from(vec![
+                Operation::Remove { path: path("b") },
+                Operation::Replace {
+                    path: path("c/d"),
+                    value: valueof!(2_i32)
+                },
+                Operation::Add {
+                    path: path("e"),
+                    value: valueof!(3_i32)
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn diff_detects_move() {
+        let patch = round_trip(
+            valueof!({ "old": { "x": 1_i32 } }),
+            valueof!({ "new": { "x": 1_i32 } }),
+        );
+
+        assert_eq!(
+            patch,
+            Patch::from(vec![Operation::Move {
+                from: path("old"),
+                path: path("new")
+            }])
+        );
+    }
+
+    #[test]
+    fn diff_array() {
+        round_trip(
+            valueof!([1_i32, 2_i32]),
+            valueof!([1_i32, 3_i32, 4_i32, 5_i32]),
+        );
+        let patch = round_trip(valueof!([1_i32, 2_i32, 3_i32]), valueof!([1_i32]));
+        assert_eq!(
+            patch,
+            Patch::from(vec![
+                Operation::Remove { path: path("2") },
+                Operation::Remove { path: path("1") },
+            ])
+        );
+    }
+
+    #[test]
+    fn diff_type_change_replaces() {
+        round_trip(valueof!({ "a": [1_i32] }), valueof!({ "a": "x" }));
+        round_trip(valueof!(1_i32), valueof!(1_i64));
+        round_trip(valueof!((1_i32, 2_i32)), valueof!((1_i32, 3_i32)));
+        round_trip(valueof!((1_i32,)), valueof!((1_i32, 3_i32)));
+    }
+
+    #[test]
+    fn apply_add_inserts_into_arrays() {
+        let mut v = valueof!({ "list": [1_i32, 3_i32] });
+        v.apply(&Patch::from(vec![
+            Operation::Add {
+                path: path("list/1"),
+                value: valueof!(2_i32),
+            },
+            Operation::Add {
+                path: path("list/-"),
+                value: valueof!(4_i32),
+            },
+        ]))
+        .unwrap();
+
+        assert_eq!(v, valueof!({ "list": [1_i32, 2_i32, 3_i32, 4_i32] }));
+    }
+
+    #[test]
+    fn apply_copy_and_move() {
+        let mut v = valueof!({ "a": { "b": 1_i32 }, "c": [] });
+        v.apply(&Patch::from(vec![
+            Operation::Copy {
+                from: path("a/b"),
+                path: path("c/0"),
+            },
+            Operation::Move {
+                from: path("a"),
+                path: path("d"),
+            },
+        ]))
+        .unwrap();
+
+        assert_eq!(v, valueof!({ "c": [1_i32], "d": { "b": 1_i32 } }));
+
+        let err = v.apply(&Patch::from(vec![Operation::Move {
+            from: path("d"),
+            path: path("d/e"),
+        }]));
+        assert_eq!(err, Err(ValueError::invalid_move(path("d"), path("d/e"))));
+    }
+
+    #[test]
+    fn apply_is_atomic() {
+        let mut v = valueof!({ "a": 1_i32 });
+        let result = v.apply(&Patch::from(vec![
+            Operation::Replace {
+                path: path("a"),
+                value: valueof!(2_i32),
+            },
+            Operation::Test {
+                path: path("a"),
+                value: valueof!(3_i32),
+            },
+        ]));
+
+        assert_eq!(result, Err(ValueError::test_failed(path("a"))));
+        assert_eq!(v, valueof!({ "a": 1_i32 }));
+    }
+
+    #[test]
+    fn apply_requires_existing_targets() {
+        let mut v = valueof!({ "a": 1_i32 });
+
+        for op in [
+            Operation::Remove { path: path("b") },
+            Operation::Replace {
+                path: path("b"),
+                value: valueof!(1_i32),
+            },
+            Operation::Add {
+                path: path("b/c"),
+                value: valueof!(1_i32),
+            },
+        ] {
+            assert!(v.apply(&Patch::from(vec![op.clone()])).is_err(), "{op:?}");
+        }
+    }
+
+    #[test]
+    fn test_compares_numbers_by_value() {
+        let mut v = valueof!({ "a": 1_i32 });
+        let patch = Patch::from(vec![Operation::Test {
+            path: path("a"),
+            value: valueof!(1.0_f64),
+        }]);
+        assert!(v.apply(&patch).is_ok());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json_patch() {
+        let json = r#"[{"op":"add","path":"/a~1b/0","value":1},{"op":"remove","path":"/x"},{"op":"move","from":"/m~0n","path":""},{"op":"test","path":"/t","value":"ok"}]"#;
+        let patch: Patch = serde_json::from_str(json).unwrap();
+
+        let mut a_b = xpath::Path::default();
+        a_b.push("a/b").push(0);
+        assert_eq!(patch.iter().next().unwrap().path(), &a_b);
+        assert_eq!(
+            patch.iter().nth(2),
+            Some(&Operation::Move {
+                from: path("m~n"),
+                path: xpath::Path::default(),
+            })
+        );
+        assert_eq!(serde_json::to_string(&patch).unwrap(), json);
+        assert!(serde_json::from_str::<Patch>(r#"[{"op":"remove","path":"x"}]"#).is_err());
+    }
+}
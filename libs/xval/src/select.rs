@@ -0,0 +1,250 @@
+use xpath::{Path, Segment, Selector};
+
+use crate::{Ident, ToValue, Value, ValueRef};
+
+impl Value {
+    /// Every node `selector` matches, in item order, paired with its
+    /// concrete [`Path`]. A plain path selects at most one node.
+    pub fn select(&self, selector: &Selector) -> impl Iterator<Item = (Path, Value)> + use<> {
+        self.as_value_ref().select(selector)
+    }
+}
+
+impl ValueRef<'_> {
+    /// Like [`Value::select`], converting only the matched nodes.
+    pub fn select(&self, selector: &Selector) -> impl Iterator<Item = (Path, Value)> + use<> {
+        let mut matches = vec![];
+        select_at(
+            &mut Path::default(),
+            self,
+            selector.segments(),
+            &mut matches,
+        );
+        matches.into_iter()
+    }
+}
+
+fn select_at(
+    path: &mut Path,
+    value: &ValueRef<'_>,
+    segments: &[Segment],
+    out: &mut Vec<(Path, Value)>,
+) {
+    if let ValueRef::Owned(v) = value {
+        return select_at(path, &v.as_value_ref(), segments, out);
+    }
+
+    let Some((segment, rest)) = segments.split_first() else {
+        out.push((path.clone(), value.to_value()));
+        return;
+    };
+
+    if *segment == Segment::Descendants {
+        select_at(path, value, rest, out);
+
+        for (ident, item) in children(value, &Segment::Wildcard) {
+            path.push(ident);
+            select_at(path, &item.as_value_ref(), segments, out);
+            path.pop();
+        }
+
+        return;
+    }
+
+    for (ident, item) in children(value, segment) {
+        path.push(ident);
+        select_at(path, &item.as_value_ref(), rest, out);
+        path.pop();
+    }
+}
+
+/// The items of a struct, array or tuple that one segment picks, with the
+/// ident each is reached by.
+fn children<'a>(value: &ValueRef<'a>, segment: &Segment) -> Vec<(Ident, &'a dyn ToValue)> {
+    let len = match value {
+        ValueRef::Struct(v) => match segment {
+            Segment::Wildcard => return v.items().collect(),
+            Segment::Ident(Ident::Index(i)) if v.field(Ident::Index(*i)).is_none() => {
+                let ident = Ident::Key(i.to_string().into());
+                return v
+                    .field(ident.clone())
+                    .map(|v| (ident, v))
+                    .into_iter()
+                    .collect();
+            }
+            Segment::Ident(ident) => {
+                return v
+                    .field(ident.clone())
+                    .map(|v| (ident.clone(), v))
+                    .into_iter()
+                    .collect();
+            }
+            Segment::Union(members) => {
+                return members.iter().flat_map(|m| children(value, m)).collect();
+            }
+            _ => return vec![],
+        },
+        ValueRef::Array(v) => v.len(),
+        ValueRef::Tuple(v) => v.len(),
+        _ => return vec![],
+    };
+
+    let indices = match segment {
+        Segment::Ident(Ident::Index(i)) => vec![*i],
+        Segment::Ident(Ident::Key(_)) | Segment::Descendants => vec![],
+        Segment::Wildcard => (0..len).collect(),
+        Segment::FromEnd(n) => len.checked_sub(*n).into_iter().collect(),
+        Segment::Slice { start, end, step } => slice(len, *start, *end, *step),
+        Segment::Union(members) => {
+            return members.iter().flat_map(|m| children(value, m)).collect();
+        }
+    };
+
+    indices
+        .into_iter()
+        .filter_map(|i| {
+            let ident = Ident::Index(i);
+            value.item(&ident).map(|v| (ident, v))
+        })
+        .collect()
+}
+
+/// The indices a `start:end:step` slice covers in a sequence of `len`
+/// items, with Python's clamping and negative bounds.
+fn slice(len: usize, start: Option<isize>, end: Option<isize>, step: isize) -> Vec<usize> {
+    let len = len as isize;
+    let bound = |i: isize| if i < 0 { i + len } else { i };
+
+    let (mut i, end) = match step > 0 {
+        true => (
+            start.map_or(0, bound).clamp(0, len),
+            end.map_or(len, bound).clamp(0, len),
+        ),
+        false => (
+            start.map_or(len - 1, bound).clamp(-1, len - 1),
+            end.map_or(-1, bound).clamp(-1, len - 1),
+        ),
+    };
+
+    let mut indices = vec![];
+
+    while (step > 0 && i < end) || (step < 0 && i > end) {
+        indices.push(i as usize);
+
+        match i.checked_add(step) {
+            Some(n) => i = n,
+            None => break,
+        }
+    }
+
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use xpath::{Path, Selector};
+
+    use crate::*;
+
+    fn select(value: &Value, selector: &str) -> Vec<(String, Value)> {
+        value
+            .select(&Selector::parse(selector).unwrap())
+            .map(|(path, v)| (path.to_string(), v))
+            .collect()
+    }
+
+    fn paths(value: &Value, selector: &str) -> Vec<String> {
+        select(value, selector)
+            .into_iter()
+            .map(|(p, _)| p)
+            .collect()
+    }
+
+    fn data() -> Value {
+        valueof!({
+            "users": [
+                { "name": "alice", "email": "a@x.io" },
+                { "name": "bob" },
+                { "name": "carol", "email": "c@x.io" },
+            ],
+            "pair": (1_i32, 2_i32),
+        })
+    }
+
+    #[test]
+    fn wildcard() {
+        assert_eq!(
+            select(&data(), "users/*/email"),
+            [
+                ("users/0/email".to_string(), valueof!("a@x.io")),
+                ("users/2/email".to_string(), valueof!("c@x.io")),
+            ]
+        );
+        assert_eq!(paths(&data(), "pair/*"), ["pair/0", "pair/1"]);
+        assert_eq!(paths(&data(), "*").len(), 2);
+    }
+
+    #[test]
+    fn descendants() {
+        assert_eq!(
+            paths(&data(), "**/name"),
+            ["users/0/name", "users/1/name", "users/2/name"]
+        );
+        assert_eq!(paths(&data(), "**").len(), 13);
+        assert_eq!(paths(&data(), "**").first().unwrap(), "");
+    }
+
+    #[test]
+    fn indices_and_slices() {
+        let value = valueof!([0_i32, 1_i32, 2_i32, 3_i32, 4_i32, 5_i32]);
+        let items = |selector: &str| -> Vec<i32> {
+            select(&value, selector)
+                .into_iter()
+                .map(|(_, v)| v.to_i32())
+                .collect()
+        };
+
+        assert_eq!(items("-1"), [5]);
+        assert_eq!(items("-6"), [0]);
+        assert!(items("-7").is_empty());
+        assert!(items("6").is_empty());
+        assert_eq!(items("1:5:2"), [1, 3]);
+        assert_eq!(items("-2:"), [4, 5]);
+        assert_eq!(items(":2"), [0, 1]);
+        assert_eq!(items("::-2"), [5, 3, 1]);
+        assert_eq!(items("4:1:-1"), [4, 3, 2]);
+        assert_eq!(items("-100:100"), [0, 1, 2, 3, 4, 5]);
+        assert_eq!(items("[0,-1,1:3]"), [0, 5, 1, 2]);
+        assert_eq!(items(&format!("0::{}", isize::MAX)), [0]);
+        assert_eq!(items(&format!("::{}", isize::MIN)), [5]);
+        assert_eq!(paths(&value, "-1"), ["5"]);
+    }
+
+    #[test]
+    fn structs() {
+        let value = valueof!({ "a": 1_i32, "b": 2_i32, "404": 3_i32, "*": 4_i32 });
+
+        assert_eq!(paths(&value, "[b,a]"), ["b", "a"]);
+        assert_eq!(paths(&value, "404"), ["'404'"]);
+        assert_eq!(paths(&value, "'*'"), ["*"]);
+        assert!(paths(&value, "-1").is_empty());
+        assert!(paths(&value, "0:2").is_empty());
+        assert!(paths(&value, "a/b").is_empty());
+    }
+
+    #[test]
+    fn paths_resolve() {
+        let value = data();
+
+        for (path, v) in value.select(&Selector::parse("**/[0,email]").unwrap()) {
+            assert_eq!(value.get(&path), Some(v));
+        }
+
+        let path = Path::parse("users/1/name").unwrap();
+        assert_eq!(
+            select(&value, &path.to_string()),
+            [("users/1/name".to_string(), valueof!("bob"))]
+        );
+        assert_eq!(value.select(&Selector::from(path)).count(), 1);
+    }
+}
@@ -0,0 +1,490 @@
+use serde::ser::{self, Serialize};
+
+use crate::{Ident, OrderedMap, ToValueError, Value};
+
+/// Convert any `T: Serialize` into a [`Value`], keeping numeric widths.
+///
+/// Sequences become arrays, tuples and tuple structs become tuples, and
+/// maps and structs become structs. Enum variants are externally tagged:
+/// a unit variant is its name as a string, every other variant is a
+/// single-field struct keyed by the variant name.
+pub fn to_value<T: Serialize + ?Sized>(value: &T) -> Result<Value, ToValueError> {
+    value.serialize(ValueSerializer)
+}
+
+/// A [`serde::Serializer`] whose output is a [`Value`].
+pub struct ValueSerializer;
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = Value;
+    type Error = ToValueError;
+
+    type SerializeSeq = SerializeArray;
+    type SerializeTuple = SerializeTuple;
+    type SerializeTupleStruct = SerializeTuple;
+    type SerializeTupleVariant = SerializeTupleVariant;
+    type SerializeMap = SerializeMap;
+    type SerializeStruct = SerializeStruct;
+    type SerializeStructVariant = SerializeStructVariant;
+
+    fn serialize_bool(self, v: bool) -> Result<Value, ToValueError> {
+        Ok(Value::from_bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value, ToValueError> {
+        Ok(Value::from_i8(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value, ToValueError> {
+        Ok(Value::from_i16(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value, ToValueError> {
+        Ok(Value::from_i32(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value, ToValueError> {
+        Ok(Value::from_i64(v))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Value, ToValueError> {
+        Ok(Value::from_i128(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value, ToValueError> {
+        Ok(Value::from_u8(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value, ToValueError> {
+        Ok(Value::from_u16(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value, ToValueError> {
+        Ok(Value::from_u32(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value, ToValueError> {
+        Ok(Value::from_u64(v))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Value, ToValueError> {
+        Ok(Value::from_u128(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value, ToValueError> {
+        Ok(Value::from_f32(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value, ToValueError> {
+        Ok(Value::from_f64(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value, ToValueError> {
+        Ok(Value::from_string(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value, ToValueError> {
+        Ok(Value::from_str(v))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value, ToValueError> {
+        Ok(Value::from_bytes(v))
+    }
+
+    fn serialize_none(self) -> Result<Value, ToValueError> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Value, ToValueError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value, ToValueError> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value, ToValueError> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<Value, ToValueError> {
+        Ok(Value::from_str(variant))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Value, ToValueError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, ToValueError> {
+        Ok(tagged(variant, value.serialize(self)?))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeArray, ToValueError> {
+        Ok(SerializeArray {
+            items: Vec::with_capacity(len.unwrap_or_default()),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeTuple, ToValueError> {
+        Ok(SerializeTuple {
+            items: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeTuple, ToValueError> {
+        self.serialize_tuple(len)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeTupleVariant, ToValueError> {
+        Ok(SerializeTupleVariant {
+            variant,
+            items: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<SerializeMap, ToValueError> {
+        Ok(SerializeMap {
+            fields: OrderedMap::new(),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<SerializeStruct, ToValueError> {
+        Ok(SerializeStruct {
+            fields: OrderedMap::new(),
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<SerializeStructVariant, ToValueError> {
+        Ok(SerializeStructVariant {
+            variant,
+            fields: OrderedMap::new(),
+        })
+    }
+}
+
+pub struct SerializeArray {
+    items: Vec<Value>,
+}
+
+impl ser::SerializeSeq for SerializeArray {
+    type Ok = Value;
+    type Error = ToValueError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ToValueError> {
+        self.items.push(to_value(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, ToValueError> {
+        Ok(Value::from_array(self.items))
+    }
+}
+
+pub struct SerializeTuple {
+    items: Vec<Value>,
+}
+
+impl ser::SerializeTuple for SerializeTuple {
+    type Ok = Value;
+    type Error = ToValueError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ToValueError> {
+        self.items.push(to_value(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, ToValueError> {
+        Ok(tuple(self.items))
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeTuple {
+    type Ok = Value;
+    type Error = ToValueError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ToValueError> {
+        ser::SerializeTuple::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Value, ToValueError> {
+        ser::SerializeTuple::end(self)
+    }
+}
+
+pub struct SerializeTupleVariant {
+    variant: &'static str,
+    items: Vec<Value>,
+}
+
+impl ser::SerializeTupleVariant for SerializeTupleVariant {
+    type Ok = Value;
+    type Error = ToValueError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ToValueError> {
+        self.items.push(to_value(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, ToValueError> {
+        Ok(tagged(self.variant, tuple(self.items)))
+    }
+}
+
+pub struct SerializeMap {
+    fields: OrderedMap,
+    key: Option<Ident>,
+}
+
+impl ser::SerializeMap for SerializeMap {
+    type Ok = Value;
+    type Error = ToValueError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), ToValueError> {
+        self.key = Some(match to_value(key)? {
+            Value::String(v) => Ident::key(v.as_str()),
+            Value::Number(v) if !v.is_float() => Ident::key(&v.to_string()),
+            v => {
+                return Err(ToValueError::new(format!(
+                    "map keys must be strings or integers, received {}",
+                    v.type_name()
+                )));
+            }
+        });
+
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ToValueError> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| ToValueError::new("map value serialized before its key"))?;
+
+        self.fields.insert(key, to_value(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, ToValueError> {
+        Ok(Value::from_struct(self.fields))
+    }
+}
+
+pub struct SerializeStruct {
+    fields: OrderedMap,
+}
+
+impl ser::SerializeStruct for SerializeStruct {
+    type Ok = Value;
+    type Error = ToValueError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), ToValueError> {
+        self.fields.insert(Ident::key(key), to_value(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, ToValueError> {
+        Ok(Value::from_struct(self.fields))
+    }
+}
+
+pub struct SerializeStructVariant {
+    variant: &'static str,
+    fields: OrderedMap,
+}
+
+impl ser::SerializeStructVariant for SerializeStructVariant {
+    type Ok = Value;
+    type Error = ToValueError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), ToValueError> {
+        self.fields.insert(Ident::key(key), to_value(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, ToValueError> {
+        Ok(tagged(self.variant, Value::from_struct(self.fields)))
+    }
+}
+
+/// `{ variant: value }`, the externally tagged form of an enum variant.
+fn tagged(variant: &str, value: Value) -> Value {
+    let mut fields = OrderedMap::new();
+    fields.insert(Ident::key(variant), value);
+    Value::from_struct(fields)
+}
+
+macro_rules! tuple_from_vec {
+    ($items:ident, $($len:literal => ($($v:ident),+)),+ $(,)?) => {
+        match $items.len() {
+            $(
+                $len => {
+                    let [$($v),+]: [Value; $len] = $items.try_into().unwrap();
+                    Value::from_tuple(($($v,)+))
+                }
+            )+
+            _ => Value::from_array($items),
+        }
+    };
+}
+
+/// Tuples of up to 12 items map onto the built-in tuple backings, longer
+/// ones fall back to an array.
+fn tuple(items: Vec<Value>) -> Value {
+    tuple_from_vec!(
+        items,
+        1 => (a),
+        2 => (a, b),
+        3 => (a, b, c),
+        4 => (a, b, c, d),
+        5 => (a, b, c, d, e),
+        6 => (a, b, c, d, e, f),
+        7 => (a, b, c, d, e, f, g),
+        8 => (a, b, c, d, e, f, g, h),
+        9 => (a, b, c, d, e, f, g, h, i),
+        10 => (a, b, c, d, e, f, g, h, i, j),
+        11 => (a, b, c, d, e, f, g, h, i, j, k),
+        12 => (a, b, c, d, e, f, g, h, i, j, k, l),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde::Serialize;
+
+    use crate::{ext::StructExt, *};
+
+    #[derive(Serialize)]
+    struct User {
+        name: String,
+        age: u8,
+        score: f32,
+        tags: Vec<String>,
+        pair: (i64, bool),
+        nickname: Option<String>,
+    }
+
+    #[derive(Serialize)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Line(i32, i32),
+        Rect { w: u16, h: u16 },
+    }
+
+    #[test]
+    fn primitives_keep_width() {
+        assert_eq!(to_value(&1_i8).unwrap().type_name(), "i8");
+        assert_eq!(to_value(&1_u128).unwrap().type_name(), "u128");
+        assert_eq!(to_value(&1.5_f32).unwrap().type_name(), "f32");
+        assert_eq!(to_value(&1.5_f64).unwrap().type_name(), "f64");
+        assert_eq!(to_value(&'x').unwrap().as_str(), "x");
+        assert!(to_value(&()).unwrap().is_null());
+        assert!(to_value(&None::<i32>).unwrap().is_null());
+    }
+
+    #[test]
+    fn structs() {
+        let v = to_value(&User {
+            name: "alice".to_string(),
+            age: 30,
+            score: 9.5,
+            tags: vec!["a".to_string()],
+            pair: (1, true),
+            nickname: None,
+        })
+        .unwrap();
+
+        let user = v.as_struct();
+        assert_eq!(user.len(), 6);
+        assert_eq!(user.get("name").unwrap().to_value().as_str(), "alice");
+        assert_eq!(user.get("age").unwrap().to_value().type_name(), "u8");
+        assert_eq!(user.get("score").unwrap().to_value().type_name(), "f32");
+        assert!(user.get("tags").unwrap().to_value().is_array());
+        assert_eq!(user.get("pair").unwrap().to_value().type_name(), "Tuple2");
+        assert!(user.get("nickname").unwrap().to_value().is_null());
+    }
+
+    #[test]
+    fn maps() {
+        let mut map = HashMap::new();
+        map.insert(1_u32, "one");
+
+        let v = to_value(&map).unwrap();
+        assert_eq!(v.as_struct().get("1").unwrap().to_value().as_str(), "one");
+
+        let mut map = HashMap::new();
+        map.insert((1, 2), "pair");
+        assert!(to_value(&map).is_err());
+    }
+
+    #[test]
+    fn enums() {
+        assert_eq!(to_value(&Shape::Empty).unwrap(), valueof!("Empty"));
+        assert_eq!(
+            to_value(&Shape::Circle(1.0)).unwrap(),
+            valueof!({ "Circle": 1.0_f64 })
+        );
+
+        let line = to_value(&Shape::Line(1, 2)).unwrap();
+        let fields = line.as_struct().get("Line").unwrap().to_value();
+        assert_eq!(fields.type_name(), "Tuple2");
+
+        assert_eq!(
+            to_value(&Shape::Rect { w: 2, h: 3 }).unwrap(),
+            valueof!({ "Rect": { "w": 2_u16, "h": 3_u16 } })
+        );
+    }
+
+    #[test]
+    fn long_tuples_are_arrays() {
+        let v = to_value(&(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13)).unwrap();
+        assert!(v.is_array());
+        assert_eq!(v.as_array().len(), 13);
+    }
+}
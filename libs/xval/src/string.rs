@@ -1,4 +1,4 @@
-use crate::{ToValue, Value};
+use crate::{ToValue, Value, ValueRef};
 
 /// A type-safe wrapper around a [`str`] value.
 #[repr(transparent)]
@@ -128,24 +128,40 @@ impl ToValue for Str {
     fn to_value(&self) -> Value {
         Value::String(self.clone())
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::String(self.as_str())
+    }
 }
 
 impl ToValue for str {
     fn to_value(&self) -> Value {
         Value::from_str(self)
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::String(self)
+    }
 }
 
 impl ToValue for &str {
     fn to_value(&self) -> Value {
         Value::from_str(self)
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::String(self)
+    }
 }
 
 impl ToValue for String {
     fn to_value(&self) -> Value {
         Value::from_string(self.clone())
     }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::String(self)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,212 @@
+use crate::{
+    ToValue, Value, ValueError, ValueRef,
+    time::{
+        Cursor, Duration, NANOS_PER_DAY, Time, civil_from_days, days_from_civil, days_in_month,
+        impl_serde_str,
+    },
+};
+
+/// A calendar date in the proleptic Gregorian calendar, between the years
+/// 0000 and 9999.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    days: i32,
+}
+
+impl Date {
+    /// 0000-01-01.
+    pub const MIN: Self = Self { days: -719_528 };
+    /// 9999-12-31.
+    pub const MAX: Self = Self { days: 2_932_896 };
+
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Result<Self, ValueError> {
+        if !(0..=9999).contains(&year)
+            || !(1..=12).contains(&month)
+            || day == 0
+            || day > days_in_month(year as i64, month)
+        {
+            return Err(ValueError::parse(
+                "date",
+                format!("{:04}-{:02}-{:02}", year, month, day),
+            ));
+        }
+
+        Ok(Self {
+            days: days_from_civil(year as i64, month, day) as i32,
+        })
+    }
+
+    /// The date `days` after 1970-01-01.
+    pub fn from_unix_days(days: i64) -> Result<Self, ValueError> {
+        if !(Self::MIN.days as i64..=Self::MAX.days as i64).contains(&days) {
+            return Err(ValueError::overflow(days, "date"));
+        }
+
+        Ok(Self { days: days as i32 })
+    }
+
+    /// Parses an ISO 8601 calendar date, `YYYY-MM-DD`.
+    pub fn parse(value: &str) -> Result<Self, ValueError> {
+        let mut cursor = Cursor::new(value);
+        let date = Self::parse_from(&mut cursor).filter(|_| cursor.is_done());
+        date.ok_or_else(|| ValueError::parse("date", value))
+    }
+
+    pub(crate) fn parse_from(cursor: &mut Cursor<'_>) -> Option<Self> {
+        let year = cursor.digits(4)?;
+        cursor.eat(b'-').then_some(())?;
+        let month = cursor.digits(2)?;
+        cursor.eat(b'-').then_some(())?;
+        let day = cursor.digits(2)?;
+        Self::from_ymd(year as i32, month, day).ok()
+    }
+
+    pub fn unix_days(&self) -> i64 {
+        self.days as i64
+    }
+
+    pub fn year(&self) -> i32 {
+        civil_from_days(self.unix_days()).0 as i32
+    }
+
+    pub fn month(&self) -> u32 {
+        civil_from_days(self.unix_days()).1
+    }
+
+    pub fn day(&self) -> u32 {
+        civil_from_days(self.unix_days()).2
+    }
+
+    /// Adds a whole number of days; errors when `rhs` has a time part.
+    pub fn checked_add(self, rhs: Duration) -> Result<Self, ValueError> {
+        let nanos = rhs.as_nanos();
+
+        if nanos % NANOS_PER_DAY != 0 {
+            return Err(ValueError::precision_loss(rhs, "date"));
+        }
+
+        let days = i64::try_from(nanos / NANOS_PER_DAY)
+            .ok()
+            .and_then(|days| self.unix_days().checked_add(days))
+            .ok_or_else(|| ValueError::overflow(format!("{} + {}", self, rhs), "date"))?;
+
+        Self::from_unix_days(days)
+    }
+
+    pub fn checked_sub(self, rhs: Duration) -> Result<Self, ValueError> {
+        self.checked_add(rhs.checked_neg()?)
+    }
+
+    /// The whole days from `earlier` to `self`.
+    pub fn since(&self, earlier: &Self) -> Duration {
+        Duration::from_days(self.unix_days() - earlier.unix_days())
+    }
+}
+
+impl From<Date> for Time {
+    fn from(value: Date) -> Self {
+        Self::Date(value)
+    }
+}
+
+impl From<Date> for Value {
+    fn from(value: Date) -> Self {
+        Self::Time(Time::Date(value))
+    }
+}
+
+impl std::ops::Add<Duration> for Date {
+    type Output = Date;
+
+    fn add(self, rhs: Duration) -> Date {
+        self.checked_add(rhs).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl std::ops::Sub<Duration> for Date {
+    type Output = Date;
+
+    fn sub(self, rhs: Duration) -> Date {
+        self.checked_sub(rhs).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl std::ops::Sub for Date {
+    type Output = Duration;
+
+    fn sub(self, rhs: Self) -> Duration {
+        self.since(&rhs)
+    }
+}
+
+impl std::fmt::Debug for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl std::fmt::Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (year, month, day) = civil_from_days(self.unix_days());
+        write!(f, "{:04}-{:02}-{:02}", year, month, day)
+    }
+}
+
+impl ToValue for Date {
+    fn to_value(&self) -> Value {
+        Value::from(*self)
+    }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Time(Time::Date(*self))
+    }
+}
+
+impl_serde_str!(Date);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_format() {
+        let date = Date::parse("2024-02-29").unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (2024, 2, 29));
+        assert_eq!(date.to_string(), "2024-02-29");
+        assert_eq!(Date::MIN.to_string(), "0000-01-01");
+        assert_eq!(Date::MAX.to_string(), "9999-12-31");
+    }
+
+    #[test]
+    fn parse_rejects_invalid() {
+        for input in [
+            "2023-02-29",
+            "2024-13-01",
+            "2024-1-01",
+            "2024-01-01T00:00:00Z",
+            "",
+        ] {
+            assert!(Date::parse(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        let date = Date::from_ymd(2024, 2, 28).unwrap();
+        assert_eq!(
+            date + Duration::from_days(2),
+            Date::from_ymd(2024, 3, 1).unwrap()
+        );
+        assert_eq!(
+            date - Date::from_ymd(2024, 1, 1).unwrap(),
+            Duration::from_days(58)
+        );
+        assert!(date.checked_add(Duration::from_secs(1)).is_err());
+        assert!(Date::MAX.checked_add(Duration::from_days(1)).is_err());
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(Date::parse("2023-12-31").unwrap() < Date::parse("2024-01-01").unwrap());
+    }
+}
@@ -0,0 +1,450 @@
+use std::time::SystemTime;
+
+use crate::{
+    ToValue, Value, ValueError, ValueRef,
+    time::{
+        Cursor, Date, Duration, NANOS_PER_DAY, NANOS_PER_SEC, Time, impl_serde_str, write_fraction,
+    },
+};
+
+/// An instant in UTC together with the offset it was written in. Equality,
+/// ordering and hashing only look at the instant, so `12:00:00+02:00` and
+/// `10:00:00Z` are equal; use [`DateTime::strict_eq`] to compare offsets too.
+/// The local date is kept between the years 0000 and 9999.
+#[derive(Copy, Clone)]
+pub struct DateTime {
+    nanos: i128,
+    offset: i32,
+}
+
+impl DateTime {
+    pub const UNIX_EPOCH: Self = Self {
+        nanos: 0,
+        offset: 0,
+    };
+
+    /// The instant `nanos` after 1970-01-01T00:00:00Z, in UTC.
+    pub fn from_unix_nanos(nanos: i128) -> Result<Self, ValueError> {
+        Self::new(nanos, 0)
+    }
+
+    pub fn from_unix_secs(secs: i64) -> Result<Self, ValueError> {
+        Self::from_unix_nanos(secs as i128 * NANOS_PER_SEC)
+    }
+
+    /// Midnight UTC at the start of `date`.
+    pub fn from_date(date: Date) -> Self {
+        Self {
+            nanos: date.unix_days() as i128 * NANOS_PER_DAY,
+            offset: 0,
+        }
+    }
+
+    pub fn from_system_time(value: SystemTime) -> Result<Self, ValueError> {
+        let nanos = match value.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(v) => v.as_nanos() as i128,
+            Err(err) => -(err.duration().as_nanos() as i128),
+        };
+
+        Self::from_unix_nanos(nanos)
+    }
+
+    /// The current time, in UTC.
+    pub fn now() -> Self {
+        Self::from_system_time(SystemTime::now()).expect("system clock before year 10000")
+    }
+
+    fn new(nanos: i128, offset: i32) -> Result<Self, ValueError> {
+        let value = Self { nanos, offset };
+        let days = value.local_nanos().div_euclid(NANOS_PER_DAY);
+
+        if !(Date::MIN.unix_days() as i128..=Date::MAX.unix_days() as i128).contains(&days) {
+            return Err(ValueError::overflow(nanos, "datetime"));
+        }
+
+        Ok(value)
+    }
+
+    /// Parses an RFC 3339 timestamp such as `2024-05-01T12:00:00.5+02:00`.
+    /// Leap seconds are rejected.
+    pub fn parse(value: &str) -> Result<Self, ValueError> {
+        Self::parse_rfc3339(value).ok_or_else(|| ValueError::parse("datetime", value))
+    }
+
+    fn parse_rfc3339(value: &str) -> Option<Self> {
+        let mut cursor = Cursor::new(value);
+        let date = Date::parse_from(&mut cursor)?;
+
+        if !cursor.eat(b'T') && !cursor.eat(b' ') {
+            return None;
+        }
+
+        let hour = cursor.digits(2).filter(|&v| v < 24)?;
+        cursor.eat(b':').then_some(())?;
+        let minute = cursor.digits(2).filter(|&v| v < 60)?;
+        cursor.eat(b':').then_some(())?;
+        let second = cursor.digits(2).filter(|&v| v < 60)?;
+        let fraction = cursor.fraction()?.unwrap_or(0);
+
+        let offset = if cursor.eat(b'Z') {
+            0
+        } else {
+            let sign = match cursor.peek()? {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+
+            cursor.eat(cursor.peek()?);
+            let hours = cursor.digits(2).filter(|&v| v < 24)?;
+            cursor.eat(b':').then_some(())?;
+            let minutes = cursor.digits(2).filter(|&v| v < 60)?;
+            sign * (hours * 3_600 + minutes * 60) as i32
+        };
+
+        if !cursor.is_done() {
+            return None;
+        }
+
+        let local = date.unix_days() as i128 * NANOS_PER_DAY
+            + (hour * 3_600 + minute * 60 + second) as i128 * NANOS_PER_SEC
+            + fraction as i128;
+
+        Self::new(local - offset as i128 * NANOS_PER_SEC, offset).ok()
+    }
+
+    pub fn unix_nanos(&self) -> i128 {
+        self.nanos
+    }
+
+    /// Whole seconds since the epoch, rounded toward negative infinity.
+    pub fn unix_secs(&self) -> i64 {
+        self.nanos.div_euclid(NANOS_PER_SEC) as i64
+    }
+
+    /// The UTC offset in seconds, east of Greenwich.
+    pub fn offset_secs(&self) -> i32 {
+        self.offset
+    }
+
+    /// The same instant at another offset, which must be whole minutes and
+    /// less than a day.
+    pub fn with_offset(&self, secs: i32) -> Result<Self, ValueError> {
+        if secs % 60 != 0 || secs.abs() >= 86_400 {
+            return Err(ValueError::overflow(secs, "utc offset"));
+        }
+
+        Self::new(self.nanos, secs)
+    }
+
+    pub fn to_utc(&self) -> Self {
+        Self {
+            nanos: self.nanos,
+            offset: 0,
+        }
+    }
+
+    pub fn to_system_time(&self) -> Result<SystemTime, ValueError> {
+        let since = Duration::from_nanos(self.nanos.abs()).to_std()?;
+        let time = match self.nanos < 0 {
+            true => SystemTime::UNIX_EPOCH.checked_sub(since),
+            false => SystemTime::UNIX_EPOCH.checked_add(since),
+        };
+
+        time.ok_or_else(|| ValueError::overflow(self, "SystemTime"))
+    }
+
+    fn local_nanos(&self) -> i128 {
+        self.nanos + self.offset as i128 * NANOS_PER_SEC
+    }
+
+    fn time_of_day(&self) -> i128 {
+        self.local_nanos().rem_euclid(NANOS_PER_DAY)
+    }
+
+    /// The local date at this timestamp's offset.
+    pub fn date(&self) -> Date {
+        let days = self.local_nanos().div_euclid(NANOS_PER_DAY);
+        Date::from_unix_days(days as i64).expect("datetime within date range")
+    }
+
+    pub fn year(&self) -> i32 {
+        self.date().year()
+    }
+
+    pub fn month(&self) -> u32 {
+        self.date().month()
+    }
+
+    pub fn day(&self) -> u32 {
+        self.date().day()
+    }
+
+    pub fn hour(&self) -> u32 {
+        (self.time_of_day() / (3_600 * NANOS_PER_SEC)) as u32
+    }
+
+    pub fn minute(&self) -> u32 {
+        (self.time_of_day() / (60 * NANOS_PER_SEC) % 60) as u32
+    }
+
+    pub fn second(&self) -> u32 {
+        (self.time_of_day() / NANOS_PER_SEC % 60) as u32
+    }
+
+    pub fn nanosecond(&self) -> u32 {
+        (self.time_of_day() % NANOS_PER_SEC) as u32
+    }
+
+    /// Like `==`, but the offsets must match too.
+    pub fn strict_eq(&self, other: &Self) -> bool {
+        self.nanos == other.nanos && self.offset == other.offset
+    }
+
+    /// Shifts the instant, keeping the offset.
+    pub fn checked_add(self, rhs: Duration) -> Result<Self, ValueError> {
+        self.nanos
+            .checked_add(rhs.as_nanos())
+            .ok_or_else(|| ValueError::overflow(format!("{} + {}", self, rhs), "datetime"))
+            .and_then(|nanos| Self::new(nanos, self.offset))
+    }
+
+    pub fn checked_sub(self, rhs: Duration) -> Result<Self, ValueError> {
+        self.checked_add(rhs.checked_neg()?)
+    }
+
+    /// The time elapsed from `earlier` to `self`, negative if `earlier` is
+    /// later.
+    pub fn since(&self, earlier: &Self) -> Duration {
+        Duration::from_nanos(self.nanos - earlier.nanos)
+    }
+}
+
+impl PartialEq for DateTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.nanos == other.nanos
+    }
+}
+
+impl Eq for DateTime {}
+
+impl PartialOrd for DateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DateTime {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.nanos.cmp(&other.nanos)
+    }
+}
+
+impl std::hash::Hash for DateTime {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.nanos.hash(state);
+    }
+}
+
+impl From<Date> for DateTime {
+    fn from(value: Date) -> Self {
+        Self::from_date(value)
+    }
+}
+
+impl TryFrom<SystemTime> for DateTime {
+    type Error = ValueError;
+
+    fn try_from(value: SystemTime) -> Result<Self, Self::Error> {
+        Self::from_system_time(value)
+    }
+}
+
+impl From<DateTime> for Time {
+    fn from(value: DateTime) -> Self {
+        Self::DateTime(value)
+    }
+}
+
+impl From<DateTime> for Value {
+    fn from(value: DateTime) -> Self {
+        Self::Time(Time::DateTime(value))
+    }
+}
+
+impl std::ops::Add<Duration> for DateTime {
+    type Output = DateTime;
+
+    fn add(self, rhs: Duration) -> DateTime {
+        self.checked_add(rhs).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl std::ops::Sub<Duration> for DateTime {
+    type Output = DateTime;
+
+    fn sub(self, rhs: Duration) -> DateTime {
+        self.checked_sub(rhs).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl std::ops::Sub for DateTime {
+    type Output = Duration;
+
+    fn sub(self, rhs: Self) -> Duration {
+        self.since(&rhs)
+    }
+}
+
+impl std::fmt::Debug for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+/// RFC 3339 at the stored offset, with `Z` for UTC and the fraction trimmed.
+impl std::fmt::Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}T{:02}:{:02}:{:02}",
+            self.date(),
+            self.hour(),
+            self.minute(),
+            self.second()
+        )?;
+
+        write_fraction(f, self.nanosecond())?;
+
+        if self.offset == 0 {
+            return write!(f, "Z");
+        }
+
+        let sign = if self.offset < 0 { '-' } else { '+' };
+        let offset = self.offset.unsigned_abs();
+        write!(f, "{}{:02}:{:02}", sign, offset / 3_600, offset / 60 % 60)
+    }
+}
+
+impl ToValue for DateTime {
+    fn to_value(&self) -> Value {
+        Value::from(*self)
+    }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Time(Time::DateTime(*self))
+    }
+}
+
+/// Panics when the time is outside the years 0000 to 9999.
+impl ToValue for SystemTime {
+    fn to_value(&self) -> Value {
+        DateTime::from_system_time(*self)
+            .map(Value::from)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        let value = DateTime::from_system_time(*self).unwrap_or_else(|err| panic!("{err}"));
+        ValueRef::Time(Time::DateTime(value))
+    }
+}
+
+impl_serde_str!(DateTime);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_format() {
+        let cases = [
+            ("1970-01-01T00:00:00Z", 0, 0),
+            (
+                "2024-05-01T12:00:00+02:00",
+                1_714_557_600 * NANOS_PER_SEC,
+                7_200,
+            ),
+            ("1969-12-31T23:59:59.5Z", -NANOS_PER_SEC / 2, 0),
+            (
+                "2024-05-01T00:30:00.000000001-05:30",
+                1_714_543_200 * NANOS_PER_SEC + 1,
+                -19_800,
+            ),
+        ];
+
+        for (input, nanos, offset) in cases {
+            let v = DateTime::parse(input).unwrap();
+            assert_eq!(
+                (v.unix_nanos(), v.offset_secs()),
+                (nanos, offset),
+                "{input}"
+            );
+            assert_eq!(v.to_string(), input);
+        }
+
+        let v = DateTime::parse("2024-05-01t12:00:00.120z").unwrap();
+        assert_eq!(v.to_string(), "2024-05-01T12:00:00.12Z");
+        assert!(DateTime::parse("2024-05-01 12:00:00Z").is_ok());
+    }
+
+    #[test]
+    fn parse_rejects_invalid() {
+        for input in [
+            "2024-05-01",
+            "2024-05-01T12:00Z",
+            "2024-05-01T24:00:00Z",
+            "2024-06-30T23:59:60Z",
+            "2024-05-01T12:00:00",
+            "2024-05-01T12:00:00+0200",
+            "2024-05-01T12:00:00.Z",
+        ] {
+            assert!(DateTime::parse(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn compares_instants() {
+        let a = DateTime::parse("2024-05-01T12:00:00+02:00").unwrap();
+        let b = DateTime::parse("2024-05-01T10:00:00Z").unwrap();
+        assert_eq!(a, b);
+        assert!(!a.strict_eq(&b));
+        assert!(a.to_utc().strict_eq(&b));
+        assert!(b < DateTime::parse("2024-05-01T10:00:00.1Z").unwrap());
+        assert_eq!(b.with_offset(7_200).unwrap().to_string(), a.to_string());
+        assert!(b.with_offset(30).is_err());
+    }
+
+    #[test]
+    fn fields() {
+        let v = DateTime::parse("2024-02-29T23:30:15.25-01:00").unwrap();
+        assert_eq!(v.date(), Date::from_ymd(2024, 2, 29).unwrap());
+        assert_eq!((v.hour(), v.minute(), v.second()), (23, 30, 15));
+        assert_eq!(v.nanosecond(), 250_000_000);
+        assert_eq!(v.to_utc().date(), Date::from_ymd(2024, 3, 1).unwrap());
+    }
+
+    #[test]
+    fn arithmetic() {
+        let v = DateTime::parse("2024-05-01T23:00:00+02:00").unwrap();
+        let later = v + Duration::from_secs(3_600);
+        assert_eq!(later.to_string(), "2024-05-02T00:00:00+02:00");
+        assert_eq!(later - v, Duration::from_secs(3_600));
+        assert_eq!(v - later, Duration::from_secs(-3_600));
+        assert_eq!(later - Duration::from_secs(3_600), v);
+
+        let max = DateTime::parse("9999-12-31T23:59:59Z").unwrap();
+        assert!(max.checked_add(Duration::from_secs(1)).is_err());
+    }
+
+    #[test]
+    fn system_time() {
+        let now = SystemTime::now();
+        let v = DateTime::from_system_time(now).unwrap();
+        assert_eq!(v.to_system_time().unwrap(), now);
+        assert!(now.to_value().is_datetime());
+
+        let before = SystemTime::UNIX_EPOCH - std::time::Duration::from_secs(1);
+        assert_eq!(DateTime::try_from(before).unwrap().unix_secs(), -1);
+    }
+}
@@ -0,0 +1,364 @@
+use crate::{
+    ToValue, Value, ValueError, ValueRef,
+    time::{Cursor, NANOS_PER_DAY, NANOS_PER_SEC, Time, impl_serde_str, write_fraction},
+};
+
+/// A signed span of time with nanosecond precision. Unlike
+/// [`std::time::Duration`] it can be negative, so the difference of two
+/// instants is always representable.
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Duration {
+    nanos: i128,
+}
+
+impl Duration {
+    pub const ZERO: Self = Self { nanos: 0 };
+
+    pub fn from_nanos(nanos: i128) -> Self {
+        Self { nanos }
+    }
+
+    pub fn from_millis(millis: i64) -> Self {
+        Self::from_nanos(millis as i128 * 1_000_000)
+    }
+
+    pub fn from_secs(secs: i64) -> Self {
+        Self::from_nanos(secs as i128 * NANOS_PER_SEC)
+    }
+
+    pub fn from_days(days: i64) -> Self {
+        Self::from_nanos(days as i128 * NANOS_PER_DAY)
+    }
+
+    pub fn from_std(value: std::time::Duration) -> Self {
+        Self::from_nanos(value.as_nanos() as i128)
+    }
+
+    /// Errors for negative durations, which [`std::time::Duration`] cannot hold.
+    pub fn to_std(&self) -> Result<std::time::Duration, ValueError> {
+        let nanos = u128::try_from(self.nanos)
+            .map_err(|_| ValueError::overflow(self, "std::time::Duration"))?;
+        let secs = u64::try_from(nanos / NANOS_PER_SEC as u128)
+            .map_err(|_| ValueError::overflow(self, "std::time::Duration"))?;
+        Ok(std::time::Duration::new(
+            secs,
+            (nanos % NANOS_PER_SEC as u128) as u32,
+        ))
+    }
+
+    /// Parses an ISO 8601 duration such as `PT1H30M`, `P1DT2.5S` or `-P2W`.
+    /// Years and months are rejected since their length varies.
+    pub fn parse(value: &str) -> Result<Self, ValueError> {
+        Self::parse_iso(value).ok_or_else(|| ValueError::parse("duration", value))
+    }
+
+    fn parse_iso(value: &str) -> Option<Self> {
+        let mut cursor = Cursor::new(value);
+        let negative = cursor.eat(b'-');
+
+        if !negative {
+            cursor.eat(b'+');
+        }
+
+        if !cursor.eat(b'P') {
+            return None;
+        }
+
+        let mut nanos = 0_i128;
+        let mut any = false;
+        let mut time = false;
+        let mut last = 0;
+
+        while !cursor.is_done() {
+            if !time && cursor.eat(b'T') {
+                time = true;
+                last = 0;
+
+                if cursor.is_done() {
+                    return None;
+                }
+
+                continue;
+            }
+
+            let (whole, _) = cursor.number()?;
+            let fraction = cursor.fraction()?;
+            let designator = cursor.peek()?.to_ascii_uppercase();
+            cursor.eat(designator);
+
+            let (order, unit) = match (time, designator) {
+                (false, b'W') => (1, 7 * NANOS_PER_DAY),
+                (false, b'D') => (2, NANOS_PER_DAY),
+                (true, b'H') => (1, 3_600 * NANOS_PER_SEC),
+                (true, b'M') => (2, 60 * NANOS_PER_SEC),
+                (true, b'S') => (3, NANOS_PER_SEC),
+                _ => return None,
+            };
+
+            if order <= last || (fraction.is_some() && designator != b'S') {
+                return None;
+            }
+
+            last = order;
+            any = true;
+            nanos = nanos.checked_add((whole as i128).checked_mul(unit)?)?;
+            nanos += fraction.unwrap_or(0) as i128;
+        }
+
+        any.then_some(Self::from_nanos(if negative { -nanos } else { nanos }))
+    }
+
+    pub fn as_nanos(&self) -> i128 {
+        self.nanos
+    }
+
+    /// Whole seconds, truncated toward zero.
+    pub fn as_secs(&self) -> i128 {
+        self.nanos / NANOS_PER_SEC
+    }
+
+    pub fn as_secs_f64(&self) -> f64 {
+        self.nanos as f64 / NANOS_PER_SEC as f64
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.nanos == 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.nanos < 0
+    }
+
+    /// The magnitude, saturating at the largest duration for the one negative
+    /// duration without a positive counterpart.
+    pub fn abs(&self) -> Self {
+        Self::from_nanos(self.nanos.saturating_abs())
+    }
+
+    pub fn checked_abs(self) -> Result<Self, ValueError> {
+        self.nanos
+            .checked_abs()
+            .map(Self::from_nanos)
+            .ok_or_else(|| ValueError::overflow(format!("|{}|", self), "duration"))
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Result<Self, ValueError> {
+        self.nanos
+            .checked_add(rhs.nanos)
+            .map(Self::from_nanos)
+            .ok_or_else(|| ValueError::overflow(format!("{} + {}", self, rhs), "duration"))
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, ValueError> {
+        self.nanos
+            .checked_sub(rhs.nanos)
+            .map(Self::from_nanos)
+            .ok_or_else(|| ValueError::overflow(format!("{} - {}", self, rhs), "duration"))
+    }
+
+    pub fn checked_mul(self, rhs: i64) -> Result<Self, ValueError> {
+        self.nanos
+            .checked_mul(rhs as i128)
+            .map(Self::from_nanos)
+            .ok_or_else(|| ValueError::overflow(format!("{} * {}", self, rhs), "duration"))
+    }
+
+    pub fn checked_neg(self) -> Result<Self, ValueError> {
+        self.nanos
+            .checked_neg()
+            .map(Self::from_nanos)
+            .ok_or_else(|| ValueError::overflow(format!("-({})", self), "duration"))
+    }
+}
+
+impl From<std::time::Duration> for Duration {
+    fn from(value: std::time::Duration) -> Self {
+        Self::from_std(value)
+    }
+}
+
+impl From<Duration> for Time {
+    fn from(value: Duration) -> Self {
+        Self::Duration(value)
+    }
+}
+
+impl From<Duration> for Value {
+    fn from(value: Duration) -> Self {
+        Self::Time(Time::Duration(value))
+    }
+}
+
+impl std::ops::Add for Duration {
+    type Output = Duration;
+
+    fn add(self, rhs: Self) -> Duration {
+        self.checked_add(rhs).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl std::ops::Sub for Duration {
+    type Output = Duration;
+
+    fn sub(self, rhs: Self) -> Duration {
+        self.checked_sub(rhs).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl std::ops::Neg for Duration {
+    type Output = Duration;
+
+    fn neg(self) -> Duration {
+        self.checked_neg().unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl std::fmt::Debug for Duration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+/// ISO 8601, with days as the largest unit: `P1DT2H3M4.5S`, `-PT1S`, `PT0S`.
+impl std::fmt::Display for Duration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_negative() {
+            write!(f, "-")?;
+        }
+
+        let nanos = self.nanos.unsigned_abs();
+        let secs = nanos / NANOS_PER_SEC as u128;
+        let (days, hours, minutes, seconds) =
+            (secs / 86_400, secs / 3_600 % 24, secs / 60 % 60, secs % 60);
+        let fraction = (nanos % NANOS_PER_SEC as u128) as u32;
+
+        write!(f, "P")?;
+
+        if days > 0 {
+            write!(f, "{}D", days)?;
+        }
+
+        if days > 0 && hours == 0 && minutes == 0 && seconds == 0 && fraction == 0 {
+            return Ok(());
+        }
+
+        write!(f, "T")?;
+
+        if hours > 0 {
+            write!(f, "{}H", hours)?;
+        }
+
+        if minutes > 0 {
+            write!(f, "{}M", minutes)?;
+        }
+
+        if seconds > 0 || fraction > 0 || (hours == 0 && minutes == 0) {
+            write!(f, "{}", seconds)?;
+            write_fraction(f, fraction)?;
+            write!(f, "S")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ToValue for Duration {
+    fn to_value(&self) -> Value {
+        Value::from(*self)
+    }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Time(Time::Duration(*self))
+    }
+}
+
+impl ToValue for std::time::Duration {
+    fn to_value(&self) -> Value {
+        Value::from(Duration::from_std(*self))
+    }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Time(Time::Duration(Duration::from_std(*self)))
+    }
+}
+
+impl_serde_str!(Duration);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let cases = [
+            ("PT0S", 0),
+            ("PT1H30M", 5_400 * NANOS_PER_SEC),
+            ("P1DT2.5S", NANOS_PER_DAY + 2_500_000_000),
+            ("P2W", 14 * NANOS_PER_DAY),
+            ("-PT1M", -60 * NANOS_PER_SEC),
+            ("pt0.000000001s", 1),
+        ];
+
+        for (input, nanos) in cases {
+            assert_eq!(Duration::parse(input).unwrap().as_nanos(), nanos, "{input}");
+        }
+    }
+
+    #[test]
+    fn parse_rejects_invalid() {
+        for input in [
+            "", "P", "PT", "P1Y", "P1M", "PT1M1H", "PT1.5M", "1H", "P1DT", "PT1S2",
+        ] {
+            assert!(Duration::parse(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn display() {
+        let cases = [
+            (Duration::ZERO, "PT0S"),
+            (Duration::from_secs(5_400), "PT1H30M"),
+            (Duration::from_days(3), "P3D"),
+            (
+                Duration::from_nanos(NANOS_PER_DAY + 2_500_000_000),
+                "P1DT2.5S",
+            ),
+            (Duration::from_secs(-61), "-PT1M1S"),
+        ];
+
+        for (duration, text) in cases {
+            assert_eq!(duration.to_string(), text);
+            assert_eq!(Duration::parse(text).unwrap(), duration);
+        }
+    }
+
+    #[test]
+    fn std_round_trip() {
+        let std = std::time::Duration::from_millis(1_500);
+        assert_eq!(Duration::from(std).to_std().unwrap(), std);
+        assert!(Duration::from_secs(-1).to_std().is_err());
+        assert!(std.to_value().is_duration());
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = Duration::from_secs(90);
+        assert_eq!(a + a, Duration::from_secs(180));
+        assert_eq!(a - Duration::from_secs(100), Duration::from_secs(-10));
+        assert_eq!(-a, Duration::from_secs(-90));
+        assert_eq!(a.checked_mul(2).unwrap(), Duration::from_secs(180));
+    }
+
+    #[test]
+    fn abs_and_neg_at_min() {
+        let min = Duration::from_nanos(i128::MIN);
+
+        assert_eq!(Duration::from_secs(-90).abs(), Duration::from_secs(90));
+        assert_eq!(min.abs(), Duration::from_nanos(i128::MAX));
+        assert!(min.checked_abs().is_err());
+
+        let err = min.checked_neg().unwrap_err().to_string();
+        assert!(err.starts_with(&format!("-({})", min)), "{}", err);
+        assert!(!err.contains("--"), "{}", err);
+    }
+}
@@ -0,0 +1,400 @@
+mod date;
+mod datetime;
+mod duration;
+
+pub use date::*;
+pub use datetime::*;
+pub use duration::*;
+
+use crate::{ToValue, Value, ValueError, ValueRef};
+
+/// A temporal value: an instant, a calendar date or a span of time.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Time {
+    DateTime(DateTime),
+    Date(Date),
+    Duration(Duration),
+}
+
+impl Time {
+    /// Parses an RFC 3339 timestamp, an ISO 8601 date or an ISO 8601
+    /// duration, in that order.
+    pub fn parse(value: &str) -> Result<Self, ValueError> {
+        DateTime::parse(value)
+            .map(Self::DateTime)
+            .or_else(|_| Date::parse(value).map(Self::Date))
+            .or_else(|_| Duration::parse(value).map(Self::Duration))
+            .map_err(|_| ValueError::parse("time", value))
+    }
+
+    pub fn is_datetime(&self) -> bool {
+        matches!(self, Self::DateTime(_))
+    }
+
+    pub fn is_date(&self) -> bool {
+        matches!(self, Self::Date(_))
+    }
+
+    pub fn is_duration(&self) -> bool {
+        matches!(self, Self::Duration(_))
+    }
+
+    pub fn as_datetime(&self) -> &DateTime {
+        match self {
+            Self::DateTime(v) => v,
+            v => panic!("expected DateTime, received {}", v.type_name()),
+        }
+    }
+
+    pub fn as_date(&self) -> &Date {
+        match self {
+            Self::Date(v) => v,
+            v => panic!("expected Date, received {}", v.type_name()),
+        }
+    }
+
+    pub fn as_duration(&self) -> &Duration {
+        match self {
+            Self::Duration(v) => v,
+            v => panic!("expected Duration, received {}", v.type_name()),
+        }
+    }
+
+    pub fn try_as_datetime(&self) -> Result<&DateTime, ValueError> {
+        match self {
+            Self::DateTime(v) => Ok(v),
+            v => Err(ValueError::type_mismatch("datetime", v.type_name())),
+        }
+    }
+
+    pub fn try_as_date(&self) -> Result<&Date, ValueError> {
+        match self {
+            Self::Date(v) => Ok(v),
+            v => Err(ValueError::type_mismatch("date", v.type_name())),
+        }
+    }
+
+    pub fn try_as_duration(&self) -> Result<&Duration, ValueError> {
+        match self {
+            Self::Duration(v) => Ok(v),
+            v => Err(ValueError::type_mismatch("duration", v.type_name())),
+        }
+    }
+
+    /// Like `==`, but timestamps must also share their offset.
+    pub fn strict_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::DateTime(a), Self::DateTime(b)) => a.strict_eq(b),
+            (a, b) => a == b,
+        }
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::DateTime(_) => "datetime",
+            Self::Date(_) => "date",
+            Self::Duration(_) => "duration",
+        }
+    }
+
+    pub fn type_id(&self) -> std::any::TypeId {
+        match self {
+            Self::DateTime(_) => std::any::TypeId::of::<DateTime>(),
+            Self::Date(_) => std::any::TypeId::of::<Date>(),
+            Self::Duration(_) => std::any::TypeId::of::<Duration>(),
+        }
+    }
+}
+
+impl Value {
+    pub fn is_datetime(&self) -> bool {
+        matches!(self, Self::Time(v) if v.is_datetime())
+    }
+
+    pub fn is_date(&self) -> bool {
+        matches!(self, Self::Time(v) if v.is_date())
+    }
+
+    pub fn is_duration(&self) -> bool {
+        matches!(self, Self::Time(v) if v.is_duration())
+    }
+
+    pub fn as_datetime(&self) -> &DateTime {
+        self.as_time().as_datetime()
+    }
+
+    pub fn as_date(&self) -> &Date {
+        self.as_time().as_date()
+    }
+
+    pub fn as_duration(&self) -> &Duration {
+        self.as_time().as_duration()
+    }
+
+    pub fn try_as_datetime(&self) -> Result<&DateTime, ValueError> {
+        match self {
+            Self::Time(v) => v.try_as_datetime(),
+            v => Err(ValueError::type_mismatch("datetime", v.type_name())),
+        }
+    }
+
+    pub fn try_as_date(&self) -> Result<&Date, ValueError> {
+        match self {
+            Self::Time(v) => v.try_as_date(),
+            v => Err(ValueError::type_mismatch("date", v.type_name())),
+        }
+    }
+
+    pub fn try_as_duration(&self) -> Result<&Duration, ValueError> {
+        match self {
+            Self::Time(v) => v.try_as_duration(),
+            v => Err(ValueError::type_mismatch("duration", v.type_name())),
+        }
+    }
+}
+
+impl From<Time> for Value {
+    fn from(value: Time) -> Self {
+        Self::Time(value)
+    }
+}
+
+impl std::fmt::Debug for Time {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DateTime(v) => write!(f, "{:?}", v),
+            Self::Date(v) => write!(f, "{:?}", v),
+            Self::Duration(v) => write!(f, "{:?}", v),
+        }
+    }
+}
+
+impl std::fmt::Display for Time {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DateTime(v) => write!(f, "{}", v),
+            Self::Date(v) => write!(f, "{}", v),
+            Self::Duration(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl ToValue for Time {
+    fn to_value(&self) -> Value {
+        Value::Time(*self)
+    }
+
+    fn as_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::Time(*self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Time {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Time {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        Self::parse(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Implements string-based serde for a type with `parse` and `Display`.
+macro_rules! impl_serde_str {
+    ($T:ty) => {
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $T {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $T {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                Self::parse(&value).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_serde_str;
+
+pub(crate) const NANOS_PER_SEC: i128 = 1_000_000_000;
+pub(crate) const NANOS_PER_DAY: i128 = 86_400 * NANOS_PER_SEC;
+
+/// Days since 1970-01-01 of a proleptic Gregorian date.
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// The `(year, month, day)` of a day count since 1970-01-01.
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    (era * 400 + yoe + (month <= 2) as i64, month, day)
+}
+
+pub(crate) fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// A cursor over ASCII input for the ISO 8601 parsers.
+pub(crate) struct Cursor<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub(crate) fn new(input: &'a str) -> Self {
+        Self {
+            input: input.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    pub(crate) fn is_done(&self) -> bool {
+        self.pos == self.input.len()
+    }
+
+    pub(crate) fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).copied()
+    }
+
+    /// Consumes `c` (ASCII case-insensitively) if it is next.
+    pub(crate) fn eat(&mut self, c: u8) -> bool {
+        match self.peek() {
+            Some(v) if v.eq_ignore_ascii_case(&c) => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Exactly `n` digits.
+    pub(crate) fn digits(&mut self, n: usize) -> Option<u32> {
+        let digits = self.input.get(self.pos..self.pos + n)?;
+
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+
+        self.pos += n;
+        Some(digits.iter().fold(0, |acc, d| acc * 10 + (d - b'0') as u32))
+    }
+
+    /// One or more digits, with their count.
+    pub(crate) fn number(&mut self) -> Option<(u64, usize)> {
+        let start = self.pos;
+        let mut value = 0_u64;
+
+        while let Some(d) = self.peek().filter(u8::is_ascii_digit) {
+            value = value.checked_mul(10)?.checked_add((d - b'0') as u64)?;
+            self.pos += 1;
+        }
+
+        (self.pos > start).then_some((value, self.pos - start))
+    }
+
+    /// A `.` or `,` followed by up to nine digits, as nanoseconds.
+    pub(crate) fn fraction(&mut self) -> Option<Option<u32>> {
+        if !self.eat(b'.') && !self.eat(b',') {
+            return Some(None);
+        }
+
+        let (value, len) = self.number()?;
+
+        if len > 9 {
+            return None;
+        }
+
+        Some(Some(value as u32 * 10_u32.pow(9 - len as u32)))
+    }
+}
+
+/// Writes `.nnn` with trailing zeros trimmed, or nothing for zero.
+pub(crate) fn write_fraction(f: &mut std::fmt::Formatter<'_>, nanos: u32) -> std::fmt::Result {
+    if nanos == 0 {
+        return Ok(());
+    }
+
+    let digits = format!("{:09}", nanos);
+    write!(f, ".{}", digits.trim_end_matches('0'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn civil_round_trip() {
+        for days in [-719_528, -1, 0, 1, 11_016, 19_723, 2_932_896] {
+            let (y, m, d) = civil_from_days(days);
+            assert_eq!(days_from_civil(y, m, d), days);
+        }
+
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+    }
+
+    #[test]
+    fn parse_any() {
+        assert!(Time::parse("2024-05-01T12:00:00Z").unwrap().is_datetime());
+        assert!(Time::parse("2024-05-01").unwrap().is_date());
+        assert!(Time::parse("PT5M").unwrap().is_duration());
+        assert_eq!(
+            Time::parse("tomorrow"),
+            Err(ValueError::parse("time", "tomorrow"))
+        );
+    }
+
+    #[test]
+    fn value_accessors() {
+        let v = Value::from(Date::from_ymd(2024, 5, 1).unwrap());
+        assert!(v.is_time() && v.is_date() && !v.is_datetime());
+        assert_eq!(v.type_name(), "date");
+        assert_eq!(v.to_string(), "2024-05-01");
+        assert_eq!(
+            v.try_as_duration().unwrap_err(),
+            ValueError::type_mismatch("duration", "date")
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let t = Time::parse("2024-05-01T12:00:00+02:00").unwrap();
+        let json = serde_json::to_string(&t).unwrap();
+        assert_eq!(json, "\"2024-05-01T12:00:00+02:00\"");
+        assert!(serde_json::from_str::<Time>(&json).unwrap().strict_eq(&t));
+
+        let v = Value::Time(t);
+        assert_eq!(serde_json::to_string(&v).unwrap(), json);
+        assert_eq!(crate::from_value::<DateTime>(&v).unwrap(), *t.as_datetime());
+    }
+}
@@ -0,0 +1,432 @@
+use std::sync::Arc;
+
+use crate::{
+    Array, Bool, Ident, Number, Object, OrderedMap, Struct, Time, ToValue, Tuple, TupleVec, Value,
+};
+
+/// A borrowed view of a [`Value`] or any [`ToValue`] type, from
+/// [`ToValue::as_value_ref`]. Reading through a view never converts the
+/// data it points at, so walking, comparing and serializing a derived
+/// struct or a nested [`Value`] doesn't allocate.
+///
+/// Equality, ordering and hashing match [`Value`]'s, and a view compares
+/// equal to the value it was borrowed from.
+#[derive(Clone)]
+pub enum ValueRef<'a> {
+    Null,
+    Bool(bool),
+    Number(Number),
+    String(&'a str),
+    Bytes(&'a [u8]),
+    Time(Time),
+    Struct(&'a dyn Struct),
+    Array(&'a dyn Array),
+    Tuple(&'a dyn Tuple),
+    /// A converted value, for [`ToValue`] types with no borrowed form.
+    Owned(Value),
+}
+
+impl<'a> ValueRef<'a> {
+    /// Same order as [`Value`]'s discriminant, so cross-type ordering
+    /// agrees with it.
+    fn discriminant(&self) -> u8 {
+        match self {
+            Self::Null => 0,
+            Self::Bool(_) => 1,
+            Self::Number(_) => 2,
+            Self::String(_) => 3,
+            Self::Bytes(_) => 4,
+            Self::Time(_) => 5,
+            Self::Struct(_) | Self::Array(_) | Self::Tuple(_) => 6,
+            Self::Owned(v) => v.as_value_ref().discriminant(),
+        }
+    }
+
+    /// Objects order by kind: `Struct < Array < Tuple`.
+    fn kind(&self) -> u8 {
+        match self {
+            Self::Struct(_) => 0,
+            Self::Array(_) => 1,
+            _ => 2,
+        }
+    }
+
+    pub fn type_name(&self) -> &str {
+        match self {
+            Self::Null => "null",
+            Self::Bool(_) => "bool",
+            Self::Number(v) => v.type_name(),
+            Self::String(_) => "string",
+            Self::Bytes(_) => "bytes",
+            Self::Time(v) => v.type_name(),
+            Self::Struct(v) => v.name(),
+            Self::Array(v) => v.name(),
+            Self::Tuple(v) => v.name(),
+            Self::Owned(v) => v.type_name(),
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        match self {
+            Self::Null => true,
+            Self::Owned(v) => v.is_null(),
+            _ => false,
+        }
+    }
+
+    pub fn is_object(&self) -> bool {
+        match self {
+            Self::Struct(_) | Self::Array(_) | Self::Tuple(_) => true,
+            Self::Owned(v) => v.is_object(),
+            _ => false,
+        }
+    }
+
+    /// The item a struct, array or tuple holds at `ident`, unconverted.
+    /// An index into a struct falls back to the key with the same digits,
+    /// as JSON Pointer reads it. Always `None` for [`ValueRef::Owned`],
+    /// which has nothing to lend for `'a`.
+    pub(crate) fn item(&self, ident: &Ident) -> Option<&'a dyn ToValue> {
+        match (self, ident) {
+            (Self::Struct(v), Ident::Key(_)) => v.field(ident.clone()),
+            (Self::Struct(v), Ident::Index(i)) => v
+                .field(ident.clone())
+                .or_else(|| v.field(Ident::Key(i.to_string().into()))),
+            (Self::Array(v), Ident::Index(i)) => v.index(*i),
+            (Self::Tuple(v), Ident::Index(i)) => v.index(*i),
+            _ => None,
+        }
+    }
+
+    /// A view of a struct field, or an array or tuple item.
+    pub fn field(&self, ident: &Ident) -> Option<ValueRef<'a>> {
+        match self {
+            Self::Owned(v) => lookup(v, std::slice::from_ref(ident)).map(Self::Owned),
+            _ => self.item(ident).map(|v| v.as_value_ref()),
+        }
+    }
+
+    /// A view of the value at `path`, like [`Value::get`] without cloning
+    /// at each step.
+    pub fn get(&self, path: &xpath::Path) -> Option<ValueRef<'a>> {
+        let mut value = self.clone();
+
+        for ident in path.iter() {
+            value = value.field(ident)?;
+        }
+
+        Some(value)
+    }
+
+    /// Convert to an owned [`Value`]. Objects are copied through
+    /// `clone_*` when their backing supports it, otherwise item by item.
+    pub fn to_value(&self) -> Value {
+        match self {
+            Self::Null => Value::Null,
+            Self::Bool(v) => Value::from_bool(*v),
+            Self::Number(v) => Value::Number(*v),
+            Self::String(v) => Value::from_str(v),
+            Self::Bytes(v) => Value::from_bytes(*v),
+            Self::Time(v) => Value::Time(*v),
+            Self::Struct(v) => match v.clone_struct() {
+                Some(v) => Value::Object(Object::Struct(Arc::from(v))),
+                None => Value::from_struct(
+                    v.items()
+                        .map(|(k, v)| (k, v.to_value()))
+                        .collect::<OrderedMap>(),
+                ),
+            },
+            Self::Array(v) => match v.clone_array() {
+                Some(v) => Value::Object(Object::Array(Arc::from(v))),
+                None => Value::from_array(v.items().map(|v| v.to_value()).collect::<Vec<_>>()),
+            },
+            Self::Tuple(v) => match v.clone_tuple() {
+                Some(v) => Value::Object(Object::Tuple(Arc::from(v))),
+                None => Value::from(TupleVec::new(v.items().map(|v| v.to_value()).collect())),
+            },
+            Self::Owned(v) => v.clone(),
+        }
+    }
+
+    /// Structural equality that also requires identical number types, as
+    /// [`Value::strict_eq`].
+    pub fn strict_eq(&self, other: &ValueRef<'_>) -> bool {
+        match (self, other) {
+            (Self::Owned(a), b) => a.as_value_ref().strict_eq(b),
+            (a, ValueRef::Owned(b)) => a.strict_eq(&b.as_value_ref()),
+            (Self::Number(a), ValueRef::Number(b)) => a.strict_eq(b),
+            (Self::Time(a), ValueRef::Time(b)) => a.strict_eq(b),
+            (Self::Struct(a), ValueRef::Struct(b)) => {
+                a.len() == b.len()
+                    && a.items().all(|(k, v)| {
+                        b.field(k)
+                            .is_some_and(|bv| v.as_value_ref().strict_eq(&bv.as_value_ref()))
+                    })
+            }
+            (Self::Array(a), ValueRef::Array(b)) => {
+                a.len() == b.len()
+                    && a.items()
+                        .zip(b.items())
+                        .all(|(av, bv)| av.as_value_ref().strict_eq(&bv.as_value_ref()))
+            }
+            (Self::Tuple(a), ValueRef::Tuple(b)) => {
+                a.len() == b.len()
+                    && a.items()
+                        .zip(b.items())
+                        .all(|(av, bv)| av.as_value_ref().strict_eq(&bv.as_value_ref()))
+            }
+            (a, b) => a == b,
+        }
+    }
+}
+
+/// Follow `idents` from `item`, converting only the value found.
+pub(crate) fn lookup(item: &dyn ToValue, idents: &[Ident]) -> Option<Value> {
+    let Some((ident, rest)) = idents.split_first() else {
+        return Some(item.to_value());
+    };
+
+    match item.as_value_ref() {
+        ValueRef::Owned(v) => lookup(&v, idents),
+        view => lookup(view.item(ident)?, rest),
+    }
+}
+
+fn sorted_fields(value: &dyn Struct) -> Vec<(Ident, ValueRef<'_>)> {
+    let mut fields: Vec<_> = value.items().map(|(k, v)| (k, v.as_value_ref())).collect();
+    fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+    fields
+}
+
+impl<'a> From<&'a Value> for ValueRef<'a> {
+    fn from(value: &'a Value) -> Self {
+        value.as_value_ref()
+    }
+}
+
+impl Eq for ValueRef<'_> {}
+
+impl<'b> PartialEq<ValueRef<'b>> for ValueRef<'_> {
+    fn eq(&self, other: &ValueRef<'b>) -> bool {
+        match (self, other) {
+            (Self::Owned(a), b) => a.as_value_ref() == *b,
+            (a, ValueRef::Owned(b)) => *a == b.as_value_ref(),
+            (Self::Null, ValueRef::Null) => true,
+            (Self::Bool(a), ValueRef::Bool(b)) => a == b,
+            (Self::Number(a), ValueRef::Number(b)) => a == b,
+            (Self::String(a), ValueRef::String(b)) => a == b,
+            (Self::Bytes(a), ValueRef::Bytes(b)) => a == b,
+            (Self::Time(a), ValueRef::Time(b)) => a == b,
+            (Self::Struct(a), ValueRef::Struct(b)) => {
+                a.len() == b.len()
+                    && a.items().all(|(k, v)| {
+                        b.field(k)
+                            .is_some_and(|bv| v.as_value_ref() == bv.as_value_ref())
+                    })
+            }
+            (Self::Array(a), ValueRef::Array(b)) => {
+                a.len() == b.len()
+                    && a.items()
+                        .zip(b.items())
+                        .all(|(av, bv)| av.as_value_ref() == bv.as_value_ref())
+            }
+            (Self::Tuple(a), ValueRef::Tuple(b)) => {
+                a.len() == b.len()
+                    && a.items()
+                        .zip(b.items())
+                        .all(|(av, bv)| av.as_value_ref() == bv.as_value_ref())
+            }
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<Value> for ValueRef<'_> {
+    fn eq(&self, other: &Value) -> bool {
+        *self == other.as_value_ref()
+    }
+}
+
+impl PartialEq<ValueRef<'_>> for Value {
+    fn eq(&self, other: &ValueRef<'_>) -> bool {
+        self.as_value_ref() == *other
+    }
+}
+
+impl Ord for ValueRef<'_> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Self::Owned(a), b) => a.as_value_ref().cmp(b),
+            (a, Self::Owned(b)) => a.cmp(&b.as_value_ref()),
+            (Self::Null, Self::Null) => std::cmp::Ordering::Equal,
+            (Self::Bool(a), Self::Bool(b)) => a.cmp(b),
+            (Self::Number(a), Self::Number(b)) => a.cmp(b),
+            (Self::String(a), Self::String(b)) => a.cmp(b),
+            (Self::Bytes(a), Self::Bytes(b)) => a.cmp(b),
+            (Self::Time(a), Self::Time(b)) => a.cmp(b),
+            (Self::Struct(a), Self::Struct(b)) => sorted_fields(*a).cmp(&sorted_fields(*b)),
+            (Self::Array(a), Self::Array(b)) => a
+                .items()
+                .map(|v| v.as_value_ref())
+                .cmp(b.items().map(|v| v.as_value_ref())),
+            (Self::Tuple(a), Self::Tuple(b)) => a
+                .items()
+                .map(|v| v.as_value_ref())
+                .cmp(b.items().map(|v| v.as_value_ref())),
+            (a, b) if a.is_object() && b.is_object() => a.kind().cmp(&b.kind()),
+            (a, b) => a.discriminant().cmp(&b.discriminant()),
+        }
+    }
+}
+
+impl PartialOrd for ValueRef<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Hashes exactly as the equivalent [`Value`] does.
+impl std::hash::Hash for ValueRef<'_> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        if let Self::Owned(v) = self {
+            return v.as_value_ref().hash(state);
+        }
+
+        self.discriminant().hash(state);
+
+        if self.is_object() {
+            self.kind().hash(state);
+        }
+
+        match self {
+            Self::Bool(v) => v.hash(state),
+            Self::Number(v) => v.hash(state),
+            Self::String(v) => v.hash(state),
+            Self::Bytes(v) => v.hash(state),
+            Self::Time(v) => v.hash(state),
+            Self::Struct(v) => sorted_fields(*v).hash(state),
+            Self::Array(v) => {
+                v.len().hash(state);
+                v.items().for_each(|item| item.as_value_ref().hash(state));
+            }
+            Self::Tuple(v) => {
+                v.len().hash(state);
+                v.items().for_each(|item| item.as_value_ref().hash(state));
+            }
+            Self::Null | Self::Owned(_) => {}
+        }
+    }
+}
+
+impl std::fmt::Debug for ValueRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Null => write!(f, "<null>"),
+            Self::Bool(v) => write!(f, "{:#?}", Bool::from_bool(*v)),
+            Self::Number(v) => write!(f, "{:#?}", v),
+            Self::String(v) => write!(f, "{:#?}", v),
+            Self::Bytes(v) => write!(f, "b\"{}\"", v.escape_ascii()),
+            Self::Time(v) => write!(f, "{:#?}", v),
+            Self::Struct(v) => write!(f, "{:#?}", v),
+            Self::Array(v) => write!(f, "{:#?}", v),
+            Self::Tuple(v) => write!(f, "{:#?}", v),
+            Self::Owned(v) => write!(f, "{:#?}", v),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ValueRef<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Null => serializer.serialize_unit(),
+            Self::Bool(v) => serializer.serialize_bool(*v),
+            Self::Number(v) => v.serialize(serializer),
+            Self::String(v) => serializer.serialize_str(v),
+            Self::Bytes(v) if serializer.is_human_readable() => {
+                serializer.serialize_str(&crate::bytes::base64::encode(v))
+            }
+            Self::Bytes(v) => serializer.serialize_bytes(v),
+            Self::Time(v) => v.serialize(serializer),
+            Self::Struct(v) => v.serialize(serializer),
+            Self::Array(v) => v.serialize(serializer),
+            Self::Tuple(v) => v.serialize(serializer),
+            Self::Owned(v) => v.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::{Hash, Hasher};
+
+    use crate::*;
+
+    fn hash(value: &impl Hash) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn borrows_value() {
+        let v = valueof!({ "a": [1_i32, "x", b"y", null], "t": (true,) });
+
+        assert!(matches!(v.as_value_ref(), ValueRef::Struct(_)));
+        assert_eq!(v.as_value_ref(), v);
+        assert!(v.as_value_ref().strict_eq(&v.as_value_ref()));
+        assert!(v.as_value_ref().to_value().strict_eq(&v));
+        assert_eq!(hash(&v.as_value_ref()), hash(&v));
+    }
+
+    #[test]
+    fn get() {
+        let v = valueof!({ "a": [{ "b": 2_u8 }] });
+        let view = v.as_value_ref();
+
+        assert_eq!(
+            view.get(&"a/0/b".into()),
+            Some(ValueRef::Number(Number::from_u8(2)))
+        );
+        assert!(view.get(&"a/1".into()).is_none());
+        assert!(view.get(&"a/b".into()).is_none());
+    }
+
+    #[test]
+    fn owned_fallback() {
+        let cell = std::cell::RefCell::new(valueof!({ "a": [1_i32] }));
+        let view = cell.as_value_ref();
+
+        assert!(matches!(view, ValueRef::Owned(_)));
+        assert_eq!(view.get(&"a/0".into()).unwrap(), valueof!(1_i32));
+        assert_eq!(view, *cell.borrow());
+    }
+
+    #[test]
+    fn primitives() {
+        assert_eq!(7_u16.as_value_ref(), ValueRef::Number(Number::from_u16(7)));
+        assert_eq!("x".as_value_ref(), ValueRef::String("x"));
+        assert_eq!(String::from("x").as_value_ref(), ValueRef::String("x"));
+        assert_eq!(None::<i32>.as_value_ref(), ValueRef::Null);
+        assert_eq!(Box::new(true).as_value_ref(), ValueRef::Bool(true));
+        assert!(matches!(vec![1_i32, 2].as_value_ref(), ValueRef::Array(_)));
+        assert!(matches!((1_i32, "a").as_value_ref(), ValueRef::Tuple(_)));
+    }
+
+    #[test]
+    fn order() {
+        let values = [
+            valueof!([2_i32]),
+            valueof!(1_i32),
+            valueof!(null),
+            valueof!({}),
+        ];
+        let mut sorted = values.clone();
+        sorted.sort();
+
+        let mut views: Vec<_> = values.iter().map(|v| v.as_value_ref()).collect();
+        views.sort();
+
+        assert!(views.iter().zip(&sorted).all(|(a, b)| a == b));
+    }
+}
@@ -0,0 +1,271 @@
+use xpath::Path;
+
+use crate::{Array, Ident, Object, OrderedMap, Struct, ToValue, Tuple, TupleVec, Value, ValueRef};
+
+/// Callbacks for [`Value::walk`], each given the [`Path`] of the node it is
+/// called on. Containers are entered before and left after their items, and
+/// every other kind of value is passed to [`Visitor::scalar`]. All methods
+/// default to doing nothing.
+pub trait Visitor {
+    fn enter_struct(&mut self, _path: &Path, _value: &dyn Struct) {}
+    fn leave_struct(&mut self, _path: &Path, _value: &dyn Struct) {}
+    fn enter_array(&mut self, _path: &Path, _value: &dyn Array) {}
+    fn leave_array(&mut self, _path: &Path, _value: &dyn Array) {}
+    fn enter_tuple(&mut self, _path: &Path, _value: &dyn Tuple) {}
+    fn leave_tuple(&mut self, _path: &Path, _value: &dyn Tuple) {}
+    fn scalar(&mut self, _path: &Path, _value: ValueRef<'_>) {}
+}
+
+impl Value {
+    /// Visit every node depth first, in item order.
+    pub fn walk(&self, visitor: &mut impl Visitor) {
+        self.as_value_ref().walk(visitor);
+    }
+
+    /// Rebuild this value top down. `f` is called on each node before its
+    /// items: returning `Some` replaces the node without descending into it,
+    /// `None` keeps it and moves on to its items. Subtrees left untouched
+    /// are shared with `self`; rebuilt structs, arrays and tuples are backed
+    /// by [`OrderedMap`], `Vec` and [`TupleVec`].
+    pub fn transform(&self, mut f: impl FnMut(&Path, &Value) -> Option<Value>) -> Value {
+        transform_at(&mut Path::default(), self, &mut f).unwrap_or_else(|| self.clone())
+    }
+}
+
+impl ValueRef<'_> {
+    /// Like [`Value::walk`], without converting anything into a [`Value`].
+    pub fn walk(&self, visitor: &mut impl Visitor) {
+        walk_at(&mut Path::default(), self, visitor);
+    }
+}
+
+fn walk_at(path: &mut Path, value: &ValueRef<'_>, visitor: &mut impl Visitor) {
+    match value {
+        ValueRef::Struct(v) => {
+            visitor.enter_struct(path, *v);
+
+            for (ident, item) in v.items() {
+                path.push(ident);
+                walk_at(path, &item.as_value_ref(), visitor);
+                path.pop();
+            }
+
+            visitor.leave_struct(path, *v);
+        }
+        ValueRef::Array(v) => {
+            visitor.enter_array(path, *v);
+
+            for (i, item) in v.items().enumerate() {
+                path.push(i);
+                walk_at(path, &item.as_value_ref(), visitor);
+                path.pop();
+            }
+
+            visitor.leave_array(path, *v);
+        }
+        ValueRef::Tuple(v) => {
+            visitor.enter_tuple(path, *v);
+
+            for (i, item) in v.items().enumerate() {
+                path.push(i);
+                walk_at(path, &item.as_value_ref(), visitor);
+                path.pop();
+            }
+
+            visitor.leave_tuple(path, *v);
+        }
+        ValueRef::Owned(v) => walk_at(path, &v.as_value_ref(), visitor),
+        v => visitor.scalar(path, v.clone()),
+    }
+}
+
+/// The rewritten node, or `None` when neither it nor any of its items
+/// changed.
+fn transform_at<F>(path: &mut Path, value: &Value, f: &mut F) -> Option<Value>
+where
+    F: FnMut(&Path, &Value) -> Option<Value>,
+{
+    if let Some(v) = f(path, value) {
+        return Some(v);
+    }
+
+    let Value::Object(object) = value else {
+        return None;
+    };
+
+    let mut changed = false;
+    let mut item = |path: &mut Path, ident: Ident, item: &dyn ToValue| {
+        let item = item.to_value();
+        path.push(ident);
+        let out = transform_at(path, &item, f);
+        path.pop();
+        changed |= out.is_some();
+        out.unwrap_or(item)
+    };
+
+    let value: Value = match object {
+        Object::Struct(v) => v
+            .items()
+            .map(|(ident, v)| (ident.clone(), item(path, ident, v)))
+            .collect::<OrderedMap>()
+            .into(),
+        Object::Array(v) => v
+            .items()
+            .enumerate()
+            .map(|(i, v)| item(path, Ident::Index(i), v))
+            .collect::<Vec<_>>()
+            .into(),
+        Object::Tuple(v) => TupleVec::new(
+            v.items()
+                .enumerate()
+                .map(|(i, v)| item(path, Ident::Index(i), v))
+                .collect(),
+        )
+        .into(),
+    };
+
+    changed.then_some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use xpath::Path;
+
+    use crate::*;
+
+    #[derive(Default)]
+    struct Events(Vec<String>);
+
+    impl Visitor for Events {
+        fn enter_struct(&mut self, path: &Path, value: &dyn Struct) {
+            self.0.push(format!("+struct {path} {}", value.len()));
+        }
+
+        fn leave_struct(&mut self, path: &Path, _value: &dyn Struct) {
+            self.0.push(format!("-struct {path}"));
+        }
+
+        fn enter_array(&mut self, path: &Path, value: &dyn Array) {
+            self.0.push(format!("+array {path} {}", value.len()));
+        }
+
+        fn leave_array(&mut self, path: &Path, _value: &dyn Array) {
+            self.0.push(format!("-array {path}"));
+        }
+
+        fn enter_tuple(&mut self, path: &Path, value: &dyn Tuple) {
+            self.0.push(format!("+tuple {path} {}", value.len()));
+        }
+
+        fn leave_tuple(&mut self, path: &Path, _value: &dyn Tuple) {
+            self.0.push(format!("-tuple {path}"));
+        }
+
+        fn scalar(&mut self, path: &Path, value: ValueRef<'_>) {
+            self.0.push(format!("{path} = {}", value.to_value()));
+        }
+    }
+
+    #[test]
+    fn walk() {
+        let v = valueof!({
+            "a": [1_i32, null],
+            "b": { "c": (true, "x") },
+        });
+
+        let mut events = Events::default();
+        v.walk(&mut events);
+
+        assert_eq!(
+            events.0,
+            vec![
+                "+struct  2",
+                "+array a 2",
+                "a/0 = 1",
+                "a/1 = <null>",
+                "-array a",
+                "+struct b 1",
+                "+tuple b/c 2",
+                "b/c/0 = true",
+                "b/c/1 = x",
+                "-tuple b/c",
+                "-struct b",
+                "-struct ",
+            ]
+        );
+    }
+
+    #[test]
+    fn walk_scalar_root() {
+        let mut events = Events::default();
+        valueof!(7_u8).walk(&mut events);
+        assert_eq!(events.0, vec![" = 7"]);
+    }
+
+    #[test]
+    fn walk_native() {
+        let map = std::collections::BTreeMap::from([(Ident::key("n"), vec![1_i64, 2])]);
+        let mut events = Events::default();
+        map.as_value_ref().walk(&mut events);
+        assert_eq!(events.0.len(), 6);
+        assert_eq!(events.0[2], "n/0 = 1");
+    }
+
+    #[test]
+    fn transform_redacts() {
+        let v = valueof!({
+            "user": { "name": "bob", "password": "hunter2" },
+            "tokens": [{ "password": "x" }],
+        });
+
+        let redacted = v.transform(|path, _| match path.last() {
+            Some(Ident::Key(k)) if &**k == "password" => Some(valueof!("***")),
+            _ => None,
+        });
+
+        assert_eq!(
+            redacted,
+            valueof!({
+                "user": { "name": "bob", "password": "***" },
+                "tokens": [{ "password": "***" }],
+            })
+        );
+        assert_eq!(
+            v.get(&Path::parse("user/password").unwrap()).unwrap(),
+            "hunter2"
+        );
+    }
+
+    #[test]
+    fn transform_skips_replaced() {
+        let v = valueof!({ "a": { "b": 1_i32 } });
+        let mut seen = vec![];
+
+        let out = v.transform(|path, _| {
+            seen.push(path.to_string());
+            (path.to_string() == "a").then(|| valueof!(null))
+        });
+
+        assert_eq!(seen, vec!["", "a"]);
+        assert_eq!(out, valueof!({ "a": null }));
+    }
+
+    #[test]
+    fn transform_shares_untouched() {
+        let v = Value::from_struct(std::collections::HashMap::from([(
+            Ident::key("a"),
+            valueof!([1_i32, 2_i32]),
+        )]));
+
+        let same = v.transform(|_, _| None);
+        assert_eq!(same.as_struct().name(), "HashMap");
+
+        let doubled = v.transform(|_, v| v.is_number().then(|| (v.to_i32() * 2).to_value()));
+        assert_eq!(doubled.as_struct().name(), "OrderedMap");
+        assert_eq!(doubled, valueof!({ "a": [2_i32, 4_i32] }));
+
+        let t = valueof!((1_i32, "a")).transform(|_, v| v.is_string().then(|| valueof!("b")));
+        assert_eq!(t.as_tuple().name(), "TupleVec");
+        assert_eq!(t, valueof!((1_i32, "b")));
+    }
+}